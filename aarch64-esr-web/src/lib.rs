@@ -12,8 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use aarch64_esr_decoder::{decode, parse_number, DecodeError, FieldInfo};
+use aarch64_esr_decoder::{decode_with_options, parse_number, DecodeOptions, FieldInfo};
 use std::convert::TryFrom;
+use std::fmt::Display;
 use std::ops::Deref;
 use wasm_bindgen::prelude::*;
 use web_sys::{Document, Element};
@@ -28,7 +29,12 @@ pub fn init() {
 pub fn decode_esr(esr: &str) -> Result<(), JsValue> {
     match parse_number(esr) {
         Ok(esr) => {
-            let decoded = decode(esr);
+            // Show as much as possible of invalid values, with the problems highlighted.
+            let options = DecodeOptions {
+                lenient: true,
+                ..Default::default()
+            };
+            let decoded = decode_with_options(esr, &options);
             show_decoded(esr, decoded, u64::BITS)?;
         }
         Err(_) => show_error("ESR not valid hex or decimal number"),
@@ -76,9 +82,9 @@ fn show_error(error: &str) {
     error_element.set_text_content(Some(error));
 }
 
-fn show_decoded(
+fn show_decoded<E: Display>(
     esr: u64,
-    decoded: Result<Vec<FieldInfo>, DecodeError>,
+    decoded: Result<Vec<FieldInfo>, E>,
     nr_bit: u32,
 ) -> Result<(), JsValue> {
     assert!(nr_bit <= u64::BITS);
@@ -144,7 +150,7 @@ fn show_decoded(
                 &row,
                 &fields,
                 &mut last,
-                |field| field.description.clone(),
+                field_description,
                 |_| None,
             )?;
             table.append_child(&row)?;
@@ -175,7 +181,7 @@ fn show_decoded(
                     &row,
                     &field.subfields,
                     &mut last,
-                    |field| field.description.clone(),
+                    field_description,
                     |_| None,
                 )?;
             }
@@ -231,8 +237,20 @@ where
             get_hover_title(field).as_deref(),
             field.width,
         )?;
+        if field.diagnostic.is_some() {
+            cell.set_attribute("class", "diagnostic")?;
+        }
         row.append_child(&cell)?;
         *last = field.start;
     }
     Ok(())
 }
+
+/// Returns the description of the field, followed by its diagnostic if it has one.
+fn field_description(field: &FieldInfo) -> Option<String> {
+    match (&field.description, field.diagnostic) {
+        (Some(description), Some(diagnostic)) => Some(format!("{description} ({diagnostic})")),
+        (None, Some(diagnostic)) => Some(diagnostic.to_string()),
+        (description, None) => description.clone(),
+    }
+}
@@ -12,66 +12,570 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use arm_sysregs_xml::{EncName, Encoding, ExecutionState, RegisterPage};
+//! Generates `src/esr/sysreg_names.rs` in `aarch64-esr-decoder` from Arm's system register XML,
+//! with the names of AArch64 system registers, of 128-bit system registers accessed with MRRS and
+//! MSRR, of System instructions such as `TLBI`, `DC` and `TLBIP`, and of AArch32 System registers
+//! accessed with MCR, MRC, MCRR and MRRC.
+//!
+//! Usage:
+//!
+//! ```sh
+//! cargo run -p arm-sysregs-xml --example generate_decoder -- \
+//!     SysReg_xml_A_profile-2025-06/SysReg_xml_A_profile-2025-06 > src/esr/sysreg_names.rs
+//! ```
+
+use arm_sysregs_xml::{EncName, Encoding, ExecutionState, Register, RegisterPage};
 use quick_xml::de;
 use std::{
     collections::BTreeMap,
+    env,
+    fmt::Display,
     fs::{File, read_dir},
     io::BufReader,
+    path::Path,
+    process::exit,
 };
 
+/// The encoding of a system register, as `(op0, CRn, op1, CRm, op2)`.
+type SysRegEncoding = (u8, u8, u8, u8, u8);
+
+/// The encoding of an AArch32 System register accessed with MCR or MRC, as
+/// `(coproc, CRn, opc1, CRm, opc2)`.
+type CoprocEncoding = (u8, u8, u8, u8, u8);
+
+/// The encoding of a 64-bit AArch32 System register accessed with MCRR or MRRC, as
+/// `(coproc, opc1, CRm)`.
+type Coproc64Encoding = (u8, u8, u8);
+
+/// The names of the register read and written with each encoding.
+type DirectionNames<E> = BTreeMap<E, (Option<String>, Option<String>)>;
+
 fn main() {
-    let mut encodings = BTreeMap::new();
-    for entry in read_dir("SysReg_xml_A_profile-2025-06/SysReg_xml_A_profile-2025-06").unwrap() {
-        let entry = entry.unwrap();
-        let filename = entry.file_name().into_string().unwrap();
-        if filename.ends_with(".xml")
-            && !filename.ends_with("index.xml")
-            && ![
-                "amu.xml",
-                "architecture_info.xml",
-                "instructions.xml",
-                "notice.xml",
-                "pmu.xml",
-            ]
-            .contains(&filename.as_str())
+    let args: Vec<String> = env::args().collect();
+    let [_, directory] = args.as_slice() else {
+        eprintln!("Usage: {} <SysReg XML directory>", args[0]);
+        exit(1);
+    };
+    let release = Path::new(directory)
+        .file_name()
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_owned();
+
+    // The names of the register read by MRS and written by MSR for each encoding.
+    let mut encodings: DirectionNames<SysRegEncoding> = BTreeMap::new();
+    // The name of the System instruction for each encoding with op0 1, and whether it takes a
+    // general-purpose register operand.
+    let mut instructions: BTreeMap<SysRegEncoding, (String, bool)> = BTreeMap::new();
+    // The names of the 128-bit register read by MRRS and written by MSRR for each encoding.
+    let mut encodings128: DirectionNames<SysRegEncoding> = BTreeMap::new();
+    // The name of the SYSP instruction for each encoding with op0 1.
+    let mut sysp_instructions: BTreeMap<SysRegEncoding, String> = BTreeMap::new();
+    // The names of the AArch32 register read by MRC and written by MCR for each encoding.
+    let mut coproc_encodings: DirectionNames<CoprocEncoding> = BTreeMap::new();
+    // The name of the 64-bit AArch32 register accessed by MCRR and MRRC for each encoding.
+    let mut coproc64_encodings: BTreeMap<Coproc64Encoding, String> = BTreeMap::new();
+    let mut filenames = read_dir(directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect::<Vec<_>>();
+    // Sort so that the output doesn't depend on the order of the directory listing.
+    filenames.sort();
+    for filename in filenames {
+        if !filename.ends_with(".xml")
+            || filename.ends_with("index.xml")
+            || ["amu.xml", "architecture_info.xml", "notice.xml", "pmu.xml"]
+                .contains(&filename.as_str())
         {
-            let register_page = de::from_reader::<_, RegisterPage>(BufReader::new(
-                File::open(entry.path()).unwrap(),
-            ))
-            .unwrap();
-            let register = &register_page.registers.register;
-            if register.execution_state != Some(ExecutionState::AArch64) {
+            continue;
+        }
+        let register_page = match de::from_reader::<_, RegisterPage>(BufReader::new(
+            File::open(Path::new(directory).join(&filename)).unwrap(),
+        )) {
+            Ok(register_page) => register_page,
+            Err(e) => {
+                eprintln!("Skipping {filename}: {e}");
                 continue;
             }
-            for mechanism in &register.access_mechanisms.access_mechanism {
-                if let Some(encoding) = &mechanism.encoding
-                    && encoding.access_instruction.starts_with("MRS <Xt>, ")
-                {
-                    let reg_name = &encoding.access_instruction[10..];
-                    if let (Some(op0), Some(op1), Some(crn), Some(crm), Some(op2)) = (
-                        enc_value(encoding, EncName::Op0),
-                        enc_value(encoding, EncName::Op1),
-                        enc_value(encoding, EncName::CRn),
-                        enc_value(encoding, EncName::CRm),
-                        enc_value(encoding, EncName::Op2),
-                    ) {
-                        encodings.insert(
-                            reg_name.to_owned(),
-                            format!("({op0}, {crn}, {op1}, {crm}, {op2})"),
-                        );
-                    } else {
-                        println!("// {reg_name}");
+        };
+        let register = &register_page.registers.register;
+        match register.execution_state {
+            Some(ExecutionState::AArch64) => {
+                add_sysreg_encodings(
+                    register,
+                    &mut encodings,
+                    &mut instructions,
+                    &mut encodings128,
+                    &mut sysp_instructions,
+                );
+            }
+            Some(ExecutionState::AArch32) => {
+                add_coproc_encodings(register, &mut coproc_encodings, &mut coproc64_encodings);
+            }
+            _ => {}
+        }
+    }
+
+    print!("{HEADER}");
+    println!("pub const SYSREG_NAMES_RELEASE: &str = \"{release}\";");
+    print!("{FUNCTION_START}");
+    for arm in direction_arms(&encodings, |(op0, crn, op1, crm, op2)| {
+        format!("{op0}, {crn}, {op1}, {crm}, {op2}")
+    }) {
+        println!("        {arm}");
+    }
+    print!("{FUNCTION_END}");
+
+    // Match arms sorted by instruction name.
+    let instruction_arms = instructions
+        .iter()
+        .map(|((_, crn, op1, crm, op2), (name, takes_register))| {
+            (
+                name,
+                format!("({crn}, {op1}, {crm}, {op2}) => (\"{name}\", {takes_register}),"),
+            )
+        })
+        .collect::<BTreeMap<_, _>>();
+    print!("{INSTRUCTION_FUNCTION_START}");
+    for arm in instruction_arms.values() {
+        println!("        {arm}");
+    }
+    print!("{FUNCTION_END}");
+
+    print!("{SYSREG128_FUNCTION_START}");
+    for arm in direction_arms(&encodings128, |(op0, crn, op1, crm, op2)| {
+        format!("{op0}, {crn}, {op1}, {crm}, {op2}")
+    }) {
+        println!("        {arm}");
+    }
+    print!("{FUNCTION_END}");
+
+    // Match arms sorted by instruction name.
+    let sysp_arms = sysp_instructions
+        .iter()
+        .map(|((_, crn, op1, crm, op2), name)| {
+            (name, format!("({crn}, {op1}, {crm}, {op2}) => \"{name}\","))
+        })
+        .collect::<BTreeMap<_, _>>();
+    print!("{SYSP_FUNCTION_START}");
+    for arm in sysp_arms.values() {
+        println!("        {arm}");
+    }
+    print!("{FUNCTION_END}");
+
+    print!("{COPROC_FUNCTION_START}");
+    for arm in direction_arms(&coproc_encodings, |(coproc, crn, opc1, crm, opc2)| {
+        format!("{coproc}, {crn}, {opc1}, {crm}, {opc2}")
+    }) {
+        println!("        {arm}");
+    }
+    print!("{FUNCTION_END}");
+
+    // Match arms sorted by register name.
+    let coproc64_arms = coproc64_encodings
+        .iter()
+        .map(|(encoding @ (coproc, opc1, crm), name)| {
+            (
+                (name, encoding),
+                format!("({coproc}, {opc1}, {crm}) => \"{name}\","),
+            )
+        })
+        .collect::<BTreeMap<_, _>>();
+    print!("{COPROC64_FUNCTION_START}");
+    for arm in coproc64_arms.values() {
+        println!("        {arm}");
+    }
+    print!("{FUNCTION_END}");
+}
+
+const HEADER: &str = "\
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the \"License\");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an \"AS IS\" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Generated by the `generate_decoder` example of `arm-sysregs-xml`. Don't edit by hand.
+
+/// The release of the Arm system register XML which the names were generated from.
+";
+
+const FUNCTION_START: &str = "
+/// Returns the name of the AArch64 system register with the given encoding which is read if `read`
+/// is true or written otherwise, if there is one.
+pub fn sysreg_name(
+    op0: u64,
+    op1: u64,
+    op2: u64,
+    crn: u64,
+    crm: u64,
+    read: bool,
+) -> Option<&'static str> {
+    Some(match (op0, crn, op1, crm, op2, read) {
+";
+
+const FUNCTION_END: &str = "        _ => return None,
+    })
+}
+";
+
+const INSTRUCTION_FUNCTION_START: &str = "
+/// Returns the name of the System instruction with the given encoding and op0 1, such as
+/// `TLBI VAE1IS`, and whether it takes a general-purpose register operand, if there is one.
+pub fn sys_instruction_name(
+    op1: u64,
+    op2: u64,
+    crn: u64,
+    crm: u64,
+) -> Option<(&'static str, bool)> {
+    Some(match (crn, op1, crm, op2) {
+";
+
+const SYSREG128_FUNCTION_START: &str = "
+/// Returns the name of the 128-bit AArch64 system register with the given encoding which is read by
+/// MRRS if `read` is true or written by MSRR otherwise, if there is one.
+pub fn sysreg128_name(
+    op0: u64,
+    op1: u64,
+    op2: u64,
+    crn: u64,
+    crm: u64,
+    read: bool,
+) -> Option<&'static str> {
+    Some(match (op0, crn, op1, crm, op2, read) {
+";
+
+const SYSP_FUNCTION_START: &str = "
+/// Returns the name of the SYSP instruction with the given encoding and op0 1, such as
+/// `TLBIP VAE1IS`, if there is one.
+pub fn sysp_instruction_name(op1: u64, op2: u64, crn: u64, crm: u64) -> Option<&'static str> {
+    Some(match (crn, op1, crm, op2) {
+";
+
+const COPROC_FUNCTION_START: &str = "
+/// Returns the name of the 32-bit AArch32 System register or System instruction with the given
+/// encoding which is read by MRC if `read` is true or written by MCR otherwise, if there is one.
+pub fn coproc_name(
+    coproc: u64,
+    opc1: u64,
+    crn: u64,
+    crm: u64,
+    opc2: u64,
+    read: bool,
+) -> Option<&'static str> {
+    Some(match (coproc, crn, opc1, crm, opc2, read) {
+";
+
+const COPROC64_FUNCTION_START: &str = "
+/// Returns the name of the 64-bit AArch32 System register with the given encoding for MCRR or
+/// MRRC, if there is one.
+pub fn coproc64_name(coproc: u64, opc1: u64, crm: u64) -> Option<&'static str> {
+    Some(match (coproc, opc1, crm) {
+";
+
+/// Adds the encodings of the system registers and System instructions which the given AArch64
+/// register page describes to `encodings` and `instructions`, or to `encodings128` and
+/// `sysp_instructions` for those accessed with MRRS, MSRR and SYSP.
+fn add_sysreg_encodings(
+    register: &Register,
+    encodings: &mut DirectionNames<SysRegEncoding>,
+    instructions: &mut BTreeMap<SysRegEncoding, (String, bool)>,
+    encodings128: &mut DirectionNames<SysRegEncoding>,
+    sysp_instructions: &mut BTreeMap<SysRegEncoding, String>,
+) {
+    for mechanism in &register.access_mechanisms.access_mechanism {
+        let Some(encoding) = &mechanism.encoding else {
+            continue;
+        };
+        let instruction = &encoding.access_instruction;
+        if let Some((sysp_encoding, name)) = sysp_instruction(encoding) {
+            match sysp_instructions.get(&sysp_encoding) {
+                Some(existing) if *existing != name => {
+                    eprintln!("Ignoring {name}, which has the same encoding as {existing}");
+                }
+                _ => {
+                    sysp_instructions.insert(sysp_encoding, name);
+                }
+            }
+            continue;
+        }
+        if let Some((sys_encoding, name, takes_register)) = sys_instruction(encoding) {
+            match instructions.get(&sys_encoding) {
+                Some((existing, _)) if *existing != name => {
+                    eprintln!("Ignoring {name}, which has the same encoding as {existing}");
+                }
+                _ => {
+                    instructions.insert(sys_encoding, (name, takes_register));
+                }
+            }
+            continue;
+        }
+        let (name, read, encodings) = if let Some(name) = instruction.strip_prefix("MRS <Xt>, ") {
+            (name, true, &mut *encodings)
+        } else if let Some(name) = instruction
+            .strip_prefix("MSR ")
+            .and_then(|rest| rest.strip_suffix(", <Xt>"))
+        {
+            (name, false, &mut *encodings)
+        } else if let Some(name) = instruction.strip_prefix("MRRS <Xt>, <Xt+1>, ") {
+            (name, true, &mut *encodings128)
+        } else if let Some(name) = instruction
+            .strip_prefix("MSRR ")
+            .and_then(|rest| rest.strip_suffix(", <Xt>, <Xt+1>"))
+        {
+            (name, false, &mut *encodings128)
+        } else {
+            continue;
+        };
+        let Some(sysreg_encoding) = sysreg_encoding(encoding) else {
+            eprintln!("Skipping {name} with non-constant encoding");
+            continue;
+        };
+        add_direction_name(encodings, sysreg_encoding, name, read);
+    }
+}
+
+/// Adds the encodings of the AArch32 System registers which the given register page describes to
+/// `encodings` for MCR and MRC or `encodings64` for MCRR and MRRC.
+///
+/// Each element of a register array such as `DBGBVR<n>` is added separately.
+fn add_coproc_encodings(
+    register: &Register,
+    encodings: &mut DirectionNames<CoprocEncoding>,
+    encodings64: &mut BTreeMap<Coproc64Encoding, String>,
+) {
+    let indexes = match &register.reg_array {
+        Some(array) => (array.reg_array_start..=array.reg_array_end)
+            .map(Some)
+            .collect(),
+        None => vec![None],
+    };
+    for mechanism in &register.access_mechanisms.access_mechanism {
+        let (Some(accessor), Some(encoding)) = (&mechanism.accessor, &mechanism.encoding) else {
+            continue;
+        };
+        let Some((mnemonic, name)) = accessor.split_once(' ') else {
+            continue;
+        };
+        for &index in &indexes {
+            let name = match index {
+                Some(index) => array_element_name(name, index),
+                None => name.to_owned(),
+            };
+            match mnemonic {
+                "MRC" | "MCR" => {
+                    let Some(coproc_encoding) = coproc_encoding(encoding, index) else {
+                        eprintln!("Skipping {name} with non-constant encoding");
+                        continue;
+                    };
+                    add_direction_name(encodings, coproc_encoding, &name, mnemonic == "MRC");
+                }
+                "MRRC" | "MCRR" => {
+                    let Some(coproc64_encoding) = coproc64_encoding(encoding, index) else {
+                        eprintln!("Skipping {name} with non-constant encoding");
+                        continue;
+                    };
+                    match encodings64.get(&coproc64_encoding) {
+                        Some(existing) if *existing != name => {
+                            eprintln!("Ignoring {name}, which has the same encoding as {existing}");
+                        }
+                        _ => {
+                            encodings64.insert(coproc64_encoding, name);
+                        }
                     }
                 }
+                _ => {}
             }
         }
     }
-    for (name, encoding) in &encodings {
-        println!("{encoding} => \"{name}\",");
+}
+
+/// Records that the register with the given name is read if `read` is true or written otherwise
+/// with the given encoding, unless another register already is.
+fn add_direction_name<E: Ord>(
+    encodings: &mut DirectionNames<E>,
+    encoding: E,
+    name: &str,
+    read: bool,
+) {
+    let names = encodings.entry(encoding).or_default();
+    let slot = if read { &mut names.0 } else { &mut names.1 };
+    match slot {
+        Some(existing) if existing != name => {
+            eprintln!("Ignoring {name}, which has the same encoding as {existing}");
+        }
+        _ => *slot = Some(name.to_owned()),
+    }
+}
+
+/// Returns match arms for the given encodings sorted by register name, with those which name a
+/// different register for reads and writes split by direction.
+///
+/// `pattern` formats an encoding as the start of the pattern of its match arm.
+fn direction_arms<E: Copy + Ord, P: Display>(
+    encodings: &DirectionNames<E>,
+    pattern: impl Fn(E) -> P,
+) -> Vec<String> {
+    let mut arms = BTreeMap::new();
+    for (&encoding, names) in encodings {
+        let pattern = pattern(encoding);
+        match names {
+            (Some(read_name), Some(write_name)) if read_name != write_name => {
+                arms.insert(
+                    (read_name, true, encoding),
+                    format!("({pattern}, true) => \"{read_name}\","),
+                );
+                arms.insert(
+                    (write_name, false, encoding),
+                    format!("({pattern}, false) => \"{write_name}\","),
+                );
+            }
+            (Some(name), _) | (None, Some(name)) => {
+                arms.insert(
+                    (name, true, encoding),
+                    format!("({pattern}, _) => \"{name}\","),
+                );
+            }
+            (None, None) => {}
+        }
+    }
+    arms.into_values().collect()
+}
+
+/// Returns the encoding and name of the System instruction with op0 1 which the given encoding is
+/// for, such as `TLBI VAE1IS, <Xt>`, and whether it takes a general-purpose register operand.
+fn sys_instruction(encoding: &Encoding) -> Option<(SysRegEncoding, String, bool)> {
+    let sys_encoding = sysreg_encoding(encoding)?;
+    if sys_encoding.0 != 1 {
+        return None;
+    }
+    let instruction = &encoding.access_instruction;
+    let (name, takes_register) = if let Some(name) = instruction.strip_suffix("{, <Xt>}") {
+        (name, false)
+    } else if let Some(name) = instruction.strip_suffix(", <Xt>") {
+        (name, true)
+    } else {
+        (instruction.as_str(), false)
+    };
+    // Skip SYSL forms and anything else with other operands.
+    if name.contains('<') || !name.contains(' ') {
+        return None;
+    }
+    Some((sys_encoding, name.to_owned(), takes_register))
+}
+
+/// Returns the encoding and name of the SYSP instruction with op0 1 which the given encoding is for,
+/// such as `TLBIP VAE1IS{, <Xt>, <Xt+1>}`.
+///
+/// These take a pair of general-purpose registers, and usually share their encoding with a System
+/// instruction such as `TLBI VAE1IS`.
+fn sysp_instruction(encoding: &Encoding) -> Option<(SysRegEncoding, String)> {
+    let sys_encoding = sysreg_encoding(encoding)?;
+    let instruction = &encoding.access_instruction;
+    if sys_encoding.0 != 1 || !instruction.contains("<Xt+1>") {
+        return None;
+    }
+    let (name, _) = instruction.split_once(['{', ','])?;
+    let name = name.trim_end();
+    if name.contains('<') || !name.contains(' ') {
+        return None;
     }
+    Some((sys_encoding, name.to_owned()))
+}
+
+fn sysreg_encoding(encoding: &Encoding) -> Option<SysRegEncoding> {
+    Some((
+        enc_value(encoding, EncName::Op0)?,
+        enc_value(encoding, EncName::CRn)?,
+        enc_value(encoding, EncName::Op1)?,
+        enc_value(encoding, EncName::CRm)?,
+        enc_value(encoding, EncName::Op2)?,
+    ))
 }
 
 fn enc_value(encoding: &Encoding, name: EncName) -> Option<u8> {
     encoding.enc.iter().find(|enc| enc.n == name)?.parse_value()
 }
+
+fn coproc_encoding(encoding: &Encoding, index: Option<u32>) -> Option<CoprocEncoding> {
+    Some((
+        indexed_enc_value(encoding, EncName::Coproc, index)?,
+        indexed_enc_value(encoding, EncName::CRn, index)?,
+        indexed_enc_value(encoding, EncName::Opc1, index)?,
+        indexed_enc_value(encoding, EncName::CRm, index)?,
+        indexed_enc_value(encoding, EncName::Opc2, index)?,
+    ))
+}
+
+fn coproc64_encoding(encoding: &Encoding, index: Option<u32>) -> Option<Coproc64Encoding> {
+    Some((
+        indexed_enc_value(encoding, EncName::Coproc, index)?,
+        indexed_enc_value(encoding, EncName::Opc1, index)?,
+        indexed_enc_value(encoding, EncName::CRm, index)?,
+    ))
+}
+
+/// Returns the name of the given element of a register array, e.g. `DBGBVR3` for `DBGBVR<n>`.
+fn array_element_name(name: &str, index: u32) -> String {
+    match (name.find('<'), name.find('>')) {
+        (Some(start), Some(end)) if start < end => {
+            format!("{}{index}{}", &name[..start], &name[end + 1..])
+        }
+        _ => name.to_owned(),
+    }
+}
+
+/// Returns the value of the given field of the encoding for the given element of a register array,
+/// or of a register which isn't part of an array if `index` is `None`.
+///
+/// The value may be made up of constant bits and bits of the index joined with `:`, such as
+/// `0b10:n[4:3]`.
+fn indexed_enc_value(encoding: &Encoding, name: EncName, index: Option<u32>) -> Option<u8> {
+    let enc = encoding.enc.iter().find(|enc| enc.n == name)?;
+    let Some(index) = index else {
+        return enc.parse_value();
+    };
+    let mut value = 0u32;
+    for part in split_top_level(&enc.v) {
+        if let Some(bits) = part.strip_prefix("0b") {
+            value = value << bits.len() | u32::from_str_radix(bits, 2).ok()?;
+        } else {
+            // A slice of the index, such as `n[4:3]` or `n[0]`.
+            let (_, slice) = part.strip_suffix(']')?.split_once('[')?;
+            let (high, low) = slice.split_once(':').unwrap_or((slice, slice));
+            let (high, low) = (high.parse::<u32>().ok()?, low.parse::<u32>().ok()?);
+            let width = high.checked_sub(low)? + 1;
+            value = value << width | (index >> low) & ((1 << width) - 1);
+        }
+    }
+    u8::try_from(value).ok()
+}
+
+/// Splits the given encoding value at each `:` which isn't inside square brackets.
+fn split_top_level(value: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (position, c) in value.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            ':' if depth == 0 => {
+                parts.push(&value[start..position]);
+                start = position + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&value[start..]);
+    parts
+}
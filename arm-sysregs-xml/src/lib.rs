@@ -41,6 +41,7 @@ pub struct Register {
     pub reg_short_name: String,
     pub reg_long_name: String,
     pub reg_condition: Option<RegCondition>,
+    pub reg_array: Option<RegArray>,
     pub power_domain_text: Option<Text>,
     pub reg_reset_value: RegResetValue,
     pub reg_mappings: RegMappings,
@@ -62,6 +63,12 @@ pub enum ExecutionState {
     External,
 }
 
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct RegArray {
+    pub reg_array_start: u32,
+    pub reg_array_end: u32,
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 pub struct RegCondition {
     #[serde(rename = "@otherwise")]
@@ -12,93 +12,930 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{DecodeError, FieldInfo};
-use std::fmt::{self, Debug, Display, Formatter};
+use super::Features;
+use super::common::decode_vncr;
+use super::{IssField, bit, iss2};
+use crate::{DecodeError, FieldList, StaticFieldInfo};
+use bit_field::BitField;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::ops::Range;
+
+/// The ISS value of an Instruction Abort.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct InstructionAbortIss {
+    iss: u64,
+}
+
+impl InstructionAbortIss {
+    const SET: Range<usize> = 11..13;
+    const FNV: usize = 10;
+    const EA: usize = 9;
+    const S1PTW: usize = 7;
+    const IFSC: Range<usize> = 0..6;
+
+    /// Creates a new `InstructionAbortIss` from the given ISS field value.
+    pub fn new(iss: u64) -> Self {
+        Self { iss }
+    }
+
+    /// Returns the raw ISS value.
+    pub fn iss(self) -> u64 {
+        self.iss
+    }
+
+    /// Returns the Synchronous Error Type, if the IFSC indicates a synchronous External abort.
+    pub fn set(self) -> Option<u64> {
+        (self.iss.get_bits(Self::IFSC) == FaultStatusCode::SyncExternal as u64)
+            .then(|| self.iss.get_bits(Self::SET))
+    }
+
+    /// Returns whether FAR is not valid.
+    pub fn fnv(self) -> bool {
+        self.iss.get_bit(Self::FNV)
+    }
+
+    /// Returns the External abort type.
+    pub fn ea(self) -> bool {
+        self.iss.get_bit(Self::EA)
+    }
+
+    /// Returns whether the fault was on a stage 2 fault for an access made for a stage 1
+    /// translation table walk.
+    pub fn s1ptw(self) -> bool {
+        self.iss.get_bit(Self::S1PTW)
+    }
+
+    /// Returns the Instruction Fault Status Code, or an error if it is not a valid value.
+    pub fn ifsc(self) -> Result<FaultStatusCode, DecodeError> {
+        FaultStatusCode::try_from(self.iss.get_bits(Self::IFSC))
+    }
+}
+
+/// The non-reserved ISS fields which may be present for an Instruction Abort.
+pub const INSTRUCTION_ABORT_FIELDS: &[IssField] = &[
+    ("SET", InstructionAbortIss::SET),
+    ("FnV", bit(InstructionAbortIss::FNV)),
+    ("EA", bit(InstructionAbortIss::EA)),
+    ("S1PTW", bit(InstructionAbortIss::S1PTW)),
+    ("IFSC", InstructionAbortIss::IFSC),
+];
 
 /// Decodes the ISS value for an Instruction Abort.
-pub fn decode_iss_instruction_abort(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let res0a = FieldInfo::get(iss, "RES0", Some("Reserved"), 13, 25).check_res0()?;
-    let fnv = FieldInfo::get_bit(iss, "FnV", Some("FAR not Valid"), 10).describe_bit(describe_fnv);
-    let ea = FieldInfo::get_bit(iss, "EA", Some("External abort type"), 9);
-    let res0b = FieldInfo::get_bit(iss, "RES0", Some("Reserved"), 8).check_res0()?;
-    let s1ptw = FieldInfo::get_bit(iss, "S1PTW", Some("Stage-1 translation table walk"), 7);
-    let res0c = FieldInfo::get_bit(iss, "RES0", Some("Reserved"), 6).check_res0()?;
-    let ifsc = FieldInfo::get(iss, "IFSC", Some("Instruction Fault Status Code"), 0, 6)
-        .describe(describe_fsc)?;
-
-    let set = if ifsc.value == 0b010000 {
-        FieldInfo::get(iss, "SET", Some("Synchronous Error Type"), 11, 13).describe(describe_set)?
-    } else {
-        FieldInfo::get(iss, "RES0", Some("Reserved"), 11, 13)
+pub fn decode_iss_instruction_abort(
+    instruction_abort: InstructionAbortIss,
+    features: Features,
+) -> FieldList {
+    let iss = instruction_abort.iss();
+    let res0a = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 13, 25).check_res0();
+    let fnv = StaticFieldInfo::new_bit(
+        "FnV",
+        Some("FAR not Valid"),
+        InstructionAbortIss::FNV,
+        instruction_abort.fnv(),
+    )
+    .describe_bit(describe_fnv);
+    let ea = StaticFieldInfo::new_bit(
+        "EA",
+        Some("External abort type"),
+        InstructionAbortIss::EA,
+        instruction_abort.ea(),
+    );
+    let res0b = StaticFieldInfo::get_bit(iss, "RES0", Some("Reserved"), 8).check_res0();
+    let s1ptw = StaticFieldInfo::new_bit(
+        "S1PTW",
+        Some("Stage-1 translation table walk"),
+        InstructionAbortIss::S1PTW,
+        instruction_abort.s1ptw(),
+    );
+    let res0c = StaticFieldInfo::get_bit(iss, "RES0", Some("Reserved"), 6).check_res0();
+    // The raw value is used rather than `ifsc()` so that invalid values can still be shown.
+    let ifsc = StaticFieldInfo::new(
+        "IFSC",
+        Some("Instruction Fault Status Code"),
+        InstructionAbortIss::IFSC,
+        iss.get_bits(InstructionAbortIss::IFSC),
+    )
+    .describe(|fsc| describe_fsc(fsc, features));
+
+    let set = match instruction_abort.set() {
+        Some(set) => decode_set(set, features),
+        None => StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 11, 13),
     };
 
-    Ok(vec![res0a, set, fnv, ea, res0b, s1ptw, res0c, ifsc])
+    FieldList::from([res0a, set, fnv, ea, res0b, s1ptw, res0c, ifsc])
+}
+
+/// The ISS and ISS2 values of a Data Abort.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct DataAbortIss {
+    iss: u64,
+    iss2: u64,
+}
+
+impl DataAbortIss {
+    const ISV: usize = 24;
+    const SAS: Range<usize> = 22..24;
+    const SSE: usize = 21;
+    const SRT: Range<usize> = 16..21;
+    const SF: usize = 15;
+    const AR: usize = 14;
+    const WU: Range<usize> = 16..18;
+    const FNP: usize = 15;
+    const PFV: usize = 14;
+    const VNCR: usize = 13;
+    const SET: Range<usize> = 11..13;
+    const LST: Range<usize> = 11..13;
+    const FNV: usize = 10;
+    const EA: usize = 9;
+    const CM: usize = 8;
+    const S1PTW: usize = 7;
+    const WNR: usize = 6;
+    const DFSC: Range<usize> = 0..6;
+
+    const XS: Range<usize> = 0..5;
+    const DIRTY_BIT: usize = 5;
+    const OVERLAY: usize = 6;
+    const ASSURED_ONLY: usize = 7;
+    const GCS: usize = 8;
+    const TAG_ACCESS: usize = 9;
+    const TND: usize = 10;
+    const SASV: usize = 12;
+
+    /// Creates a new `DataAbortIss` from the given ISS and ISS2 field values.
+    pub fn new(iss: u64, iss2: u64) -> Self {
+        Self { iss, iss2 }
+    }
+
+    /// Returns the raw ISS value.
+    pub fn iss(self) -> u64 {
+        self.iss
+    }
+
+    /// Returns the raw ISS2 value.
+    pub fn iss2(self) -> u64 {
+        self.iss2
+    }
+
+    /// Returns whether the instruction syndrome (SAS, SSE, SRT, SF and AR) is valid.
+    pub fn isv(self) -> bool {
+        self.iss.get_bit(Self::ISV)
+    }
+
+    /// Returns the size of the access, if the instruction syndrome is valid or ISS2 says that SAS
+    /// is valid.
+    pub fn sas(self) -> Option<SyndromeAccessSize> {
+        (self.isv() || self.sasv()).then(|| SyndromeAccessSize::from(self.iss.get_bits(Self::SAS)))
+    }
+
+    /// Returns whether the loaded value was sign-extended, if the instruction syndrome is valid.
+    pub fn sse(self) -> Option<bool> {
+        self.isv().then(|| self.iss.get_bit(Self::SSE))
+    }
+
+    /// Returns the register number of the transfer register, if the instruction syndrome is valid.
+    pub fn srt(self) -> Option<u64> {
+        self.isv().then(|| self.iss.get_bits(Self::SRT))
+    }
+
+    /// Returns whether the transfer register is 64 bits wide, if the instruction syndrome is
+    /// valid.
+    pub fn sf(self) -> Option<bool> {
+        self.isv().then(|| self.iss.get_bit(Self::SF))
+    }
+
+    /// Returns whether the access had acquire/release semantics, if the instruction syndrome is
+    /// valid.
+    pub fn ar(self) -> Option<bool> {
+        self.isv().then(|| self.iss.get_bit(Self::AR))
+    }
+
+    /// Returns the Write Update field, if the instruction syndrome is not valid.
+    pub fn wu(self) -> Option<u64> {
+        (!self.isv()).then(|| self.iss.get_bits(Self::WU))
+    }
+
+    /// Returns whether FAR is not precise. Always false if the instruction syndrome is valid.
+    pub fn fnp(self) -> bool {
+        !self.isv() && self.iss.get_bit(Self::FNP)
+    }
+
+    /// Returns whether PFAR is valid. Always false if the instruction syndrome is valid.
+    pub fn pfv(self) -> bool {
+        !self.isv() && self.iss.get_bit(Self::PFV)
+    }
+
+    /// Returns whether the fault came from an access using VNCR_EL2.
+    pub fn vncr(self) -> bool {
+        self.iss.get_bit(Self::VNCR)
+    }
+
+    /// Returns the Synchronous Error Type, if the DFSC indicates a synchronous External abort.
+    pub fn set(self) -> Option<u64> {
+        (self.iss.get_bits(Self::DFSC) == FaultStatusCode::SyncExternal as u64)
+            .then(|| self.iss.get_bits(Self::SET))
+    }
+
+    /// Returns the Load/Store Type, if the DFSC indicates an unsupported Exclusive or atomic
+    /// access.
+    pub fn lst(self) -> Option<u64> {
+        (self.iss.get_bits(Self::DFSC)
+            == FaultStatusCode::ImpDefUnsupportedExclusiveOrAtomic as u64)
+            .then(|| self.iss.get_bits(Self::LST))
+    }
+
+    /// Returns whether FAR is not valid.
+    pub fn fnv(self) -> bool {
+        self.iss.get_bit(Self::FNV)
+    }
+
+    /// Returns the External abort type.
+    pub fn ea(self) -> bool {
+        self.iss.get_bit(Self::EA)
+    }
+
+    /// Returns whether the fault came from a cache maintenance or address translation instruction.
+    pub fn cm(self) -> bool {
+        self.iss.get_bit(Self::CM)
+    }
+
+    /// Returns whether the fault was on a stage 2 fault for an access made for a stage 1
+    /// translation table walk.
+    pub fn s1ptw(self) -> bool {
+        self.iss.get_bit(Self::S1PTW)
+    }
+
+    /// Returns whether the abort was caused by writing to memory rather than reading.
+    pub fn wnr(self) -> bool {
+        self.iss.get_bit(Self::WNR)
+    }
+
+    /// Returns the Data Fault Status Code, or an error if it is not a valid value.
+    pub fn dfsc(self) -> Result<FaultStatusCode, DecodeError> {
+        FaultStatusCode::try_from(self.iss.get_bits(Self::DFSC))
+    }
+
+    /// Returns the status register number for an ST64BV or ST64BV0 instruction.
+    pub fn xs(self) -> u64 {
+        self.iss2.get_bits(Self::XS)
+    }
+
+    /// Returns whether a permission fault was caused by a write to a page in the clean state.
+    pub fn dirty_bit(self) -> bool {
+        self.iss2.get_bit(Self::DIRTY_BIT)
+    }
+
+    /// Returns whether a permission fault was caused by Overlay permissions.
+    pub fn overlay(self) -> bool {
+        self.iss2.get_bit(Self::OVERLAY)
+    }
+
+    /// Returns whether a permission fault was caused by the AssuredOnly attribute.
+    pub fn assured_only(self) -> bool {
+        self.iss2.get_bit(Self::ASSURED_ONLY)
+    }
+
+    /// Returns whether the fault was caused by a Guarded Control Stack data access.
+    pub fn gcs(self) -> bool {
+        self.iss2.get_bit(Self::GCS)
+    }
+
+    /// Returns whether a permission fault was caused by NoTagAccess.
+    pub fn tag_access(self) -> bool {
+        self.iss2.get_bit(Self::TAG_ACCESS)
+    }
+
+    /// Returns whether the fault was on an Allocation Tag access rather than a data access.
+    pub fn tnd(self) -> bool {
+        self.iss2.get_bit(Self::TND)
+    }
+
+    /// Returns whether ISS2 says that SAS is valid even though the instruction syndrome is not.
+    pub fn sasv(self) -> bool {
+        self.iss2.get_bit(Self::SASV)
+    }
 }
 
+/// The non-reserved ISS and ISS2 fields which may be present for a Data Abort.
+pub const DATA_ABORT_FIELDS: &[IssField] = &[
+    ("ISV", bit(DataAbortIss::ISV)),
+    ("SAS", DataAbortIss::SAS),
+    ("SSE", bit(DataAbortIss::SSE)),
+    ("SRT", DataAbortIss::SRT),
+    ("SF", bit(DataAbortIss::SF)),
+    ("AR", bit(DataAbortIss::AR)),
+    ("WU", DataAbortIss::WU),
+    ("FnP", bit(DataAbortIss::FNP)),
+    ("PFV", bit(DataAbortIss::PFV)),
+    ("VNCR", bit(DataAbortIss::VNCR)),
+    ("SET", DataAbortIss::SET),
+    ("LST", DataAbortIss::LST),
+    ("FnV", bit(DataAbortIss::FNV)),
+    ("EA", bit(DataAbortIss::EA)),
+    ("CM", bit(DataAbortIss::CM)),
+    ("S1PTW", bit(DataAbortIss::S1PTW)),
+    ("WnR", bit(DataAbortIss::WNR)),
+    ("DFSC", DataAbortIss::DFSC),
+    ("SASV", iss2(bit(DataAbortIss::SASV))),
+    ("TnD", iss2(bit(DataAbortIss::TND))),
+    ("TagAccess", iss2(bit(DataAbortIss::TAG_ACCESS))),
+    ("GCS", iss2(bit(DataAbortIss::GCS))),
+    ("AssuredOnly", iss2(bit(DataAbortIss::ASSURED_ONLY))),
+    ("Overlay", iss2(bit(DataAbortIss::OVERLAY))),
+    ("DirtyBit", iss2(bit(DataAbortIss::DIRTY_BIT))),
+    ("Xs", iss2(DataAbortIss::XS)),
+];
+
 /// Decodes the ISS value for a Data Abort.
-pub fn decode_iss_data_abort(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let isv = FieldInfo::get_bit(iss, "ISV", Some("Instruction Syndrome Valid"), 24)
-        .describe_bit(describe_isv);
+pub fn decode_iss_data_abort(data_abort: DataAbortIss, features: Features) -> FieldList {
+    let iss = data_abort.iss();
+    let isv = StaticFieldInfo::new_bit(
+        "ISV",
+        Some("Instruction Syndrome Valid"),
+        DataAbortIss::ISV,
+        data_abort.isv(),
+    )
+    .describe_bit(describe_isv);
 
-    let intruction_syndrome_fields = if isv.as_bit() {
+    let intruction_syndrome_fields = if let (Some(sas), Some(sse), Some(srt), Some(sf), Some(ar)) = (
+        data_abort.sas(),
+        data_abort.sse(),
+        data_abort.srt(),
+        data_abort.sf(),
+        data_abort.ar(),
+    ) {
         // These fields are part of the instruction syndrome, and are only valid if ISV is true.
-        let sas = FieldInfo::get(iss, "SAS", Some("Syndrome Access Size"), 22, 24);
-        let sas_value = match sas.value {
-            0b00 => SyndromeAccessSize::Byte,
-            0b01 => SyndromeAccessSize::Halfword,
-            0b10 => SyndromeAccessSize::Word,
-            0b11 => SyndromeAccessSize::Doubleword,
-            _ => unreachable!(),
-        };
-        let sas = sas.with_description(sas_value.to_string());
-        let sse = FieldInfo::get_bit(iss, "SSE", Some("Syndrome Sign Extend"), 21);
-        let srt = FieldInfo::get(iss, "SRT", Some("Syndrome Register Transfer"), 16, 21);
-        let sf = FieldInfo::get_bit(iss, "SF", Some("Sixty-Four"), 15).describe_bit(describe_sf);
-        let ar =
-            FieldInfo::get_bit(iss, "AR", Some("Acquire/Release"), 14).describe_bit(describe_ar);
-        vec![sas, sse, srt, sf, ar]
+        let sas = StaticFieldInfo::new(
+            "SAS",
+            Some("Syndrome Access Size"),
+            DataAbortIss::SAS,
+            sas as u64,
+        )
+        .with_description(sas.description());
+        let sse =
+            StaticFieldInfo::new_bit("SSE", Some("Syndrome Sign Extend"), DataAbortIss::SSE, sse);
+        let srt = StaticFieldInfo::new(
+            "SRT",
+            Some("Syndrome Register Transfer"),
+            DataAbortIss::SRT,
+            srt,
+        );
+        let sf = StaticFieldInfo::new_bit("SF", Some("Sixty-Four"), DataAbortIss::SF, sf)
+            .describe_bit(describe_sf);
+        let ar = StaticFieldInfo::new_bit("AR", Some("Acquire/Release"), DataAbortIss::AR, ar)
+            .describe_bit(describe_ar);
+        FieldList::from([sas, sse, srt, sf, ar])
     } else {
-        let res0 = FieldInfo::get(iss, "RES0", Some("Reserved"), 14, 24).check_res0()?;
-        vec![res0]
+        // SAS may still be valid, if ISS2 says so.
+        let sas = match data_abort.sas() {
+            Some(sas) => FieldList::from([
+                StaticFieldInfo::new(
+                    "SAS",
+                    Some("Syndrome Access Size"),
+                    DataAbortIss::SAS,
+                    sas as u64,
+                )
+                .with_description(sas.description()),
+                StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 18, 22).check_res0(),
+            ]),
+            None => FieldList::from([
+                StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 18, 24).check_res0()
+            ]),
+        };
+        let wu = StaticFieldInfo::new(
+            "WU",
+            Some("Write Update"),
+            DataAbortIss::WU,
+            data_abort.wu().unwrap_or_default(),
+        );
+        let fnp = StaticFieldInfo::new_bit(
+            "FnP",
+            Some("FAR not Precise"),
+            DataAbortIss::FNP,
+            data_abort.fnp(),
+        )
+        .describe_bit(describe_fnp);
+        let pfv = StaticFieldInfo::new_bit(
+            "PFV",
+            Some("PFAR Valid"),
+            DataAbortIss::PFV,
+            data_abort.pfv(),
+        )
+        .describe_bit(describe_pfv);
+        let mut fields = sas;
+        fields.extend([wu, fnp, pfv]);
+        fields
     };
 
-    let vncr = FieldInfo::get_bit(iss, "VNCR", None, 13);
-    let fnv = FieldInfo::get_bit(iss, "FnV", Some("FAR not Valid"), 10).describe_bit(describe_fnv);
-    let ea = FieldInfo::get_bit(iss, "EA", Some("External abort type"), 9);
-    let cm = FieldInfo::get_bit(iss, "CM", Some("Cache Maintenance"), 8);
-    let s1ptw = FieldInfo::get_bit(iss, "S1PTW", Some("Stage-1 translation table walk"), 7);
-    let wnr = FieldInfo::get_bit(iss, "WnR", Some("Write not Read"), 6).describe_bit(describe_wnr);
-    let dfsc =
-        FieldInfo::get(iss, "DFSC", Some("Data Fault Status Code"), 0, 6).describe(describe_fsc)?;
-    let set = if dfsc.value == 0b010000 {
-        FieldInfo::get(iss, "SET", Some("Synchronous Error Type"), 11, 13).describe(describe_set)?
+    let vncr = decode_vncr(data_abort.vncr(), features);
+    let fnv = StaticFieldInfo::new_bit(
+        "FnV",
+        Some("FAR not Valid"),
+        DataAbortIss::FNV,
+        data_abort.fnv(),
+    )
+    .describe_bit(describe_fnv);
+    let ea = StaticFieldInfo::new_bit(
+        "EA",
+        Some("External abort type"),
+        DataAbortIss::EA,
+        data_abort.ea(),
+    );
+    let cm = StaticFieldInfo::new_bit(
+        "CM",
+        Some("Cache Maintenance"),
+        DataAbortIss::CM,
+        data_abort.cm(),
+    );
+    let s1ptw = StaticFieldInfo::new_bit(
+        "S1PTW",
+        Some("Stage-1 translation table walk"),
+        DataAbortIss::S1PTW,
+        data_abort.s1ptw(),
+    );
+    let wnr = StaticFieldInfo::new_bit(
+        "WnR",
+        Some("Write not Read"),
+        DataAbortIss::WNR,
+        data_abort.wnr(),
+    )
+    .describe_bit(describe_wnr);
+    // The raw value is used rather than `dfsc()` so that invalid values can still be shown.
+    let dfsc = StaticFieldInfo::new(
+        "DFSC",
+        Some("Data Fault Status Code"),
+        DataAbortIss::DFSC,
+        iss.get_bits(DataAbortIss::DFSC),
+    )
+    .describe(|fsc| describe_fsc(fsc, features));
+    let set = if let Some(set) = data_abort.set() {
+        decode_set(set, features)
+    } else if let Some(lst) = data_abort.lst() {
+        if features.ls64 {
+            StaticFieldInfo::new("LST", Some("Load/Store Type"), DataAbortIss::LST, lst)
+                .describe(describe_lst)
+        } else {
+            StaticFieldInfo::new(
+                "RES0",
+                Some("Reserved because FEAT_LS64 is not implemented"),
+                DataAbortIss::LST,
+                lst,
+            )
+            .check_res0()
+        }
     } else {
-        FieldInfo::get(iss, "RES0", Some("Reserved"), 11, 13)
+        StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 11, 13)
     };
 
-    let mut fields = vec![isv];
+    let mut fields = FieldList::from([isv]);
     fields.extend(intruction_syndrome_fields);
-    fields.extend(vec![vncr, set, fnv, ea, cm, s1ptw, wnr, dfsc]);
-    Ok(fields)
+    fields.extend([vncr, set, fnv, ea, cm, s1ptw, wnr, dfsc]);
+    fields
+}
+
+/// Decodes the ISS2 value for a Data Abort.
+pub fn decode_iss2_data_abort(data_abort: DataAbortIss) -> (FieldList, Option<&'static str>) {
+    let iss2 = data_abort.iss2();
+    let res0a = StaticFieldInfo::get(iss2, "RES0", Some("Reserved"), 13, 24).check_res0();
+    let sasv = StaticFieldInfo::new_bit(
+        "SASV",
+        Some("SAS Valid"),
+        DataAbortIss::SASV,
+        data_abort.sasv(),
+    )
+    .describe_bit(describe_sasv);
+    let res0b = StaticFieldInfo::get_bit(iss2, "RES0", Some("Reserved"), 11).check_res0();
+    let tnd = StaticFieldInfo::new_bit(
+        "TnD",
+        Some("Tag not Data"),
+        DataAbortIss::TND,
+        data_abort.tnd(),
+    )
+    .describe_bit(describe_tnd);
+    let gcs = StaticFieldInfo::new_bit(
+        "GCS",
+        Some("Guarded Control Stack data access"),
+        DataAbortIss::GCS,
+        data_abort.gcs(),
+    )
+    .describe_bit(describe_gcs);
+    let xs = StaticFieldInfo::new(
+        "Xs",
+        Some("Status register for ST64BV or ST64BV0"),
+        DataAbortIss::XS,
+        data_abort.xs(),
+    );
+
+    // These fields describe the cause of a permission fault, and are RES0 for other faults.
+    let (permission_fields, description) = if data_abort
+        .dfsc()
+        .is_ok_and(FaultStatusCode::is_permission_fault)
+    {
+        let tag_access = StaticFieldInfo::new_bit(
+            "TagAccess",
+            Some("NoTagAccess fault"),
+            DataAbortIss::TAG_ACCESS,
+            data_abort.tag_access(),
+        )
+        .describe_bit(describe_tag_access);
+        let assured_only = StaticFieldInfo::new_bit(
+            "AssuredOnly",
+            None,
+            DataAbortIss::ASSURED_ONLY,
+            data_abort.assured_only(),
+        )
+        .describe_bit(describe_assured_only);
+        let overlay = StaticFieldInfo::new_bit(
+            "Overlay",
+            Some("Overlay permission fault"),
+            DataAbortIss::OVERLAY,
+            data_abort.overlay(),
+        )
+        .describe_bit(describe_overlay);
+        let dirty_bit = StaticFieldInfo::new_bit(
+            "DirtyBit",
+            Some("Dirty state fault"),
+            DataAbortIss::DIRTY_BIT,
+            data_abort.dirty_bit(),
+        )
+        .describe_bit(describe_dirty_bit);
+        let description = if data_abort.overlay() {
+            "Permission fault due to Overlay permissions"
+        } else if data_abort.dirty_bit() {
+            "Permission fault due to dirty state"
+        } else if data_abort.assured_only() {
+            "Permission fault due to AssuredOnly"
+        } else if data_abort.tag_access() {
+            "Permission fault due to NoTagAccess"
+        } else {
+            "Permission fault due to base permissions"
+        };
+        (
+            [tag_access, assured_only, overlay, dirty_bit],
+            Some(description),
+        )
+    } else {
+        let reserved = |bit| {
+            StaticFieldInfo::get_bit(iss2, "RES0", Some("Reserved for this DFSC value"), bit)
+                .check_res0()
+        };
+        (
+            [
+                reserved(DataAbortIss::TAG_ACCESS),
+                reserved(DataAbortIss::ASSURED_ONLY),
+                reserved(DataAbortIss::OVERLAY),
+                reserved(DataAbortIss::DIRTY_BIT),
+            ],
+            None,
+        )
+    };
+    let [tag_access, assured_only, overlay, dirty_bit] = permission_fields;
+
+    (
+        FieldList::from([
+            res0a,
+            sasv,
+            res0b,
+            tnd,
+            tag_access,
+            gcs,
+            assured_only,
+            overlay,
+            dirty_bit,
+            xs,
+        ]),
+        description,
+    )
+}
+
+/// A Data Fault Status Code or Instruction Fault Status Code value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum FaultStatusCode {
+    /// Address size fault, level 0 of translation or translation table base register.
+    AddressSizeLevel0 = 0b000000,
+    /// Address size fault, level 1.
+    AddressSizeLevel1 = 0b000001,
+    /// Address size fault, level 2.
+    AddressSizeLevel2 = 0b000010,
+    /// Address size fault, level 3.
+    AddressSizeLevel3 = 0b000011,
+    /// Translation fault, level 0.
+    TranslationLevel0 = 0b000100,
+    /// Translation fault, level 1.
+    TranslationLevel1 = 0b000101,
+    /// Translation fault, level 2.
+    TranslationLevel2 = 0b000110,
+    /// Translation fault, level 3.
+    TranslationLevel3 = 0b000111,
+    /// Access flag fault, level 0.
+    AccessFlagLevel0 = 0b001000,
+    /// Access flag fault, level 1.
+    AccessFlagLevel1 = 0b001001,
+    /// Access flag fault, level 2.
+    AccessFlagLevel2 = 0b001010,
+    /// Access flag fault, level 3.
+    AccessFlagLevel3 = 0b001011,
+    /// Permission fault, level 0.
+    PermissionLevel0 = 0b001100,
+    /// Permission fault, level 1.
+    PermissionLevel1 = 0b001101,
+    /// Permission fault, level 2.
+    PermissionLevel2 = 0b001110,
+    /// Permission fault, level 3.
+    PermissionLevel3 = 0b001111,
+    /// Synchronous External abort, not on translation table walk or hardware update of translation
+    /// table.
+    SyncExternal = 0b010000,
+    /// Synchronous Tag Check Fault.
+    SyncTagCheck = 0b010001,
+    /// Synchronous External abort on translation table walk or hardware update of translation
+    /// table, level -1.
+    SyncExternalOnWalkLevelMinus1 = 0b010011,
+    /// Synchronous External abort on translation table walk or hardware update of translation
+    /// table, level 0.
+    SyncExternalOnWalkLevel0 = 0b010100,
+    /// Synchronous External abort on translation table walk or hardware update of translation
+    /// table, level 1.
+    SyncExternalOnWalkLevel1 = 0b010101,
+    /// Synchronous External abort on translation table walk or hardware update of translation
+    /// table, level 2.
+    SyncExternalOnWalkLevel2 = 0b010110,
+    /// Synchronous External abort on translation table walk or hardware update of translation
+    /// table, level 3.
+    SyncExternalOnWalkLevel3 = 0b010111,
+    /// Synchronous parity or ECC error on memory access, not on translation table walk.
+    SyncParityEcc = 0b011000,
+    /// Synchronous parity or ECC error on memory access on translation table walk or hardware
+    /// update of translation table, level -1.
+    SyncParityEccOnWalkLevelMinus1 = 0b011011,
+    /// Synchronous parity or ECC error on memory access on translation table walk or hardware
+    /// update of translation table, level 0.
+    SyncParityEccOnWalkLevel0 = 0b011100,
+    /// Synchronous parity or ECC error on memory access on translation table walk or hardware
+    /// update of translation table, level 1.
+    SyncParityEccOnWalkLevel1 = 0b011101,
+    /// Synchronous parity or ECC error on memory access on translation table walk or hardware
+    /// update of translation table, level 2.
+    SyncParityEccOnWalkLevel2 = 0b011110,
+    /// Synchronous parity or ECC error on memory access on translation table walk or hardware
+    /// update of translation table, level 3.
+    SyncParityEccOnWalkLevel3 = 0b011111,
+    /// Alignment fault.
+    Alignment = 0b100001,
+    /// Granule Protection Fault on translation table walk or hardware update of translation table,
+    /// level -1.
+    GranuleProtectionOnWalkLevelMinus1 = 0b100011,
+    /// Granule Protection Fault on translation table walk or hardware update of translation table,
+    /// level 0.
+    GranuleProtectionOnWalkLevel0 = 0b100100,
+    /// Granule Protection Fault on translation table walk or hardware update of translation table,
+    /// level 1.
+    GranuleProtectionOnWalkLevel1 = 0b100101,
+    /// Granule Protection Fault on translation table walk or hardware update of translation table,
+    /// level 2.
+    GranuleProtectionOnWalkLevel2 = 0b100110,
+    /// Granule Protection Fault on translation table walk or hardware update of translation table,
+    /// level 3.
+    GranuleProtectionOnWalkLevel3 = 0b100111,
+    /// Granule Protection Fault, not on translation table walk or hardware update of translation
+    /// table.
+    GranuleProtection = 0b101000,
+    /// Address size fault, level -1.
+    AddressSizeLevelMinus1 = 0b101001,
+    /// Translation fault, level -1.
+    TranslationLevelMinus1 = 0b101011,
+    /// TLB conflict abort.
+    TlbConflict = 0b110000,
+    /// Unsupported atomic hardware update fault.
+    UnsupportedAtomicHardwareUpdate = 0b110001,
+    /// IMPLEMENTATION DEFINED fault (Lockdown).
+    ImpDefLockdown = 0b110100,
+    /// IMPLEMENTATION DEFINED fault (Unsupported Exclusive or Atomic access).
+    ImpDefUnsupportedExclusiveOrAtomic = 0b110101,
+}
+
+impl FaultStatusCode {
+    /// Returns a description of the fault.
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::AddressSizeLevel0 => {
+                "Address size fault, level 0 of translation or translation table base register."
+            }
+            Self::AddressSizeLevel1 => "Address size fault, level 1.",
+            Self::AddressSizeLevel2 => "Address size fault, level 2.",
+            Self::AddressSizeLevel3 => "Address size fault, level 3.",
+            Self::TranslationLevel0 => "Translation fault, level 0.",
+            Self::TranslationLevel1 => "Translation fault, level 1.",
+            Self::TranslationLevel2 => "Translation fault, level 2.",
+            Self::TranslationLevel3 => "Translation fault, level 3.",
+            Self::AccessFlagLevel0 => "Access flag fault, level 0.",
+            Self::AccessFlagLevel1 => "Access flag fault, level 1.",
+            Self::AccessFlagLevel2 => "Access flag fault, level 2.",
+            Self::AccessFlagLevel3 => "Access flag fault, level 3.",
+            Self::PermissionLevel0 => "Permission fault, level 0.",
+            Self::PermissionLevel1 => "Permission fault, level 1.",
+            Self::PermissionLevel2 => "Permission fault, level 2.",
+            Self::PermissionLevel3 => "Permission fault, level 3.",
+            Self::SyncExternal => {
+                "Synchronous External abort, not on translation table walk or hardware update of \
+                 translation table."
+            }
+            Self::SyncTagCheck => "Synchronous Tag Check Fault.",
+            Self::SyncExternalOnWalkLevelMinus1 => {
+                "Synchronous External abort on translation table walk or hardware update of \
+                 translation table, level -1."
+            }
+            Self::SyncExternalOnWalkLevel0 => {
+                "Synchronous External abort on translation table walk or hardware update of \
+                 translation table, level 0."
+            }
+            Self::SyncExternalOnWalkLevel1 => {
+                "Synchronous External abort on translation table walk or hardware update of \
+                 translation table, level 1."
+            }
+            Self::SyncExternalOnWalkLevel2 => {
+                "Synchronous External abort on translation table walk or hardware update of \
+                 translation table, level 2."
+            }
+            Self::SyncExternalOnWalkLevel3 => {
+                "Synchronous External abort on translation table walk or hardware update of \
+                 translation table, level 3."
+            }
+            Self::SyncParityEcc => {
+                "Synchronous parity or ECC error on memory access, not on translation table walk."
+            }
+            Self::SyncParityEccOnWalkLevelMinus1 => {
+                "Synchronous parity or ECC error on memory access on translation table walk or \
+                 hardware update of translation table, level -1."
+            }
+            Self::SyncParityEccOnWalkLevel0 => {
+                "Synchronous parity or ECC error on memory access on translation table walk or \
+                 hardware update of translation table, level 0."
+            }
+            Self::SyncParityEccOnWalkLevel1 => {
+                "Synchronous parity or ECC error on memory access on translation table walk or \
+                 hardware update of translation table, level 1."
+            }
+            Self::SyncParityEccOnWalkLevel2 => {
+                "Synchronous parity or ECC error on memory access on translation table walk or \
+                 hardware update of translation table, level 2."
+            }
+            Self::SyncParityEccOnWalkLevel3 => {
+                "Synchronous parity or ECC error on memory access on translation table walk or \
+                 hardware update of translation table, level 3."
+            }
+            Self::Alignment => "Alignment fault.",
+            Self::GranuleProtectionOnWalkLevelMinus1 => {
+                "Granule Protection Fault on translation table walk or hardware update of \
+                translation table, level -1."
+            }
+            Self::GranuleProtectionOnWalkLevel0 => {
+                "Granule Protection Fault on translation table walk or hardware update of \
+                translation table, level 0."
+            }
+            Self::GranuleProtectionOnWalkLevel1 => {
+                "Granule Protection Fault on translation table walk or hardware update of \
+                translation table, level 1."
+            }
+            Self::GranuleProtectionOnWalkLevel2 => {
+                "Granule Protection Fault on translation table walk or hardware update of \
+                translation table, level 2."
+            }
+            Self::GranuleProtectionOnWalkLevel3 => {
+                "Granule Protection Fault on translation table walk or hardware update of \
+                translation table, level 3."
+            }
+            Self::GranuleProtection => {
+                "Granule Protection Fault, not on translation table walk or hardware update of \
+                translation table."
+            }
+            Self::AddressSizeLevelMinus1 => "Address size fault, level -1.",
+            Self::TranslationLevelMinus1 => "Translation fault, level -1.",
+            Self::TlbConflict => "TLB conflict abort.",
+            Self::UnsupportedAtomicHardwareUpdate => "Unsupported atomic hardware update fault.",
+            Self::ImpDefLockdown => "IMPLEMENTATION DEFINED fault (Lockdown).",
+            Self::ImpDefUnsupportedExclusiveOrAtomic => {
+                "IMPLEMENTATION DEFINED fault (Unsupported Exclusive or Atomic access)."
+            }
+        }
+    }
+
+    /// Returns whether this is a fault at level -1, which is only possible with FEAT_LPA2.
+    pub fn is_level_minus_1(self) -> bool {
+        matches!(
+            self,
+            Self::SyncExternalOnWalkLevelMinus1
+                | Self::SyncParityEccOnWalkLevelMinus1
+                | Self::GranuleProtectionOnWalkLevelMinus1
+                | Self::AddressSizeLevelMinus1
+                | Self::TranslationLevelMinus1
+        )
+    }
+
+    /// Returns whether this is a permission fault, at any level.
+    pub fn is_permission_fault(self) -> bool {
+        matches!(
+            self,
+            Self::PermissionLevel0
+                | Self::PermissionLevel1
+                | Self::PermissionLevel2
+                | Self::PermissionLevel3
+        )
+    }
+}
+
+impl TryFrom<u64> for FaultStatusCode {
+    type Error = DecodeError;
+
+    fn try_from(fsc: u64) -> Result<Self, Self::Error> {
+        Ok(match fsc {
+            0b000000 => Self::AddressSizeLevel0,
+            0b000001 => Self::AddressSizeLevel1,
+            0b000010 => Self::AddressSizeLevel2,
+            0b000011 => Self::AddressSizeLevel3,
+            0b000100 => Self::TranslationLevel0,
+            0b000101 => Self::TranslationLevel1,
+            0b000110 => Self::TranslationLevel2,
+            0b000111 => Self::TranslationLevel3,
+            0b001000 => Self::AccessFlagLevel0,
+            0b001001 => Self::AccessFlagLevel1,
+            0b001010 => Self::AccessFlagLevel2,
+            0b001011 => Self::AccessFlagLevel3,
+            0b001100 => Self::PermissionLevel0,
+            0b001101 => Self::PermissionLevel1,
+            0b001110 => Self::PermissionLevel2,
+            0b001111 => Self::PermissionLevel3,
+            0b010000 => Self::SyncExternal,
+            0b010001 => Self::SyncTagCheck,
+            0b010011 => Self::SyncExternalOnWalkLevelMinus1,
+            0b010100 => Self::SyncExternalOnWalkLevel0,
+            0b010101 => Self::SyncExternalOnWalkLevel1,
+            0b010110 => Self::SyncExternalOnWalkLevel2,
+            0b010111 => Self::SyncExternalOnWalkLevel3,
+            0b011000 => Self::SyncParityEcc,
+            0b011011 => Self::SyncParityEccOnWalkLevelMinus1,
+            0b011100 => Self::SyncParityEccOnWalkLevel0,
+            0b011101 => Self::SyncParityEccOnWalkLevel1,
+            0b011110 => Self::SyncParityEccOnWalkLevel2,
+            0b011111 => Self::SyncParityEccOnWalkLevel3,
+            0b100001 => Self::Alignment,
+            0b100011 => Self::GranuleProtectionOnWalkLevelMinus1,
+            0b100100 => Self::GranuleProtectionOnWalkLevel0,
+            0b100101 => Self::GranuleProtectionOnWalkLevel1,
+            0b100110 => Self::GranuleProtectionOnWalkLevel2,
+            0b100111 => Self::GranuleProtectionOnWalkLevel3,
+            0b101000 => Self::GranuleProtection,
+            0b101001 => Self::AddressSizeLevelMinus1,
+            0b101011 => Self::TranslationLevelMinus1,
+            0b110000 => Self::TlbConflict,
+            0b110001 => Self::UnsupportedAtomicHardwareUpdate,
+            0b110100 => Self::ImpDefLockdown,
+            0b110101 => Self::ImpDefUnsupportedExclusiveOrAtomic,
+            _ => return Err(DecodeError::InvalidFsc { fsc }),
+        })
+    }
 }
 
+/// The size of the access which caused a Data Abort.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-enum SyndromeAccessSize {
+pub enum SyndromeAccessSize {
+    /// A single byte.
     Byte = 0b00,
+    /// A halfword (16 bits).
     Halfword = 0b01,
+    /// A word (32 bits).
     Word = 0b10,
+    /// A doubleword (64 bits).
     Doubleword = 0b11,
 }
 
-impl Display for SyndromeAccessSize {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        let s = match self {
+impl From<u64> for SyndromeAccessSize {
+    fn from(sas: u64) -> Self {
+        match sas & 0b11 {
+            0b00 => Self::Byte,
+            0b01 => Self::Halfword,
+            0b10 => Self::Word,
+            0b11 => Self::Doubleword,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl SyndromeAccessSize {
+    /// Returns a human-readable description of the access size.
+    pub fn description(self) -> &'static str {
+        match self {
             Self::Byte => "byte",
             Self::Halfword => "halfword",
             Self::Word => "word",
             Self::Doubleword => "doubleword",
-        };
-        write!(f, "{s}")
+        }
+    }
+}
+
+impl Display for SyndromeAccessSize {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
     }
 }
 
@@ -134,7 +971,41 @@ fn describe_fnv(fnv: bool) -> &'static str {
     }
 }
 
-fn describe_wnr(wnr: bool) -> &'static str {
+fn describe_fnp(fnp: bool) -> &'static str {
+    if fnp {
+        "FAR is not precise, it holds an address within the same translation granule"
+    } else {
+        "FAR holds the faulting virtual address, if FnV is false"
+    }
+}
+
+fn describe_pfv(pfv: bool) -> &'static str {
+    if pfv {
+        "PFAR holds the faulting physical address"
+    } else {
+        "PFAR is not valid"
+    }
+}
+
+fn describe_sasv(sasv: bool) -> &'static str {
+    if sasv {
+        "SAS is valid even if ISV is false"
+    } else {
+        "SAS is only valid if ISV is true"
+    }
+}
+
+fn describe_lst(lst: u64) -> Result<&'static str, DecodeError> {
+    Ok(match lst {
+        0b00 => "The instruction that generated the Data Abort is not specified",
+        0b01 => "An ST64BV instruction generated the Data Abort",
+        0b10 => "An LD64B or ST64B instruction generated the Data Abort",
+        0b11 => "An ST64BV0 instruction generated the Data Abort",
+        _ => unreachable!(),
+    })
+}
+
+pub fn describe_wnr(wnr: bool) -> &'static str {
     if wnr {
         "Abort caused by writing to memory"
     } else {
@@ -142,108 +1013,83 @@ fn describe_wnr(wnr: bool) -> &'static str {
     }
 }
 
-fn describe_fsc(fsc: u64) -> Result<&'static str, DecodeError> {
-    let description = match fsc {
-        0b000000 => {
-            "Address size fault, level 0 of translation or translation table base register."
-        }
-        0b000001 => "Address size fault, level 1.",
-        0b000010 => "Address size fault, level 2.",
-        0b000011 => "Address size fault, level 3.",
-        0b000100 => "Translation fault, level 0.",
-        0b000101 => "Translation fault, level 1.",
-        0b000110 => "Translation fault, level 2.",
-        0b000111 => "Translation fault, level 3.",
-        0b001001 => "Access flag fault, level 1.",
-        0b001010 => "Access flag fault, level 2.",
-        0b001011 => "Access flag fault, level 3.",
-        0b001000 => "Access flag fault, level 0.",
-        0b001100 => "Permission fault, level 0.",
-        0b001101 => "Permission fault, level 1.",
-        0b001110 => "Permission fault, level 2.",
-        0b001111 => "Permission fault, level 3.",
-        0b010000 => {
-            "Synchronous External abort, not on translation table walk or hardware update of \
-             translation table."
-        }
-        0b010001 => "Synchronous Tag Check Fault.",
-        0b010011 => {
-            "Synchronous External abort on translation table walk or hardware update of \
-             translation table, level -1."
-        }
-        0b010100 => {
-            "Synchronous External abort on translation table walk or hardware update of \
-             translation table, level 0."
-        }
-        0b010101 => {
-            "Synchronous External abort on translation table walk or hardware update of \
-             translation table, level 1."
-        }
-        0b010110 => {
-            "Synchronous External abort on translation table walk or hardware update of \
-             translation table, level 2."
-        }
-        0b010111 => {
-            "Synchronous External abort on translation table walk or hardware update of \
-             translation table, level 3."
-        }
-        0b011000 => {
-            "Synchronous parity or ECC error on memory access, not on translation table walk."
-        }
-        0b011011 => {
-            "Synchronous parity or ECC error on memory access on translation table walk or \
-             hardware update of translation table, level -1."
-        }
-        0b011100 => {
-            "Synchronous parity or ECC error on memory access on translation table walk or \
-             hardware update of translation table, level 0."
-        }
-        0b011101 => {
-            "Synchronous parity or ECC error on memory access on translation table walk or \
-             hardware update of translation table, level 1."
-        }
-        0b011110 => {
-            "Synchronous parity or ECC error on memory access on translation table walk or \
-             hardware update of translation table, level 2."
-        }
-        0b011111 => {
-            "Synchronous parity or ECC error on memory access on translation table walk or \
-             hardware update of translation table, level 3."
-        }
-        0b100001 => "Alignment fault.",
-        0b100011 => {
-            "Granule Protection Fault on translation table walk or hardware update of \
-            translation table, level -1."
-        }
-        0b100100 => {
-            "Granule Protection Fault on translation table walk or hardware update of \
-            translation table, level 0."
-        }
-        0b100101 => {
-            "Granule Protection Fault on translation table walk or hardware update of \
-            translation table, level 1."
-        }
-        0b100110 => {
-            "Granule Protection Fault on translation table walk or hardware update of \
-            translation table, level 2."
-        }
-        0b100111 => {
-            "Granule Protection Fault on translation table walk or hardware update of \
-            translation table, level 3."
-        }
-        0b101000 => {
-            "Granule Protection Fault, not on translation table walk or hardware update of \
-            translation table."
-        }
-        0b101001 => "Address size fault, level -1.",
-        0b101011 => "Translation fault, level -1.",
-        0b110000 => "TLB conflict abort.",
-        0b110001 => "Unsupported atomic hardware update fault.",
-        0b110100 => "IMPLEMENTATION DEFINED fault (Lockdown).",
-        0b110101 => "IMPLEMENTATION DEFINED fault (Unsupported Exclusive or Atomic access).",
-        _ => return Err(DecodeError::InvalidFsc { fsc }),
-    };
-    Ok(description)
+fn describe_tnd(tnd: bool) -> &'static str {
+    if tnd {
+        "Fault on an Allocation Tag access"
+    } else {
+        "Fault not on an Allocation Tag access"
+    }
+}
+
+fn describe_tag_access(tag_access: bool) -> &'static str {
+    if tag_access {
+        "Permission fault on an Allocation Tag access to a page without tag access permission"
+    } else {
+        "Permission fault not caused by NoTagAccess"
+    }
+}
+
+fn describe_assured_only(assured_only: bool) -> &'static str {
+    if assured_only {
+        "Permission fault caused by the AssuredOnly attribute"
+    } else {
+        "Permission fault not caused by the AssuredOnly attribute"
+    }
+}
+
+fn describe_overlay(overlay: bool) -> &'static str {
+    if overlay {
+        "Permission fault caused by Overlay permissions"
+    } else {
+        "Permission fault not caused by Overlay permissions"
+    }
+}
+
+fn describe_dirty_bit(dirty_bit: bool) -> &'static str {
+    if dirty_bit {
+        "Permission fault caused by a write to a page in the clean state"
+    } else {
+        "Permission fault not caused by dirty state"
+    }
+}
+
+fn describe_gcs(gcs: bool) -> &'static str {
+    if gcs {
+        "Fault caused by a Guarded Control Stack data access"
+    } else {
+        "Fault not caused by a Guarded Control Stack data access"
+    }
+}
+
+/// Describes the given fault status code, or returns an error if it isn't valid or needs a feature
+/// which isn't implemented.
+pub fn describe_fsc(fsc: u64, features: Features) -> Result<&'static str, DecodeError> {
+    let fault_status_code = FaultStatusCode::try_from(fsc)?;
+    if fault_status_code.is_level_minus_1() && !features.lpa2 {
+        return Err(DecodeError::InvalidFsc { fsc });
+    }
+    Ok(fault_status_code.description())
+}
+
+/// Decodes the SET field of an abort, which is RES0 unless FEAT_RAS is implemented.
+fn decode_set(set: u64, features: Features) -> StaticFieldInfo {
+    if features.ras {
+        StaticFieldInfo::new(
+            "SET",
+            Some("Synchronous Error Type"),
+            InstructionAbortIss::SET,
+            set,
+        )
+        .describe(describe_set)
+    } else {
+        StaticFieldInfo::new(
+            "RES0",
+            Some("Reserved because FEAT_RAS is not implemented"),
+            InstructionAbortIss::SET,
+            set,
+        )
+        .check_res0()
+    }
 }
 
 fn describe_set(set: u64) -> Result<&'static str, DecodeError> {
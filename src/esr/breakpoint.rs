@@ -12,52 +12,217 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{DecodeError, FieldInfo};
+use super::Features;
+use super::common::decode_vncr;
+use super::{IssField, bit};
+use crate::{DecodeError, FieldList, StaticFieldInfo};
+use bit_field::BitField;
+use core::fmt::{self, Formatter};
+use core::ops::Range;
+
+/// The non-reserved ISS fields which may be present for a Breakpoint or Vector Catch debug
+/// exception.
+pub const BREAKPOINT_VECTOR_CATCH_FIELDS: &[IssField] = &[("IFSC", 0..6)];
 
 /// Decodes the ISS value for a Breakpoint or Vector Catch debug exception.
-pub fn decode_iss_breakpoint_vector_catch(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let res0 = FieldInfo::get(iss, "RES0", Some("Reserved"), 6, 25).check_res0()?;
-    let ifsc = FieldInfo::get(iss, "IFSC", Some("Instruction Fault Status Code"), 0, 6)
-        .describe(describe_fsc)?;
+pub fn decode_iss_breakpoint_vector_catch(iss: u64) -> FieldList {
+    let res0 = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 6, 25).check_res0();
+    let ifsc = StaticFieldInfo::get(iss, "IFSC", Some("Instruction Fault Status Code"), 0, 6)
+        .describe(describe_fsc);
 
-    Ok(vec![res0, ifsc])
+    FieldList::from([res0, ifsc])
 }
 
+/// The non-reserved ISS fields which may be present for a Software Step exception.
+pub const SOFTWARE_STEP_FIELDS: &[IssField] = &[("ISV", 24..25), ("EX", 6..7), ("IFSC", 0..6)];
+
 /// Decodes the ISS value for a Software Step exception.
-pub fn decode_iss_software_step(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let isv = FieldInfo::get_bit(iss, "ISV", Some("Instruction Syndrome Valid"), 24)
+pub fn decode_iss_software_step(iss: u64) -> FieldList {
+    let isv = StaticFieldInfo::get_bit(iss, "ISV", Some("Instruction Syndrome Valid"), 24)
         .describe_bit(describe_isv);
-    let res0 = FieldInfo::get(iss, "RES0", Some("Reserved"), 7, 24).check_res0()?;
+    let res0 = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 7, 24).check_res0();
     let ex = if isv.as_bit() {
-        FieldInfo::get_bit(iss, "EX", Some("Exclusive operation"), 6).describe_bit(describe_ex)
+        StaticFieldInfo::get_bit(iss, "EX", Some("Exclusive operation"), 6)
+            .describe_bit(describe_ex)
     } else {
-        FieldInfo::get_bit(iss, "RES0", Some("Reserved because ISV is false"), 6).check_res0()?
+        StaticFieldInfo::get_bit(iss, "RES0", Some("Reserved because ISV is false"), 6).check_res0()
     };
-    let ifsc = FieldInfo::get(iss, "IFSC", Some("Instruction Fault Status Code"), 0, 6)
-        .describe(describe_fsc)?;
+    let ifsc = StaticFieldInfo::get(iss, "IFSC", Some("Instruction Fault Status Code"), 0, 6)
+        .describe(describe_fsc);
+
+    FieldList::from([isv, res0, ex, ifsc])
+}
+
+/// The ISS value of a Watchpoint exception.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct WatchpointIss {
+    iss: u64,
+}
+
+impl WatchpointIss {
+    const WPT: Range<usize> = 18..24;
+    const WPTV: usize = 17;
+    const WPF: usize = 16;
+    const FNP: usize = 15;
+    const VNCR: usize = 13;
+    const CM: usize = 8;
+    const WNR: usize = 6;
+    const DFSC: Range<usize> = 0..6;
+
+    /// Creates a new `WatchpointIss` from the given ISS field value.
+    pub fn new(iss: u64) -> Self {
+        Self { iss }
+    }
+
+    /// Returns the raw ISS value.
+    pub fn iss(self) -> u64 {
+        self.iss
+    }
+
+    /// Returns the WPT field, which is the number of the watchpoint that triggered if `wptv` is
+    /// true.
+    pub fn wpt(self) -> u64 {
+        self.iss.get_bits(Self::WPT)
+    }
+
+    /// Returns whether the WPT field holds the number of the watchpoint that triggered.
+    pub fn wptv(self) -> bool {
+        self.iss.get_bit(Self::WPTV)
+    }
+
+    /// Returns the number of the watchpoint that triggered, if it is known.
+    pub fn watchpoint_number(self) -> Option<u64> {
+        self.wptv().then(|| self.wpt())
+    }
+
+    /// Returns whether the watchpoint might have been triggered by a false match.
+    pub fn wpf(self) -> bool {
+        self.iss.get_bit(Self::WPF)
+    }
+
+    /// Returns whether FAR is not precise.
+    pub fn fnp(self) -> bool {
+        self.iss.get_bit(Self::FNP)
+    }
+
+    /// Returns whether the watchpoint was triggered by an access using VNCR_EL2.
+    pub fn vncr(self) -> bool {
+        self.iss.get_bit(Self::VNCR)
+    }
+
+    /// Returns whether the watchpoint was triggered by a cache maintenance instruction.
+    pub fn cm(self) -> bool {
+        self.iss.get_bit(Self::CM)
+    }
+
+    /// Returns whether the watchpoint was triggered by writing to memory rather than reading.
+    pub fn wnr(self) -> bool {
+        self.iss.get_bit(Self::WNR)
+    }
 
-    Ok(vec![isv, res0, ex, ifsc])
+    /// Returns the raw Data Fault Status Code, which is always 0b100010 for a valid watchpoint.
+    pub fn dfsc(self) -> u64 {
+        self.iss.get_bits(Self::DFSC)
+    }
 }
 
+/// The non-reserved ISS fields which may be present for a Watchpoint exception.
+pub const WATCHPOINT_FIELDS: &[IssField] = &[
+    ("WPT", WatchpointIss::WPT),
+    ("WPTV", bit(WatchpointIss::WPTV)),
+    ("WPF", bit(WatchpointIss::WPF)),
+    ("FnP", bit(WatchpointIss::FNP)),
+    ("VNCR", bit(WatchpointIss::VNCR)),
+    ("CM", bit(WatchpointIss::CM)),
+    ("WnR", bit(WatchpointIss::WNR)),
+    ("DFSC", WatchpointIss::DFSC),
+];
+
 /// Decodes the ISS value for a Watchpoint exception.
-pub fn decode_iss_watchpoint(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let res0a = FieldInfo::get(iss, "RES0", Some("Reserved"), 15, 25).check_res0()?;
-    let res0b = FieldInfo::get_bit(iss, "RES0", Some("Reserved"), 14).check_res0()?;
-    let vncr = FieldInfo::get_bit(iss, "VNCR", None, 13);
-    let res0c = FieldInfo::get(iss, "RES0", Some("Reserved"), 9, 13).check_res0()?;
-    let cm = FieldInfo::get_bit(iss, "CM", Some("Cache Maintenance"), 8);
-    let res0d = FieldInfo::get_bit(iss, "RES0", Some("Reserved"), 7).check_res0()?;
-    let wnr = FieldInfo::get_bit(iss, "WnR", Some("Write not Read"), 6).describe_bit(describe_wnr);
-    let dfsc =
-        FieldInfo::get(iss, "DFSC", Some("Data Fault Status Code"), 0, 6).describe(describe_fsc)?;
-
-    Ok(vec![res0a, res0b, vncr, res0c, cm, res0d, wnr, dfsc])
+pub fn decode_iss_watchpoint(watchpoint: WatchpointIss, features: Features) -> FieldList {
+    let iss = watchpoint.iss();
+    let res0a = StaticFieldInfo::get_bit(iss, "RES0", Some("Reserved"), 24).check_res0();
+    let wpt = StaticFieldInfo::new(
+        "WPT",
+        Some("Watchpoint number"),
+        WatchpointIss::WPT,
+        watchpoint.wpt(),
+    );
+    let wptv = StaticFieldInfo::new_bit(
+        "WPTV",
+        Some("Watchpoint number Valid"),
+        WatchpointIss::WPTV,
+        watchpoint.wptv(),
+    )
+    .describe_bit(describe_wptv);
+    let wpf = StaticFieldInfo::new_bit(
+        "WPF",
+        Some("Watchpoint might be false-positive"),
+        WatchpointIss::WPF,
+        watchpoint.wpf(),
+    )
+    .describe_bit(describe_wpf);
+    let fnp = StaticFieldInfo::new_bit(
+        "FnP",
+        Some("FAR not Precise"),
+        WatchpointIss::FNP,
+        watchpoint.fnp(),
+    )
+    .describe_bit(describe_fnp);
+    let res0b = StaticFieldInfo::get_bit(iss, "RES0", Some("Reserved"), 14).check_res0();
+    let vncr = decode_vncr(watchpoint.vncr(), features);
+    let res0c = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 9, 13).check_res0();
+    let cm = StaticFieldInfo::new_bit(
+        "CM",
+        Some("Cache Maintenance"),
+        WatchpointIss::CM,
+        watchpoint.cm(),
+    );
+    let res0d = StaticFieldInfo::get_bit(iss, "RES0", Some("Reserved"), 7).check_res0();
+    let wnr = StaticFieldInfo::new_bit(
+        "WnR",
+        Some("Write not Read"),
+        WatchpointIss::WNR,
+        watchpoint.wnr(),
+    )
+    .describe_bit(describe_wnr);
+    let dfsc = StaticFieldInfo::new(
+        "DFSC",
+        Some("Data Fault Status Code"),
+        WatchpointIss::DFSC,
+        watchpoint.dfsc(),
+    )
+    .describe(describe_fsc);
+
+    FieldList::from([
+        res0a, wpt, wptv, wpf, fnp, res0b, vncr, res0c, cm, res0d, wnr, dfsc,
+    ])
+}
+
+/// Writes which watchpoint was triggered, assuming that it is known.
+///
+/// Watchpoints 16 to 63 are accessed through the same registers as watchpoints 0 to 15, with the
+/// bank selected by MDSELR_EL1.BANK.
+pub fn fmt_iss_watchpoint(f: &mut Formatter, watchpoint: WatchpointIss) -> fmt::Result {
+    let n = watchpoint.wpt();
+    let index = n % 16;
+    write!(
+        f,
+        "Watchpoint {n} triggered (DBGWVR{index}_EL1 and DBGWCR{index}_EL1"
+    )?;
+    if n >= 16 {
+        write!(f, " with MDSELR_EL1.BANK {}", n / 16)?;
+    }
+    f.write_str(")")
 }
 
+/// The non-reserved ISS fields which may be present for a Breakpoint instruction.
+pub const BREAKPOINT_FIELDS: &[IssField] = &[("Comment", 0..16)];
+
 /// Decodes the ISS value for a Breakpoint instruction.
-pub fn decode_iss_breakpoint(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let res0 = FieldInfo::get(iss, "RES0", Some("Reserved"), 16, 25).check_res0()?;
-    let comment = FieldInfo::get(
+pub fn decode_iss_breakpoint(iss: u64) -> FieldList {
+    let res0 = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 16, 25).check_res0();
+    let comment = StaticFieldInfo::get(
         iss,
         "Comment",
         Some("Instruction comment field or immediate field"),
@@ -65,7 +230,7 @@ pub fn decode_iss_breakpoint(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
         16,
     );
 
-    Ok(vec![res0, comment])
+    FieldList::from([res0, comment])
 }
 
 fn describe_fsc(fsc: u64) -> Result<&'static str, DecodeError> {
@@ -91,6 +256,30 @@ fn describe_ex(ex: bool) -> &'static str {
     }
 }
 
+fn describe_wptv(wptv: bool) -> &'static str {
+    if wptv {
+        "WPT holds the number of the watchpoint that triggered"
+    } else {
+        "WPT is not valid"
+    }
+}
+
+fn describe_wpf(wpf: bool) -> &'static str {
+    if wpf {
+        "The watchpoint might have been triggered by a false match"
+    } else {
+        "The watchpoint matched the address of the access"
+    }
+}
+
+fn describe_fnp(fnp: bool) -> &'static str {
+    if fnp {
+        "FAR is not precise, it holds an address within the watched range"
+    } else {
+        "FAR holds the address of the access that triggered the watchpoint"
+    }
+}
+
 fn describe_wnr(wnr: bool) -> &'static str {
     if wnr {
         "Watchpoint caused by writing to memory"
@@ -12,12 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{DecodeError, FieldInfo};
+use super::IssField;
+use crate::{FieldList, StaticFieldInfo};
+
+/// The non-reserved ISS fields which may be present for a Branch Target Exception.
+pub const BTI_FIELDS: &[IssField] = &[("BTYPE", 0..2)];
 
 /// Decodes the ISS value for a Branch Target Exception.
-pub fn decode_iss_bti(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let res0 = FieldInfo::get(iss, "RES0", Some("Reserved"), 2, 25).check_res0()?;
-    let btype = FieldInfo::get(iss, "BTYPE", Some("PSTATE.BTYPE value"), 0, 2);
+pub fn decode_iss_bti(iss: u64) -> FieldList {
+    let res0 = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 2, 25).check_res0();
+    let btype = StaticFieldInfo::get(iss, "BTYPE", Some("PSTATE.BTYPE value"), 0, 2);
 
-    Ok(vec![res0, btype])
+    FieldList::from([res0, btype])
 }
@@ -0,0 +1,336 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::IMPDEF_FIELDS;
+use super::abort::{DATA_ABORT_FIELDS, INSTRUCTION_ABORT_FIELDS};
+use super::breakpoint::{
+    BREAKPOINT_FIELDS, BREAKPOINT_VECTOR_CATCH_FIELDS, SOFTWARE_STEP_FIELDS, WATCHPOINT_FIELDS,
+};
+use super::bti::BTI_FIELDS;
+use super::eret::ERET_FIELDS;
+use super::fp::FP_FIELDS;
+use super::gcs::GCS_FIELDS;
+use super::gpc::GPC_FIELDS;
+use super::hvc::{HVC_FIELDS, SMC32_FIELDS};
+use super::ld64b::LD64B_FIELDS;
+use super::ldc::LDC_FIELDS;
+use super::mcr::{MCR_FIELDS, MCRR_FIELDS};
+use super::mops::MOPS_FIELDS;
+use super::msr::MSR_FIELDS;
+use super::msrr::MSRR_FIELDS;
+use super::pauth::PAUTH_FIELDS;
+use super::pmu::PMU_FIELDS;
+use super::serror::SERROR_FIELDS;
+use super::sme::SME_FIELDS;
+use super::sve::SVE_FIELDS;
+use super::tstart::TSTART_FIELDS;
+use super::wf::WF_FIELDS;
+use crate::DecodeError;
+use core::ops::Range;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// The Exception Class of an ESR value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ExceptionClass {
+    /// Unknown reason.
+    Unknown = 0b000000,
+    /// Wrapped WF* instruction execution.
+    Wf = 0b000001,
+    /// Trapped MCR or MRC access with coproc=0b1111.
+    McrMrc15 = 0b000011,
+    /// Trapped MCRR or MRRC access with coproc=0b1111.
+    McrrMrrc15 = 0b000100,
+    /// Trapped MCR or MRC access with coproc=0b1110.
+    McrMrc14 = 0b000101,
+    /// Trapped LDC or STC access.
+    LdcStc = 0b000110,
+    /// Trapped access to SVE, Advanced SIMD or floating point.
+    SimdFp = 0b000111,
+    /// Trapped VMRS access, from ID group trap.
+    Vmrs = 0b001000,
+    /// Trapped use of a Pointer authentication instruction.
+    PacTrap = 0b001001,
+    /// Trapped execution of an LD64B, ST64B, ST64BV, or ST64BV0 instruction.
+    Ld64b = 0b001010,
+    /// Trapped MRRC access with (coproc==0b1110).
+    Mrrc14 = 0b001100,
+    /// Branch Target Exception.
+    BranchTarget = 0b001101,
+    /// Illegal Execution state.
+    IllegalExecutionState = 0b001110,
+    /// SVC instruction execution in AArch32 state.
+    Svc32 = 0b010001,
+    /// HVC instruction execution in AArch32 state.
+    Hvc32 = 0b010010,
+    /// SMC instruction execution in AArch32 state.
+    Smc32 = 0b010011,
+    /// Trapped MSRR, MRRS or SYSP instruction execution in AArch64 state.
+    SysReg128 = 0b010100,
+    /// SVC instruction execution in AArch64 state.
+    Svc64 = 0b010101,
+    /// HVC instruction execution in AArch64 state.
+    Hvc64 = 0b010110,
+    /// SMC instruction execution in AArch64 state.
+    Smc64 = 0b010111,
+    /// Trapped MSR, MRS or System instruction execution in AArch64 state.
+    SysReg = 0b011000,
+    /// Access to SVE functionality trapped.
+    Sve = 0b011001,
+    /// Trapped ERET, ERETAA or ERETAB instruction execution.
+    Eret = 0b011010,
+    /// Trapped TSTART instruction.
+    Tstart = 0b011011,
+    /// Exception from a Pointer Authentication instruction authentication failure.
+    PacFail = 0b011100,
+    /// Access to SME functionality trapped.
+    Sme = 0b011101,
+    /// Granule Protection Check exception.
+    GranuleProtectionCheck = 0b011110,
+    /// IMPLEMENTATION DEFINED exception to EL3.
+    ImpDefEl3 = 0b011111,
+    /// Instruction Abort from a lower Exception level.
+    InstructionAbortLowerEl = 0b100000,
+    /// Instruction Abort taken without a change in Exception level.
+    InstructionAbortSameEl = 0b100001,
+    /// PC alignment fault exception.
+    PcAlignment = 0b100010,
+    /// Data Abort from a lower Exception level.
+    DataAbortLowerEl = 0b100100,
+    /// Data Abort taken without a change in Exception level.
+    DataAbortSameEl = 0b100101,
+    /// SP alignment fault exception.
+    SpAlignment = 0b100110,
+    /// Memory Copy or Memory Set exception.
+    Mops = 0b100111,
+    /// Trapped floating-point exception taken from AArch32 state.
+    Fp32 = 0b101000,
+    /// Trapped floating-point exception taken from AArch64 state.
+    Fp64 = 0b101100,
+    /// Guarded Control Stack exception.
+    Gcs = 0b101101,
+    /// SError interrupt.
+    SError = 0b101111,
+    /// Breakpoint exception from a lower Exception level.
+    BreakpointLowerEl = 0b110000,
+    /// Breakpoint exception taken without a change in Exception level.
+    BreakpointSameEl = 0b110001,
+    /// Software Step exception from a lower Exception level.
+    SoftwareStepLowerEl = 0b110010,
+    /// Software Step exception taken without a change in Exception level.
+    SoftwareStepSameEl = 0b110011,
+    /// Watchpoint exception from a lower Exception level.
+    WatchpointLowerEl = 0b110100,
+    /// Watchpoint exception taken without a change in Exception level.
+    WatchpointSameEl = 0b110101,
+    /// BKPT instruction execution in AArch32 state.
+    Bkpt32 = 0b111000,
+    /// Vector Catch exception from AArch32 state.
+    VectorCatch32 = 0b111010,
+    /// BRK instruction execution in AArch64 state.
+    Brk64 = 0b111100,
+    /// PMU exception.
+    Pmu = 0b111101,
+}
+
+impl ExceptionClass {
+    /// Returns a description of the exception class.
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Unknown => "Unknown reason",
+            Self::Wf => "Wrapped WF* instruction execution",
+            Self::McrMrc15 => "Trapped MCR or MRC access with coproc=0b1111",
+            Self::McrrMrrc15 => "Trapped MCRR or MRRC access with coproc=0b1111",
+            Self::McrMrc14 => "Trapped MCR or MRC access with coproc=0b1110",
+            Self::LdcStc => "Trapped LDC or STC access",
+            Self::SimdFp => "Trapped access to SVE, Advanced SIMD or floating point",
+            Self::Vmrs => "Trapped VMRS access, from ID group trap",
+            Self::PacTrap => {
+                "Trapped use of a Pointer authentication instruction because of HCR_EL2.API or \
+                 SCR_EL3.API"
+            }
+            Self::Ld64b => "Trapped execution of an LD64B, ST64B, ST64BV, or ST64BV0 instruction",
+            Self::Mrrc14 => "Trapped MRRC access with (coproc==0b1110)",
+            Self::BranchTarget => "Branch Target Exception",
+            Self::IllegalExecutionState => "Illegal Execution state",
+            Self::Svc32 => "SVC instruction execution in AArch32 state",
+            Self::Hvc32 => "HVC instruction execution in AArch32 state",
+            Self::Smc32 => "SMC instruction execution in AArch32 state",
+            Self::SysReg128 => "Trapped MSRR, MRRS or SYSP instruction execution in AArch64 state",
+            Self::Svc64 => "SVC instruction execution in AArch64 state",
+            Self::Hvc64 => "HVC instruction execution in AArch64 state",
+            Self::Smc64 => "SMC instruction execution in AArch64 state",
+            Self::SysReg => "Trapped MSR, MRS or System instruction execution in AArch64 state",
+            Self::Sve => {
+                "Access to SVE functionality trapped as a result of CPACR_EL1.ZEN, CPTR_EL2.ZEN, \
+                 CPTR_EL2.TZ, or CPTR_EL3.EZ"
+            }
+            Self::Eret => "Trapped ERET, ERETAA or ERETAB instruction execution",
+            Self::Tstart => {
+                "Exception from an access to a TSTART instruction at EL0 when SCTLR_EL1.TME0 is \
+                 0, EL0 when SCTLR_EL2.TME0 is 0, EL1 when SCTLR_EL1.TME is 0, EL2 when \
+                 SCTLR_EL2.TME is 0 or EL3 when SCTLR_EL3.TME is 0"
+            }
+            Self::PacFail => {
+                "Exception from a Pointer Authentication instruction authentication failure"
+            }
+            Self::Sme => {
+                "Access to SME functionality trapped as a result of CPACR_EL1.SMEN, \
+                 CPTR_EL2.SMEN, CPTR_EL2.TSM, CPTR_EL3.ESM, or an instruction that is illegal \
+                 because of the value of PSTATE.SM or PSTATE.ZA"
+            }
+            Self::GranuleProtectionCheck => "Granule Protection Check exception",
+            Self::ImpDefEl3 => "IMPLEMENTATION DEFINED exception to EL3",
+            Self::InstructionAbortLowerEl => "Instruction Abort from a lower Exception level",
+            Self::InstructionAbortSameEl => {
+                "Instruction Abort taken without a change in Exception level"
+            }
+            Self::PcAlignment => "PC alignment fault exception",
+            Self::DataAbortLowerEl => "Data Abort from a lower Exception level",
+            Self::DataAbortSameEl => "Data Abort taken without a change in Exception level",
+            Self::SpAlignment => "SP alignment fault exception",
+            Self::Mops => "Memory Copy or Memory Set exception",
+            Self::Fp32 => "Trapped floating-point exception taken from AArch32 state",
+            Self::Fp64 => "Trapped floating-point exception taken from AArch64 state",
+            Self::Gcs => "Guarded Control Stack exception",
+            Self::SError => "SError interrupt",
+            Self::BreakpointLowerEl => "Breakpoint exception from a lower Exception level",
+            Self::BreakpointSameEl => {
+                "Breakpoint exception taken without a change in Exception level"
+            }
+            Self::SoftwareStepLowerEl => "Software Step exception from a lower Exception level",
+            Self::SoftwareStepSameEl => {
+                "Software Step exception taken without a change in Exception level"
+            }
+            Self::WatchpointLowerEl => "Watchpoint exception from a lower Exception level",
+            Self::WatchpointSameEl => {
+                "Watchpoint exception taken without a change in Exception level"
+            }
+            Self::Bkpt32 => "BKPT instruction execution in AArch32 state",
+            Self::VectorCatch32 => "Vector Catch exception from AArch32 state",
+            Self::Brk64 => "BRK instruction execution in AArch64 state",
+            Self::Pmu => "PMU exception",
+        }
+    }
+
+    /// Returns the names and bit ranges within the ESR of all the ISS and ISS2 fields which may be
+    /// present for the exception class, other than reserved fields.
+    ///
+    /// Some of these fields are only present for some values of other fields, such as SAS which
+    /// needs ISV to be set.
+    pub fn iss_fields(self) -> &'static [(&'static str, Range<usize>)] {
+        match self {
+            Self::Unknown
+            | Self::PacTrap
+            | Self::IllegalExecutionState
+            | Self::Sve
+            | Self::PcAlignment
+            | Self::SpAlignment => &[],
+            Self::Wf => WF_FIELDS,
+            Self::McrMrc15 | Self::McrMrc14 | Self::Vmrs => MCR_FIELDS,
+            Self::McrrMrrc15 | Self::Mrrc14 => MCRR_FIELDS,
+            Self::LdcStc => LDC_FIELDS,
+            Self::SimdFp => SVE_FIELDS,
+            Self::Ld64b => LD64B_FIELDS,
+            Self::BranchTarget => BTI_FIELDS,
+            Self::Svc32 | Self::Hvc32 | Self::Svc64 | Self::Hvc64 | Self::Smc64 => HVC_FIELDS,
+            Self::Smc32 => SMC32_FIELDS,
+            Self::SysReg128 => MSRR_FIELDS,
+            Self::SysReg => MSR_FIELDS,
+            Self::Eret => ERET_FIELDS,
+            Self::Tstart => TSTART_FIELDS,
+            Self::PacFail => PAUTH_FIELDS,
+            Self::Sme => SME_FIELDS,
+            Self::GranuleProtectionCheck => GPC_FIELDS,
+            Self::ImpDefEl3 => IMPDEF_FIELDS,
+            Self::InstructionAbortLowerEl | Self::InstructionAbortSameEl => {
+                INSTRUCTION_ABORT_FIELDS
+            }
+            Self::DataAbortLowerEl | Self::DataAbortSameEl => DATA_ABORT_FIELDS,
+            Self::Mops => MOPS_FIELDS,
+            Self::Fp32 | Self::Fp64 => FP_FIELDS,
+            Self::Gcs => GCS_FIELDS,
+            Self::SError => SERROR_FIELDS,
+            Self::BreakpointLowerEl | Self::BreakpointSameEl | Self::VectorCatch32 => {
+                BREAKPOINT_VECTOR_CATCH_FIELDS
+            }
+            Self::SoftwareStepLowerEl | Self::SoftwareStepSameEl => SOFTWARE_STEP_FIELDS,
+            Self::WatchpointLowerEl | Self::WatchpointSameEl => WATCHPOINT_FIELDS,
+            Self::Bkpt32 | Self::Brk64 => BREAKPOINT_FIELDS,
+            Self::Pmu => PMU_FIELDS,
+        }
+    }
+}
+
+impl TryFrom<u64> for ExceptionClass {
+    type Error = DecodeError;
+
+    fn try_from(ec: u64) -> Result<Self, Self::Error> {
+        Ok(match ec {
+            0b000000 => Self::Unknown,
+            0b000001 => Self::Wf,
+            0b000011 => Self::McrMrc15,
+            0b000100 => Self::McrrMrrc15,
+            0b000101 => Self::McrMrc14,
+            0b000110 => Self::LdcStc,
+            0b000111 => Self::SimdFp,
+            0b001000 => Self::Vmrs,
+            0b001001 => Self::PacTrap,
+            0b001010 => Self::Ld64b,
+            0b001100 => Self::Mrrc14,
+            0b001101 => Self::BranchTarget,
+            0b001110 => Self::IllegalExecutionState,
+            0b010001 => Self::Svc32,
+            0b010010 => Self::Hvc32,
+            0b010011 => Self::Smc32,
+            0b010100 => Self::SysReg128,
+            0b010101 => Self::Svc64,
+            0b010110 => Self::Hvc64,
+            0b010111 => Self::Smc64,
+            0b011000 => Self::SysReg,
+            0b011001 => Self::Sve,
+            0b011010 => Self::Eret,
+            0b011011 => Self::Tstart,
+            0b011100 => Self::PacFail,
+            0b011101 => Self::Sme,
+            0b011110 => Self::GranuleProtectionCheck,
+            0b011111 => Self::ImpDefEl3,
+            0b100000 => Self::InstructionAbortLowerEl,
+            0b100001 => Self::InstructionAbortSameEl,
+            0b100010 => Self::PcAlignment,
+            0b100100 => Self::DataAbortLowerEl,
+            0b100101 => Self::DataAbortSameEl,
+            0b100110 => Self::SpAlignment,
+            0b100111 => Self::Mops,
+            0b101000 => Self::Fp32,
+            0b101100 => Self::Fp64,
+            0b101101 => Self::Gcs,
+            0b101111 => Self::SError,
+            0b110000 => Self::BreakpointLowerEl,
+            0b110001 => Self::BreakpointSameEl,
+            0b110010 => Self::SoftwareStepLowerEl,
+            0b110011 => Self::SoftwareStepSameEl,
+            0b110100 => Self::WatchpointLowerEl,
+            0b110101 => Self::WatchpointSameEl,
+            0b111000 => Self::Bkpt32,
+            0b111010 => Self::VectorCatch32,
+            0b111100 => Self::Brk64,
+            0b111101 => Self::Pmu,
+            _ => return Err(DecodeError::InvalidEc { ec }),
+        })
+    }
+}
@@ -14,6 +14,9 @@
 
 //! Description functions shared between multiple modules.
 
+use super::Features;
+use crate::{DecodeError, StaticFieldInfo};
+
 pub fn describe_cv(cv: bool) -> &'static str {
     if cv {
         "COND is valid"
@@ -21,3 +24,52 @@ pub fn describe_cv(cv: bool) -> &'static str {
         "COND is not valid"
     }
 }
+
+pub fn describe_cond(cond: u64) -> Result<&'static str, DecodeError> {
+    Ok(match cond {
+        0b0000 => "EQ (equal)",
+        0b0001 => "NE (not equal)",
+        0b0010 => "CS/HS (carry set, unsigned higher or same)",
+        0b0011 => "CC/LO (carry clear, unsigned lower)",
+        0b0100 => "MI (negative)",
+        0b0101 => "PL (positive or zero)",
+        0b0110 => "VS (overflow)",
+        0b0111 => "VC (no overflow)",
+        0b1000 => "HI (unsigned higher)",
+        0b1001 => "LS (unsigned lower or same)",
+        0b1010 => "GE (signed greater than or equal)",
+        0b1011 => "LT (signed less than)",
+        0b1100 => "GT (signed greater than)",
+        0b1101 => "LE (signed less than or equal)",
+        0b1110 => "AL (always)",
+        0b1111 => "Unconditional",
+        _ => unreachable!(),
+    })
+}
+
+/// Decodes the VNCR bit of an abort or watchpoint, which is RES0 unless FEAT_NV2 is implemented.
+pub fn decode_vncr(vncr: bool, features: Features) -> StaticFieldInfo {
+    if features.nv2 {
+        StaticFieldInfo::new_bit("VNCR", Some("VNCR_EL2 access"), VNCR, vncr)
+            .describe_bit(describe_vncr)
+    } else {
+        StaticFieldInfo::new_bit(
+            "RES0",
+            Some("Reserved because FEAT_NV2 is not implemented"),
+            VNCR,
+            vncr,
+        )
+        .check_res0()
+    }
+}
+
+/// The position of the VNCR bit in the ISS of aborts and watchpoints.
+const VNCR: usize = 13;
+
+fn describe_vncr(vncr: bool) -> &'static str {
+    if vncr {
+        "Fault came from an access to the VNCR_EL2 page rather than the guest's own memory"
+    } else {
+        "Fault not generated by the use of VNCR_EL2"
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{ExceptionClass, decode};
+use crate::{EncodeError, FieldInfo};
+use alloc::{string::ToString, vec, vec::Vec};
+use bit_field::BitField;
+use core::ops::Range;
+
+/// Builds an Exception Syndrome Register value from an exception class and named fields.
+///
+/// Field names are the same as those used in the tree returned by [`decode`], e.g. `"DFSC"` or
+/// `"WnR"`. Fields of ISS2 are named directly too, e.g. `"Overlay"`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EsrBuilder {
+    class: ExceptionClass,
+    esr: u64,
+    fields: Vec<(&'static str, usize)>,
+}
+
+impl EsrBuilder {
+    /// Creates a new builder for an ESR with the given exception class, and IL set to indicate a
+    /// 32-bit instruction.
+    pub fn new(class: ExceptionClass) -> Self {
+        let mut esr = 0;
+        esr.set_bits(26..32, class as u64);
+        esr.set_bit(25, true);
+        Self {
+            class,
+            esr,
+            fields: vec![],
+        }
+    }
+
+    /// Sets the IL bit, which is true for a 32-bit instruction or false for a 16-bit instruction.
+    pub fn il(mut self, il: bool) -> Self {
+        self.esr.set_bit(25, il);
+        self
+    }
+
+    /// Sets the ISS or ISS2 field with the given name to the given value.
+    ///
+    /// Returns an error if there is no field with the given name for the exception class, or the
+    /// value doesn't fit in the field.
+    pub fn field(mut self, name: &str, value: u64) -> Result<Self, EncodeError> {
+        let (name, Range { start, end }) = self
+            .class
+            .iss_fields()
+            .iter()
+            .find(|(field_name, _)| *field_name == name)
+            .cloned()
+            .ok_or_else(|| EncodeError::UnknownField {
+                name: name.to_string(),
+                ec: self.class as u64,
+            })?;
+        if value >= 1 << (end - start) {
+            return Err(EncodeError::ValueTooWide {
+                name,
+                value,
+                width: end - start,
+            });
+        }
+        self.esr.set_bits(start..end, value);
+        self.fields.push((name, start));
+        Ok(self)
+    }
+
+    /// Returns the ESR value, or an error if it is not valid.
+    ///
+    /// This checks that the value can be decoded, and that every field which was set is valid given
+    /// the values of the other fields. For example, `SAS` may only be set if `ISV` is also set.
+    pub fn build(self) -> Result<u64, EncodeError> {
+        let decoded = decode(self.esr)?;
+        for &(name, start) in &self.fields {
+            if !contains_field(&decoded, 0, name, start) {
+                return Err(EncodeError::FieldNotValid { name });
+            }
+        }
+        Ok(self.esr)
+    }
+}
+
+/// Returns whether any of the given fields or their subfields has the given name and start bit,
+/// where the fields start relative to `base`.
+fn contains_field(fields: &[FieldInfo], base: usize, name: &str, start: usize) -> bool {
+    fields.iter().any(|field| {
+        field.name == name && base + field.start == start
+            || contains_field(&field.subfields, base + field.start, name, start)
+    })
+}
@@ -0,0 +1,53 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::IssField;
+use crate::{FieldList, StaticFieldInfo};
+use bit_field::BitField;
+
+/// The non-reserved ISS fields which may be present for a trapped ERET, ERETAA or ERETAB
+/// instruction.
+pub const ERET_FIELDS: &[IssField] = &[("ERET", 1..2), ("ERETA", 0..1)];
+
+/// Decodes the ISS value for a trapped ERET, ERETAA or ERETAB instruction.
+pub fn decode_iss_eret(iss: u64) -> FieldList {
+    let res0 = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 2, 25).check_res0();
+    let eret = StaticFieldInfo::get_bit(iss, "ERET", Some("ERET or ERETA*"), 1)
+        .describe_bit(describe_eret);
+    let ereta = StaticFieldInfo::get_bit(iss, "ERETA", Some("A key or B key"), 0)
+        .describe_bit(describe_ereta);
+
+    FieldList::from([res0, eret, ereta])
+}
+
+/// Returns the mnemonic of the trapped instruction for the given ISS value.
+pub fn describe_iss_eret(iss: u64) -> &'static str {
+    match (iss.get_bit(1), iss.get_bit(0)) {
+        (false, _) => "ERET",
+        (true, false) => "ERETAA",
+        (true, true) => "ERETAB",
+    }
+}
+
+fn describe_eret(eret: bool) -> &'static str {
+    if eret {
+        "ERETAA or ERETAB instruction trapped"
+    } else {
+        "ERET instruction trapped"
+    }
+}
+
+fn describe_ereta(ereta: bool) -> &'static str {
+    if ereta { "B Key" } else { "A Key" }
+}
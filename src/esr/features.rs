@@ -0,0 +1,83 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bit_field::BitField;
+
+/// The set of architecture features implemented by the CPU which an ESR value came from.
+///
+/// Fields which are only defined when a feature is implemented are decoded as RES0 if it isn't.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Features {
+    /// FEAT_NV2, which adds the VNCR bit to abort and watchpoint syndromes.
+    pub nv2: bool,
+    /// FEAT_RAS, which adds the SET field to abort syndromes.
+    pub ras: bool,
+    /// FEAT_LS64, which adds the LST field to data abort syndromes.
+    pub ls64: bool,
+    /// FEAT_LPA2, which adds the level -1 fault status codes.
+    pub lpa2: bool,
+}
+
+impl Features {
+    /// All features which affect decoding are implemented.
+    pub const ALL: Self = Self {
+        nv2: true,
+        ras: true,
+        ls64: true,
+        lpa2: true,
+    };
+
+    /// None of the features which affect decoding are implemented.
+    pub const NONE: Self = Self {
+        nv2: false,
+        ras: false,
+        ls64: false,
+        lpa2: false,
+    };
+}
+
+impl Default for Features {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// Values of the ID registers from which the implemented `Features` can be derived.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct IdRegisters {
+    /// The value of ID_AA64PFR0_EL1.
+    pub id_aa64pfr0_el1: u64,
+    /// The value of ID_AA64ISAR1_EL1.
+    pub id_aa64isar1_el1: u64,
+    /// The value of ID_AA64MMFR0_EL1.
+    pub id_aa64mmfr0_el1: u64,
+    /// The value of ID_AA64MMFR2_EL1.
+    pub id_aa64mmfr2_el1: u64,
+}
+
+impl From<IdRegisters> for Features {
+    fn from(id_registers: IdRegisters) -> Self {
+        // ID_AA64MMFR0_EL1.TGran4 is 0b0001 and TGran16 is 0b0010 when 52-bit addresses are
+        // supported with that granule size.
+        let tgran4 = id_registers.id_aa64mmfr0_el1.get_bits(28..32);
+        let tgran16 = id_registers.id_aa64mmfr0_el1.get_bits(20..24);
+        Self {
+            nv2: id_registers.id_aa64mmfr2_el1.get_bits(24..28) >= 0b0010,
+            ras: id_registers.id_aa64pfr0_el1.get_bits(28..32) >= 0b0001,
+            ls64: id_registers.id_aa64isar1_el1.get_bits(60..64) >= 0b0001,
+            lpa2: tgran4 == 0b0001 || tgran16 == 0b0010,
+        }
+    }
+}
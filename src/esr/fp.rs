@@ -12,25 +12,40 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{DecodeError, FieldInfo};
+use super::IssField;
+use crate::{FieldList, StaticFieldInfo};
+
+/// The non-reserved ISS fields which may be present for a floating-point exception.
+pub const FP_FIELDS: &[IssField] = &[
+    ("TFV", 23..24),
+    ("VECITR", 8..11),
+    ("IDF", 7..8),
+    ("IXF", 4..5),
+    ("UFF", 3..4),
+    ("OFF", 2..3),
+    ("DZF", 1..2),
+    ("IOF", 0..1),
+];
 
 /// Decodes the ISS value for a floating-point exception.
-pub fn decode_iss_fp(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let res0a = FieldInfo::get_bit(iss, "RES0", Some("Reserved"), 24).check_res0()?;
-    let tfv =
-        FieldInfo::get_bit(iss, "TFV", Some("Trapped Fault Valid"), 23).describe_bit(describe_tfv);
-    let res0b = FieldInfo::get(iss, "RES0", Some("Reserved"), 11, 23).check_res0()?;
-    let vecitr = FieldInfo::get(iss, "VECITR", Some("RES1 or UNKNOWN"), 8, 11);
-    let idf = FieldInfo::get_bit(iss, "IDF", Some("Input Denormal"), 7).describe_bit(describe_idf);
-    let res0c = FieldInfo::get(iss, "RES0", Some("Reserved"), 5, 7).check_res0()?;
-    let ixf = FieldInfo::get_bit(iss, "IXF", Some("Inexact"), 4).describe_bit(describe_ixf);
-    let uff = FieldInfo::get_bit(iss, "UFF", Some("Underflow"), 3).describe_bit(describe_uff);
-    let off = FieldInfo::get_bit(iss, "OFF", Some("Overflow"), 2).describe_bit(describe_off);
-    let dzf = FieldInfo::get_bit(iss, "DZF", Some("Divide by Zero"), 1).describe_bit(describe_dzf);
-    let iof =
-        FieldInfo::get_bit(iss, "IOF", Some("Invalid Operation"), 0).describe_bit(describe_iof);
+pub fn decode_iss_fp(iss: u64) -> FieldList {
+    let res0a = StaticFieldInfo::get_bit(iss, "RES0", Some("Reserved"), 24).check_res0();
+    let tfv = StaticFieldInfo::get_bit(iss, "TFV", Some("Trapped Fault Valid"), 23)
+        .describe_bit(describe_tfv);
+    let res0b = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 11, 23).check_res0();
+    let vecitr = StaticFieldInfo::get(iss, "VECITR", Some("RES1 or UNKNOWN"), 8, 11);
+    let idf =
+        StaticFieldInfo::get_bit(iss, "IDF", Some("Input Denormal"), 7).describe_bit(describe_idf);
+    let res0c = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 5, 7).check_res0();
+    let ixf = StaticFieldInfo::get_bit(iss, "IXF", Some("Inexact"), 4).describe_bit(describe_ixf);
+    let uff = StaticFieldInfo::get_bit(iss, "UFF", Some("Underflow"), 3).describe_bit(describe_uff);
+    let off = StaticFieldInfo::get_bit(iss, "OFF", Some("Overflow"), 2).describe_bit(describe_off);
+    let dzf =
+        StaticFieldInfo::get_bit(iss, "DZF", Some("Divide by Zero"), 1).describe_bit(describe_dzf);
+    let iof = StaticFieldInfo::get_bit(iss, "IOF", Some("Invalid Operation"), 0)
+        .describe_bit(describe_iof);
 
-    Ok(vec![
+    FieldList::from([
         res0a, tfv, res0b, vecitr, idf, res0c, ixf, uff, off, dzf, iof,
     ])
 }
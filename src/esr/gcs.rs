@@ -0,0 +1,107 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::IssField;
+use crate::{DecodeError, FieldList, StaticFieldInfo};
+use bit_field::BitField;
+use core::fmt::{self, Formatter};
+
+/// The non-reserved ISS fields which may be present for a Guarded Control Stack exception.
+pub const GCS_FIELDS: &[IssField] = &[
+    ("ExType", 20..24),
+    ("Raddr", 10..15),
+    ("Rn", 5..10),
+    ("IT", 0..5),
+];
+
+/// Decodes the ISS value for a Guarded Control Stack exception.
+pub fn decode_iss_gcs(iss: u64) -> FieldList {
+    let res0a = StaticFieldInfo::get_bit(iss, "RES0", Some("Reserved"), 24).check_res0();
+    let ex_type = StaticFieldInfo::get(iss, "ExType", Some("Exception Type"), 20, 24)
+        .describe(describe_ex_type);
+    let res0b = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 15, 20).check_res0();
+
+    let mut fields = FieldList::from([res0a, ex_type, res0b]);
+    match ex_type.value {
+        0b0000 => {
+            let raddr = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 10, 15).check_res0();
+            let rn = StaticFieldInfo::get(iss, "Rn", Some("Register Number"), 5, 10);
+            let it = StaticFieldInfo::get(iss, "IT", Some("Instruction Type"), 0, 5)
+                .describe(describe_it);
+            fields.extend([raddr, rn, it]);
+        }
+        0b0001 => {
+            let res0 = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 0, 15).check_res0();
+            fields.extend([res0]);
+        }
+        0b0010 => {
+            let raddr = StaticFieldInfo::get(iss, "Raddr", Some("Address register number"), 10, 15);
+            let rn = StaticFieldInfo::get(iss, "Rn", Some("Register Number"), 5, 10);
+            let it = StaticFieldInfo::get(iss, "IT", Some("Instruction Type"), 0, 5);
+            fields.extend([raddr, rn, it]);
+        }
+        _ => {
+            // The layout of the rest of the ISS is unknown for other ExType values, which are
+            // reported as a diagnostic on the ExType field.
+            let iss = StaticFieldInfo::get(iss, "ISS", None, 0, 15);
+            fields.extend([iss]);
+        }
+    }
+    fields
+}
+
+/// Returns whether there is a description of the instruction for the given ISS value, i.e. whether
+/// it is a GCS data check exception or a trapped GCSSTR or GCSSTTR instruction.
+pub fn has_gcs_instruction(iss: u64) -> bool {
+    matches!(iss.get_bits(20..24), 0b0000 | 0b0010)
+}
+
+/// Writes a description of the instruction which caused the exception for the given valid ISS
+/// value, assuming that [`has_gcs_instruction`] is true.
+pub fn fmt_iss_gcs(f: &mut Formatter, iss: u64) -> fmt::Result {
+    let rn = iss.get_bits(5..10);
+    if iss.get_bits(20..24) == 0b0000 {
+        let it = describe_it(iss.get_bits(0..5)).map_err(|_| fmt::Error)?;
+        write!(
+            f,
+            "Return address mismatch on GCS data check for {it} x{rn}"
+        )
+    } else {
+        let raddr = iss.get_bits(10..15);
+        write!(f, "Trapped GCSSTR or GCSSTTR x{rn}, [x{raddr}]")
+    }
+}
+
+fn describe_ex_type(ex_type: u64) -> Result<&'static str, DecodeError> {
+    Ok(match ex_type {
+        0b0000 => "Guarded Control Stack data check exception (return address mismatch)",
+        0b0001 => "EXLOCK exception",
+        0b0010 => "Trapped GCSSTR or GCSSTTR instruction",
+        _ => return Err(DecodeError::InvalidExType { ex_type }),
+    })
+}
+
+fn describe_it(it: u64) -> Result<&'static str, DecodeError> {
+    Ok(match it {
+        0b00000 => "RET",
+        0b00001 => "GCSPOPM",
+        0b00010 => "RETAA",
+        0b00011 => "RETAB",
+        0b00100 => "GCSSS1",
+        0b00101 => "GCSSS2",
+        0b01000 => "GCSPOPCX",
+        0b01001 => "GCSPOPX",
+        _ => return Err(DecodeError::InvalidIt { it }),
+    })
+}
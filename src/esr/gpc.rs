@@ -0,0 +1,96 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::Features;
+use super::IssField;
+use super::abort::{describe_fsc, describe_wnr};
+use super::common::decode_vncr;
+use crate::{DecodeError, FieldList, StaticFieldInfo};
+use bit_field::BitField;
+
+/// The non-reserved ISS fields which may be present for a Granule Protection Check exception.
+pub const GPC_FIELDS: &[IssField] = &[
+    ("S2PTW", 21..22),
+    ("InD", 20..21),
+    ("GPCSC", 14..20),
+    ("VNCR", 13..14),
+    ("CM", 8..9),
+    ("S1PTW", 7..8),
+    ("WnR", 6..7),
+    ("xFSC", 0..6),
+];
+
+/// Decodes the ISS value for a Granule Protection Check exception.
+pub fn decode_iss_gpc(iss: u64, features: Features) -> FieldList {
+    let res0a = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 22, 25).check_res0();
+    let s2ptw = StaticFieldInfo::get_bit(iss, "S2PTW", Some("Stage 2 translation table walk"), 21)
+        .describe_bit(describe_s2ptw);
+    let ind = StaticFieldInfo::get_bit(iss, "InD", Some("Instruction not Data"), 20)
+        .describe_bit(describe_ind);
+    let gpcsc = StaticFieldInfo::get(
+        iss,
+        "GPCSC",
+        Some("Granule Protection Check Status Code"),
+        14,
+        20,
+    )
+    .describe(describe_gpcsc);
+    let vncr = decode_vncr(iss.get_bit(13), features);
+    let res0b = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 9, 13).check_res0();
+    let cm = StaticFieldInfo::get_bit(iss, "CM", Some("Cache Maintenance"), 8);
+    let s1ptw = StaticFieldInfo::get_bit(iss, "S1PTW", Some("Stage-1 translation table walk"), 7);
+    let wnr =
+        StaticFieldInfo::get_bit(iss, "WnR", Some("Write not Read"), 6).describe_bit(describe_wnr);
+    let xfsc = StaticFieldInfo::get(
+        iss,
+        "xFSC",
+        Some("Instruction or Data Fault Status Code"),
+        0,
+        6,
+    )
+    .describe(|fsc| describe_fsc(fsc, features));
+
+    FieldList::from([res0a, s2ptw, ind, gpcsc, vncr, res0b, cm, s1ptw, wnr, xfsc])
+}
+
+fn describe_s2ptw(s2ptw: bool) -> &'static str {
+    if s2ptw {
+        "Fault on a stage 2 translation table walk"
+    } else {
+        "Fault not on a stage 2 translation table walk"
+    }
+}
+
+fn describe_ind(ind: bool) -> &'static str {
+    if ind {
+        "Instruction access"
+    } else {
+        "Data access"
+    }
+}
+
+fn describe_gpcsc(gpcsc: u64) -> Result<&'static str, DecodeError> {
+    Ok(match gpcsc {
+        0b000000 => "Address size fault on GPT walk, level 0 of the Granule Protection Table",
+        0b000100 => "GPT walk fault, level 0 of the Granule Protection Table",
+        0b000101 => "GPT walk fault, level 1 of the Granule Protection Table",
+        0b001100 => {
+            "Synchronous External abort on GPT fetch, level 0 of the Granule Protection Table"
+        }
+        0b001101 => {
+            "Synchronous External abort on GPT fetch, level 1 of the Granule Protection Table"
+        }
+        _ => return Err(DecodeError::InvalidGpcsc { gpcsc }),
+    })
+}
@@ -12,12 +12,52 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{DecodeError, FieldInfo};
+use super::IssField;
+use super::common::{describe_cond, describe_cv};
+use crate::{FieldList, StaticFieldInfo};
+
+/// The non-reserved ISS fields which may be present for an HVC or SVC exception.
+pub const HVC_FIELDS: &[IssField] = &[("imm16", 0..16)];
 
 /// Decodes the ISS value for an HVC or SVC exception.
-pub fn decode_iss_hvc(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let res0 = FieldInfo::get(iss, "RES0", Some("Reserved"), 16, 25).check_res0()?;
-    let imm16 = FieldInfo::get(iss, "imm16", Some("Value of the immediate field"), 0, 16);
+pub fn decode_iss_hvc(iss: u64) -> FieldList {
+    let res0 = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 16, 25).check_res0();
+    let imm16 = StaticFieldInfo::get(iss, "imm16", Some("Value of the immediate field"), 0, 16);
+
+    FieldList::from([res0, imm16])
+}
+
+/// The non-reserved ISS fields which may be present for an SMC exception from AArch32 state.
+pub const SMC32_FIELDS: &[IssField] = &[("CV", 24..25), ("COND", 20..24), ("CCKNOWNPASS", 19..20)];
+
+/// Decodes the ISS value for an SMC exception from AArch32 state.
+pub fn decode_iss_smc32(iss: u64) -> FieldList {
+    let cv = StaticFieldInfo::get_bit(iss, "CV", Some("Condition code valid"), 24)
+        .describe_bit(describe_cv);
+    let cond = StaticFieldInfo::get(
+        iss,
+        "COND",
+        Some("Condition code of the trapped instruction"),
+        20,
+        24,
+    )
+    .describe(describe_cond);
+    let ccknownpass = StaticFieldInfo::get_bit(
+        iss,
+        "CCKNOWNPASS",
+        Some("Condition code check known to have passed"),
+        19,
+    )
+    .describe_bit(describe_ccknownpass);
+    let res0 = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 0, 19).check_res0();
+
+    FieldList::from([cv, cond, ccknownpass, res0])
+}
 
-    Ok(vec![res0, imm16])
+fn describe_ccknownpass(ccknownpass: bool) -> &'static str {
+    if ccknownpass {
+        "The instruction was unconditional, or was conditional and passed its condition code check"
+    } else {
+        "The instruction was conditional, and might have failed its condition code check"
+    }
 }
@@ -12,12 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{DecodeError, FieldInfo};
+use super::IssField;
+use crate::{DecodeError, FieldList, StaticFieldInfo};
+
+/// The non-reserved ISS fields which may be present for a trapped LD64B or ST64B* instruction.
+pub const LD64B_FIELDS: &[IssField] = &[("ISS", 0..25)];
 
 /// Decodes the ISS value for a trapped LD64B or ST64B* instruction.
-pub fn decode_iss_ld64b(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let iss = FieldInfo::get(iss, "ISS", None, 0, 25).describe(describe_iss_ld64b)?;
-    Ok(vec![iss])
+pub fn decode_iss_ld64b(iss: u64) -> FieldList {
+    let iss = StaticFieldInfo::get(iss, "ISS", None, 0, 25).describe(describe_iss_ld64b);
+    FieldList::from([iss])
 }
 
 fn describe_iss_ld64b(iss: u64) -> Result<&'static str, DecodeError> {
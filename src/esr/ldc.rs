@@ -12,44 +12,57 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::common::describe_cv;
-use crate::{DecodeError, FieldInfo};
+use super::IssField;
+use super::common::{describe_cond, describe_cv};
+use crate::{DecodeError, FieldList, StaticFieldInfo};
+
+/// The non-reserved ISS fields which may be present for a trapped LDC or STC instruction.
+pub const LDC_FIELDS: &[IssField] = &[
+    ("CV", 24..25),
+    ("COND", 20..24),
+    ("imm8", 12..20),
+    ("Rn", 5..10),
+    ("Offset", 4..5),
+    ("AM", 1..4),
+    ("Direction", 0..1),
+];
 
 /// Decodes the ISS value for a trapped LDC or STC instruction.
-pub fn decode_iss_ldc(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let cv =
-        FieldInfo::get_bit(iss, "CV", Some("Condition code valid"), 24).describe_bit(describe_cv);
-    let cond = FieldInfo::get(
+pub fn decode_iss_ldc(iss: u64) -> FieldList {
+    let cv = StaticFieldInfo::get_bit(iss, "CV", Some("Condition code valid"), 24)
+        .describe_bit(describe_cv);
+    let cond = StaticFieldInfo::get(
         iss,
         "COND",
         Some("Condition code of the trapped instruction"),
         20,
         24,
-    );
-    let imm8 = FieldInfo::get(
+    )
+    .describe(describe_cond);
+    let imm8 = StaticFieldInfo::get(
         iss,
         "imm8",
         Some("Immediate value of the trapped instruction"),
         12,
         20,
     );
-    let res0 = FieldInfo::get(iss, "RES0", Some("Reserved"), 10, 12).check_res0()?;
-    let rn = FieldInfo::get(
+    let res0 = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 10, 12).check_res0();
+    let rn = StaticFieldInfo::get(
         iss,
         "Rn",
         Some("General-purpose register number of the trapped instruction"),
         5,
         10,
     );
-    let offset = FieldInfo::get_bit(
+    let offset = StaticFieldInfo::get_bit(
         iss,
         "Offset",
         Some("Whether the offset is added or subtracted"),
         4,
     )
     .describe_bit(describe_offset);
-    let am = FieldInfo::get(iss, "AM", Some("Addressing Mode"), 1, 4).describe(describe_am)?;
-    let direction = FieldInfo::get_bit(
+    let am = StaticFieldInfo::get(iss, "AM", Some("Addressing Mode"), 1, 4).describe(describe_am);
+    let direction = StaticFieldInfo::get_bit(
         iss,
         "Direction",
         Some("Direction of the trapped instruction"),
@@ -57,7 +70,7 @@ pub fn decode_iss_ldc(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
     )
     .describe_bit(describe_direction);
 
-    Ok(vec![cv, cond, imm8, res0, rn, offset, am, direction])
+    FieldList::from([cv, cond, imm8, res0, rn, offset, am, direction])
 }
 
 fn describe_offset(offset: bool) -> &'static str {
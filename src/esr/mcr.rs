@@ -12,26 +12,43 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::common::describe_cv;
-use crate::{DecodeError, FieldInfo};
+use super::IssField;
+use super::common::{describe_cond, describe_cv};
+use super::sysreg_names::{coproc_name, coproc64_name};
+use crate::{FieldList, StaticFieldInfo};
+use bit_field::BitField;
+use core::fmt::{self, Formatter};
+
+/// The non-reserved ISS fields which may be present for an MCR or MRC access.
+pub const MCR_FIELDS: &[IssField] = &[
+    ("CV", 24..25),
+    ("COND", 20..24),
+    ("Opc2", 17..20),
+    ("Opc1", 14..17),
+    ("CRn", 10..14),
+    ("Rt", 5..10),
+    ("CRm", 1..5),
+    ("Direction", 0..1),
+];
 
 /// Decodes the ISS value for an MCR or MRC access.
-pub fn decode_iss_mcr(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let cv =
-        FieldInfo::get_bit(iss, "CV", Some("Condition code valid"), 24).describe_bit(describe_cv);
-    let cond = FieldInfo::get(
+pub fn decode_iss_mcr(iss: u64) -> FieldList {
+    let cv = StaticFieldInfo::get_bit(iss, "CV", Some("Condition code valid"), 24)
+        .describe_bit(describe_cv);
+    let cond = StaticFieldInfo::get(
         iss,
         "COND",
         Some("Condition code of the trapped instruction"),
         20,
         24,
-    );
-    let opc2 = FieldInfo::get(iss, "Opc2", None, 17, 20);
-    let opc1 = FieldInfo::get(iss, "Opc1", None, 14, 17);
-    let crn = FieldInfo::get(iss, "CRn", None, 10, 14);
-    let rt = FieldInfo::get(iss, "Rt", None, 5, 10);
-    let crm = FieldInfo::get(iss, "CRm", None, 1, 5);
-    let direction = FieldInfo::get_bit(
+    )
+    .describe(describe_cond);
+    let opc2 = StaticFieldInfo::get(iss, "Opc2", None, 17, 20);
+    let opc1 = StaticFieldInfo::get(iss, "Opc1", None, 14, 17);
+    let crn = StaticFieldInfo::get(iss, "CRn", None, 10, 14);
+    let rt = StaticFieldInfo::get(iss, "Rt", None, 5, 10);
+    let crm = StaticFieldInfo::get(iss, "CRm", None, 1, 5);
+    let direction = StaticFieldInfo::get_bit(
         iss,
         "Direction",
         Some("Direction of the trapped instruction"),
@@ -39,26 +56,38 @@ pub fn decode_iss_mcr(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
     )
     .describe_bit(describe_direction);
 
-    Ok(vec![cv, cond, opc2, opc1, crn, rt, crm, direction])
+    FieldList::from([cv, cond, opc2, opc1, crn, rt, crm, direction])
 }
 
+/// The non-reserved ISS fields which may be present for an MCRR or MRRC access.
+pub const MCRR_FIELDS: &[IssField] = &[
+    ("CV", 24..25),
+    ("COND", 20..24),
+    ("Opc1", 16..20),
+    ("Rt2", 10..15),
+    ("Rt", 5..10),
+    ("CRm", 1..5),
+    ("Direction", 0..1),
+];
+
 /// Decodes the ISS value for an MCRR or MRRC access.
-pub fn decode_iss_mcrr(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let cv =
-        FieldInfo::get_bit(iss, "CV", Some("Condition code valid"), 24).describe_bit(describe_cv);
-    let cond = FieldInfo::get(
+pub fn decode_iss_mcrr(iss: u64) -> FieldList {
+    let cv = StaticFieldInfo::get_bit(iss, "CV", Some("Condition code valid"), 24)
+        .describe_bit(describe_cv);
+    let cond = StaticFieldInfo::get(
         iss,
         "COND",
         Some("Condition code of the trapped instruction"),
         20,
         24,
-    );
-    let opc1 = FieldInfo::get(iss, "Opc2", None, 16, 20);
-    let res0 = FieldInfo::get_bit(iss, "RES0", Some("Reserved"), 15).check_res0()?;
-    let rt2 = FieldInfo::get(iss, "Rt2", None, 10, 15);
-    let rt = FieldInfo::get(iss, "Rt", None, 5, 10);
-    let crm = FieldInfo::get(iss, "CRm", None, 1, 5);
-    let direction = FieldInfo::get_bit(
+    )
+    .describe(describe_cond);
+    let opc1 = StaticFieldInfo::get(iss, "Opc1", None, 16, 20);
+    let res0 = StaticFieldInfo::get_bit(iss, "RES0", Some("Reserved"), 15).check_res0();
+    let rt2 = StaticFieldInfo::get(iss, "Rt2", None, 10, 15);
+    let rt = StaticFieldInfo::get(iss, "Rt", None, 5, 10);
+    let crm = StaticFieldInfo::get(iss, "CRm", None, 1, 5);
+    let direction = StaticFieldInfo::get_bit(
         iss,
         "Direction",
         Some("Direction of the trapped instruction"),
@@ -66,7 +95,73 @@ pub fn decode_iss_mcrr(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
     )
     .describe_bit(describe_direction);
 
-    Ok(vec![cv, cond, opc1, res0, rt2, rt, crm, direction])
+    FieldList::from([cv, cond, opc1, res0, rt2, rt, crm, direction])
+}
+
+/// Writes the trapped instruction for the given ISS value of an MCR or MRC access to the given
+/// coprocessor, followed by the name of the register accessed if it is known.
+pub fn fmt_iss_mcr(f: &mut Formatter, iss: u64, coproc: u64) -> fmt::Result {
+    let opc2 = iss.get_bits(17..20);
+    let opc1 = iss.get_bits(14..17);
+    let crn = iss.get_bits(10..14);
+    let rt = iss.get_bits(5..10);
+    let crm = iss.get_bits(1..5);
+    let read = iss.get_bit(0);
+    let mnemonic = if read { "MRC" } else { "MCR" };
+    write!(f, "{mnemonic} p{coproc}, {opc1}, ")?;
+    fmt_register(f, rt)?;
+    write!(f, ", c{crn}, c{crm}, {opc2}")?;
+    if let Some(name) = coproc_name(coproc, opc1, crn, crm, opc2, read) {
+        write!(f, " ; {name}")?;
+    }
+    Ok(())
+}
+
+/// Writes the trapped instruction for the given ISS value of an MCRR or MRRC access to the given
+/// coprocessor, followed by the name of the register accessed if it is known.
+pub fn fmt_iss_mcrr(f: &mut Formatter, iss: u64, coproc: u64) -> fmt::Result {
+    let opc1 = iss.get_bits(16..20);
+    let rt2 = iss.get_bits(10..15);
+    let rt = iss.get_bits(5..10);
+    let crm = iss.get_bits(1..5);
+    let mnemonic = if iss.get_bit(0) { "MRRC" } else { "MCRR" };
+    write!(f, "{mnemonic} p{coproc}, {opc1}, ")?;
+    fmt_register(f, rt)?;
+    f.write_str(", ")?;
+    fmt_register(f, rt2)?;
+    write!(f, ", c{crm}")?;
+    if let Some(name) = coproc64_name(coproc, opc1, crm) {
+        write!(f, " ; {name}")?;
+    }
+    Ok(())
+}
+
+/// Writes the AArch32 name of the general-purpose register with the given number in an Rt or Rt2
+/// field.
+///
+/// These fields give the AArch64 view of the register, e.g. 19 for SP_svc, so the name depends on
+/// which banked register it maps to. The number is written as is if it doesn't map to one.
+fn fmt_register(f: &mut Formatter, rt: u64) -> fmt::Result {
+    match rt {
+        0..=12 => write!(f, "r{rt}"),
+        24..=28 => write!(f, "R{}_fiq", rt - 16),
+        _ => f.write_str(match rt {
+            13 => "sp",
+            14 => "lr",
+            15 => "SP_hyp",
+            16 => "LR_irq",
+            17 => "SP_irq",
+            18 => "LR_svc",
+            19 => "SP_svc",
+            20 => "LR_abt",
+            21 => "SP_abt",
+            22 => "LR_und",
+            23 => "SP_und",
+            29 => "SP_fiq",
+            30 => "LR_fiq",
+            _ => return write!(f, "{rt}"),
+        }),
+    }
 }
 
 fn describe_direction(direction: bool) -> &'static str {
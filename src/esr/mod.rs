@@ -1,226 +1,534 @@
 mod abort;
 mod breakpoint;
 mod bti;
+mod class;
 mod common;
+#[cfg(feature = "alloc")]
+mod encode;
+mod eret;
+mod features;
 mod fp;
+mod gcs;
+mod gpc;
 mod hvc;
 mod ld64b;
 mod ldc;
 mod mcr;
+mod mops;
 mod msr;
+mod msrr;
+mod options;
 mod pauth;
+mod pmu;
 mod serror;
+mod sme;
 mod sve;
-#[cfg(test)]
+mod sysreg_names;
+#[cfg(all(test, feature = "alloc"))]
 mod tests;
+mod tstart;
 mod wf;
 
-use super::{DecodeError, FieldInfo};
-use abort::{decode_iss_data_abort, decode_iss_instruction_abort};
+#[cfg(feature = "alloc")]
+use super::FieldInfo;
+use super::format::{FieldLine, FormatOptions};
+use super::{
+    DecodeError, FieldError, FieldList, FieldPath, StaticFieldInfo, path_segment_matches,
+    split_path,
+};
+pub use abort::{DataAbortIss, FaultStatusCode, InstructionAbortIss, SyndromeAccessSize};
+use abort::{decode_iss_data_abort, decode_iss_instruction_abort, decode_iss2_data_abort};
+#[cfg(feature = "alloc")]
+use alloc::{string::ToString, vec, vec::Vec};
+use bit_field::BitField;
+pub use breakpoint::WatchpointIss;
 use breakpoint::{
     decode_iss_breakpoint, decode_iss_breakpoint_vector_catch, decode_iss_software_step,
-    decode_iss_watchpoint,
+    decode_iss_watchpoint, fmt_iss_watchpoint,
 };
 use bti::decode_iss_bti;
+pub use class::ExceptionClass;
+use core::fmt::{self, Display, Formatter};
+use core::ops::Range;
+#[cfg(feature = "alloc")]
+pub use encode::EsrBuilder;
+use eret::{decode_iss_eret, describe_iss_eret};
+pub use features::{Features, IdRegisters};
 use fp::decode_iss_fp;
-use hvc::decode_iss_hvc;
+use gcs::{decode_iss_gcs, fmt_iss_gcs, has_gcs_instruction};
+use gpc::decode_iss_gpc;
+use hvc::{decode_iss_hvc, decode_iss_smc32};
 use ld64b::decode_iss_ld64b;
 use ldc::decode_iss_ldc;
-use mcr::{decode_iss_mcr, decode_iss_mcrr};
-use msr::decode_iss_msr;
+use mcr::{decode_iss_mcr, decode_iss_mcrr, fmt_iss_mcr, fmt_iss_mcrr};
+use mops::{decode_iss_mops, fmt_iss_mops};
+pub use msr::SysRegTrapIss;
+use msr::{decode_iss_msr, fmt_iss_msr};
+use msrr::{decode_iss_msrr, fmt_iss_msrr};
+pub use options::{DecodeOptions, EsrRegister, ExceptionLevel};
 use pauth::decode_iss_pauth;
+use pmu::decode_iss_pmu;
 use serror::decode_iss_serror;
+use sme::decode_iss_sme;
 use sve::decode_iss_sve;
+pub use sysreg_names::SYSREG_NAMES_RELEASE;
+use tstart::{decode_iss_tstart, fmt_iss_tstart};
 use wf::decode_iss_wf;
 
-fn decode_iss_res0(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let res0 = FieldInfo::get(iss, "RES0", Some("Reserved"), 0, 25)
-        .check_res0()?
-        .with_description("ISS is RES0".to_string());
-    Ok(vec![res0])
+fn decode_iss_res0(iss: u64) -> FieldList {
+    let res0 = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 0, 25)
+        .check_res0()
+        .with_description("ISS is RES0");
+    FieldList::from([res0])
 }
 
-/// Decodes the given Exception Syndrome Register value, or returns an error if it is not valid.
-pub fn decode(esr: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let res0 = FieldInfo::get(esr, "RES0", Some("Reserved"), 37, 64).check_res0()?;
-    let iss2 = FieldInfo::get(esr, "ISS2", None, 32, 37);
-    let ec = FieldInfo::get(esr, "EC", Some("Exception Class"), 26, 32);
-    let il =
-        FieldInfo::get_bit(esr, "IL", Some("Instruction Length"), 25).describe_bit(describe_il);
-    let iss = FieldInfo::get(esr, "ISS", Some("Instruction Specific Syndrome"), 0, 25);
-    let (class, iss_subfields, iss_description) = match ec.value {
-        0b000000 => ("Unknown reason", decode_iss_res0(iss.value)?, None),
-        0b000001 => (
-            "Wrapped WF* instruction execution",
-            decode_iss_wf(iss.value)?,
-            None,
-        ),
-        0b000011 => (
-            "Trapped MCR or MRC access with coproc=0b1111",
-            decode_iss_mcr(iss.value)?,
-            None,
-        ),
-        0b000100 => (
-            "Trapped MCRR or MRRC access with coproc=0b1111",
-            decode_iss_mcrr(iss.value)?,
-            None,
-        ),
-        0b000101 => (
-            "Trapped MCR or MRC access with coproc=0b1110",
-            decode_iss_mcr(iss.value)?,
-            None,
-        ),
-        0b000110 => (
-            "Trapped LDC or STC access",
-            decode_iss_ldc(iss.value)?,
-            None,
-        ),
-        0b000111 => (
-            "Trapped access to SVE, Advanced SIMD or floating point",
-            decode_iss_sve(iss.value)?,
-            None,
-        ),
-        0b001010 => (
-            "Trapped execution of an LD64B, ST64B, ST64BV, or ST64BV0 instruction",
-            decode_iss_ld64b(iss.value)?,
-            None,
-        ),
-        0b001100 => (
-            "Trapped MRRC access with (coproc==0b1110)",
-            decode_iss_mcrr(iss.value)?,
-            None,
-        ),
-        0b001101 => ("Branch Target Exception", decode_iss_bti(iss.value)?, None),
-        0b001110 => ("Illegal Execution state", decode_iss_res0(iss.value)?, None),
-        0b010001 => (
-            "SVC instruction execution in AArch32 state",
-            decode_iss_hvc(iss.value)?,
-            None,
-        ),
-        0b010101 => (
-            "SVC instruction execution in AArch64 state",
-            decode_iss_hvc(iss.value)?,
-            None,
-        ),
-        0b010110 => (
-            "HVC instruction execution in AArch64 state",
-            decode_iss_hvc(iss.value)?,
-            None,
-        ),
-        0b010111 => (
-            "SMC instruction execution in AArch64 state",
-            decode_iss_hvc(iss.value)?,
-            None,
-        ),
-        0b011000 => {
-            let (subfields, description) = decode_iss_msr(iss.value)?;
-            (
-                "Trapped MSR, MRS or System instruction execution in AArch64 state",
-                subfields,
-                description,
-            )
+/// The name and bit range within the ESR of a field which may be present in the ISS or ISS2 of
+/// some exception class.
+type IssField = (&'static str, Range<usize>);
+
+/// Returns the range of a single-bit field.
+const fn bit(index: usize) -> Range<usize> {
+    index..index + 1
+}
+
+/// Returns the range within the ESR of the given range within ISS2.
+const fn iss2(bits: Range<usize>) -> Range<usize> {
+    Esr::ISS2.start + bits.start..Esr::ISS2.start + bits.end
+}
+
+/// The non-reserved ISS fields which may be present for an IMPLEMENTATION DEFINED exception to
+/// EL3.
+const IMPDEF_FIELDS: &[IssField] = &[("IMPDEF", 0..25)];
+
+fn decode_iss_impdef(iss: u64) -> FieldList {
+    let impdef = StaticFieldInfo::get(iss, "IMPDEF", Some("Implementation defined"), 0, 25)
+        .with_description("ISS is IMPLEMENTATION DEFINED");
+    FieldList::from([impdef])
+}
+
+/// An Exception Syndrome Register value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Esr(pub u64);
+
+impl Esr {
+    const ISS2: Range<usize> = 32..56;
+    const EC: Range<usize> = 26..32;
+    const IL: usize = 25;
+    const ISS: Range<usize> = 0..25;
+
+    /// Returns the Exception Class, or an error if it is not a valid value.
+    pub fn exception_class(self) -> Result<ExceptionClass, DecodeError> {
+        ExceptionClass::try_from(self.0.get_bits(Self::EC))
+    }
+
+    /// Returns true if the trapped instruction was 32 bits long, or false if it was 16 bits long.
+    pub fn il(self) -> bool {
+        self.0.get_bit(Self::IL)
+    }
+
+    /// Returns the raw Instruction Specific Syndrome value.
+    pub fn iss(self) -> u64 {
+        self.0.get_bits(Self::ISS)
+    }
+
+    /// Returns the raw ISS2 value.
+    pub fn iss2(self) -> u64 {
+        self.0.get_bits(Self::ISS2)
+    }
+
+    /// Returns the Instruction Abort syndrome, if this is an Instruction Abort.
+    pub fn instruction_abort(self) -> Option<InstructionAbortIss> {
+        match self.exception_class() {
+            Ok(
+                ExceptionClass::InstructionAbortLowerEl | ExceptionClass::InstructionAbortSameEl,
+            ) => Some(InstructionAbortIss::new(self.iss())),
+            _ => None,
+        }
+    }
+
+    /// Returns the Data Abort syndrome, if this is a Data Abort.
+    pub fn data_abort(self) -> Option<DataAbortIss> {
+        match self.exception_class() {
+            Ok(ExceptionClass::DataAbortLowerEl | ExceptionClass::DataAbortSameEl) => {
+                Some(DataAbortIss::new(self.iss(), self.iss2()))
+            }
+            _ => None,
         }
-        0b011001 => (
-            "Access to SVE functionality trapped as a result of CPACR_EL1.ZEN, CPTR_EL2.ZEN, \
-                 CPTR_EL2.TZ, or CPTR_EL3.EZ",
-            decode_iss_res0(iss.value)?,
-            None,
-        ),
-        0b011100 => (
-            "Exception from a Pointer Authentication instruction authentication failure",
-            decode_iss_pauth(iss.value)?,
-            None,
-        ),
-        0b100000 => (
-            "Instruction Abort from a lower Exception level",
-            decode_iss_instruction_abort(iss.value)?,
-            None,
-        ),
-        0b100001 => (
-            "Instruction Abort taken without a change in Exception level",
-            decode_iss_instruction_abort(iss.value)?,
-            None,
-        ),
-        0b100010 => (
-            "PC alignment fault exception",
-            decode_iss_res0(iss.value)?,
-            None,
-        ),
-        0b100100 => (
-            "Data Abort from a lower Exception level",
-            decode_iss_data_abort(iss.value)?,
-            None,
-        ),
-        0b100101 => (
-            "Data Abort taken without a change in Exception level",
-            decode_iss_data_abort(iss.value)?,
-            None,
-        ),
-        0b100110 => (
-            "SP alignment fault exception",
-            decode_iss_res0(iss.value)?,
-            None,
-        ),
-        0b101000 => (
-            "Trapped floating-point exception taken from AArch32 state",
-            decode_iss_fp(iss.value)?,
-            None,
-        ),
-        0b101100 => (
-            "Trapped floating-point exception taken from AArch64 state",
-            decode_iss_fp(iss.value)?,
-            None,
-        ),
-        0b101111 => ("SError interrupt", decode_iss_serror(iss.value)?, None),
-        0b110000 => (
-            "Breakpoint exception from a lower Exception level",
-            decode_iss_breakpoint_vector_catch(iss.value)?,
-            None,
-        ),
-        0b110001 => (
-            "Breakpoint exception taken without a change in Exception level",
-            decode_iss_breakpoint_vector_catch(iss.value)?,
-            None,
-        ),
-        0b110010 => (
-            "Software Step exception from a lower Exception level",
-            decode_iss_software_step(iss.value)?,
-            None,
-        ),
-        0b110011 => (
-            "Software Step exception taken without a change in Exception level",
-            decode_iss_software_step(iss.value)?,
-            None,
-        ),
-        0b110100 => (
-            "Watchpoint exception from a lower Exception level",
-            decode_iss_watchpoint(iss.value)?,
-            None,
-        ),
-        0b110101 => (
-            "Watchpoint exception taken without a change in Exception level",
-            decode_iss_watchpoint(iss.value)?,
-            None,
-        ),
-        0b111000 => (
-            "BKPT instruction execution in AArch32 state",
-            decode_iss_breakpoint(iss.value)?,
-            None,
-        ),
-        0b111100 => (
-            "BRK instruction execution in AArch64 state",
-            decode_iss_breakpoint(iss.value)?,
-            None,
-        ),
-        _ => return Err(DecodeError::InvalidEc { ec: ec.value }),
+    }
+
+    /// Returns the trapped system register access syndrome, if this is a trapped MSR, MRS or
+    /// System instruction.
+    pub fn sys_reg_trap(self) -> Option<SysRegTrapIss> {
+        match self.exception_class() {
+            Ok(ExceptionClass::SysReg) => Some(SysRegTrapIss::new(self.iss())),
+            _ => None,
+        }
+    }
+
+    /// Returns the Watchpoint syndrome, if this is a Watchpoint exception.
+    pub fn watchpoint(self) -> Option<WatchpointIss> {
+        match self.exception_class() {
+            Ok(ExceptionClass::WatchpointLowerEl | ExceptionClass::WatchpointSameEl) => {
+                Some(WatchpointIss::new(self.iss()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Decodes the value into a tree of fields, or returns an error if it is not valid.
+    #[cfg(feature = "alloc")]
+    pub fn decode(self) -> Result<Vec<FieldInfo>, FieldError> {
+        decode(self.0)
+    }
+
+    /// Decodes the value without allocating, or returns an error if it is not valid.
+    pub fn decode_static(self) -> Result<DecodedEsr, FieldError> {
+        decode_static(self.0)
+    }
+}
+
+impl From<u64> for Esr {
+    fn from(esr: u64) -> Self {
+        Self(esr)
+    }
+}
+
+/// Decodes the given Exception Syndrome Register value, or returns an error if it is not valid.
+#[cfg(feature = "alloc")]
+pub fn decode(esr: u64) -> Result<Vec<FieldInfo>, FieldError> {
+    decode_with_options(esr, &DecodeOptions::default())
+}
+
+/// Decodes the given Exception Syndrome Register value using the given context about where it came
+/// from, or returns an error if it is not valid.
+#[cfg(feature = "alloc")]
+pub fn decode_with_options(
+    esr: u64,
+    options: &DecodeOptions,
+) -> Result<Vec<FieldInfo>, FieldError> {
+    let decoded = decode_static_with_options(esr, options)?;
+    let [res0, iss2, ec, il, iss] = decoded.fields;
+    let iss2 = FieldInfo {
+        subfields: decoded.iss2_subfields.into_iter().map(Into::into).collect(),
+        ..iss2.into()
     };
     let iss = FieldInfo {
-        description: iss_description,
-        subfields: iss_subfields,
-        ..iss
+        description: decoded.iss_description.as_ref().map(ToString::to_string),
+        subfields: decoded.iss_subfields.into_iter().map(Into::into).collect(),
+        ..iss.into()
+    };
+    Ok(vec![res0.into(), iss2, ec.into(), il.into(), iss])
+}
+
+/// Decodes the given Exception Syndrome Register value without allocating, or returns an error if
+/// it is not valid.
+pub fn decode_static(esr: u64) -> Result<DecodedEsr, FieldError> {
+    decode_static_with_options(esr, &DecodeOptions::default())
+}
+
+/// Decodes the given Exception Syndrome Register value without allocating, using the given context
+/// about where it came from, or returns an error if it is not valid.
+///
+/// If `options.lenient` is set then an error is never returned; invalid fields have a diagnostic
+/// instead.
+pub fn decode_static_with_options(
+    esr: u64,
+    options: &DecodeOptions,
+) -> Result<DecodedEsr, FieldError> {
+    let res0 = StaticFieldInfo::get(esr, "RES0", Some("Reserved"), 56, 64).check_res0();
+    let typed = Esr(esr);
+    let iss2 = StaticFieldInfo::new("ISS2", None, Esr::ISS2, typed.iss2());
+    // The raw value is used rather than `exception_class()` so that invalid values can be shown.
+    let ec = StaticFieldInfo::new(
+        "EC",
+        Some("Exception Class"),
+        Esr::EC,
+        esr.get_bits(Esr::EC),
+    );
+    let il = StaticFieldInfo::new_bit("IL", Some("Instruction Length"), Esr::IL, typed.il())
+        .describe_bit(describe_il);
+    let iss = StaticFieldInfo::new(
+        "ISS",
+        Some("Instruction Specific Syndrome"),
+        Esr::ISS,
+        typed.iss(),
+    );
+    let decoded = match typed.exception_class() {
+        Ok(class) => {
+            let ec = ec.with_description(options.describe_class(class));
+            let ec = match options.check(class) {
+                Ok(()) => ec,
+                Err(diagnostic) => ec.with_diagnostic(diagnostic),
+            };
+            let iss_subfields = decode_iss(class, iss.value, iss2.value, options.features);
+            let (iss2_subfields, iss2_description) = match typed.data_abort() {
+                Some(data_abort) => decode_iss2_data_abort(data_abort),
+                None => (FieldList::new(), None),
+            };
+            let iss2 = StaticFieldInfo {
+                description: iss2_description,
+                ..iss2
+            };
+            // The ISS description assumes that the ISS fields are valid.
+            let iss_description = if iss_subfields.iter().all(|field| field.diagnostic.is_none()) {
+                IssDescription::new(class, iss.value)
+            } else {
+                None
+            };
+            DecodedEsr {
+                fields: [res0, iss2, ec, il, iss],
+                iss2_subfields,
+                iss_subfields,
+                iss_description,
+            }
+        }
+        Err(diagnostic) => DecodedEsr {
+            fields: [res0, iss2, ec.with_diagnostic(diagnostic), il, iss],
+            iss2_subfields: FieldList::new(),
+            iss_subfields: FieldList::new(),
+            iss_description: None,
+        },
     };
-    let ec = ec.with_description(class.to_string());
-    Ok(vec![res0, iss2, ec, il, iss])
+    if !options.lenient
+        && let Some(error) = decoded.diagnostics().next()
+    {
+        return Err(error);
+    }
+    Ok(decoded)
+}
+
+/// Decodes the ISS value for the given exception class.
+fn decode_iss(class: ExceptionClass, iss: u64, iss2: u64, features: Features) -> FieldList {
+    match class {
+        ExceptionClass::Unknown
+        | ExceptionClass::PacTrap
+        | ExceptionClass::IllegalExecutionState
+        | ExceptionClass::Sve
+        | ExceptionClass::PcAlignment
+        | ExceptionClass::SpAlignment => decode_iss_res0(iss),
+        ExceptionClass::Wf => decode_iss_wf(iss),
+        ExceptionClass::McrMrc15 | ExceptionClass::McrMrc14 | ExceptionClass::Vmrs => {
+            decode_iss_mcr(iss)
+        }
+        ExceptionClass::McrrMrrc15 | ExceptionClass::Mrrc14 => decode_iss_mcrr(iss),
+        ExceptionClass::LdcStc => decode_iss_ldc(iss),
+        ExceptionClass::SimdFp => decode_iss_sve(iss),
+        ExceptionClass::Ld64b => decode_iss_ld64b(iss),
+        ExceptionClass::BranchTarget => decode_iss_bti(iss),
+        ExceptionClass::Svc32
+        | ExceptionClass::Hvc32
+        | ExceptionClass::Svc64
+        | ExceptionClass::Hvc64
+        | ExceptionClass::Smc64 => decode_iss_hvc(iss),
+        ExceptionClass::Smc32 => decode_iss_smc32(iss),
+        ExceptionClass::SysReg128 => decode_iss_msrr(iss),
+        ExceptionClass::SysReg => decode_iss_msr(iss),
+        ExceptionClass::Eret => decode_iss_eret(iss),
+        ExceptionClass::Tstart => decode_iss_tstart(iss),
+        ExceptionClass::PacFail => decode_iss_pauth(iss),
+        ExceptionClass::Sme => decode_iss_sme(iss),
+        ExceptionClass::GranuleProtectionCheck => decode_iss_gpc(iss, features),
+        ExceptionClass::ImpDefEl3 => decode_iss_impdef(iss),
+        ExceptionClass::InstructionAbortLowerEl | ExceptionClass::InstructionAbortSameEl => {
+            decode_iss_instruction_abort(InstructionAbortIss::new(iss), features)
+        }
+        ExceptionClass::DataAbortLowerEl | ExceptionClass::DataAbortSameEl => {
+            decode_iss_data_abort(DataAbortIss::new(iss, iss2), features)
+        }
+        ExceptionClass::Mops => decode_iss_mops(iss),
+        ExceptionClass::Fp32 | ExceptionClass::Fp64 => decode_iss_fp(iss),
+        ExceptionClass::Gcs => decode_iss_gcs(iss),
+        ExceptionClass::SError => decode_iss_serror(iss),
+        ExceptionClass::BreakpointLowerEl
+        | ExceptionClass::BreakpointSameEl
+        | ExceptionClass::VectorCatch32 => decode_iss_breakpoint_vector_catch(iss),
+        ExceptionClass::SoftwareStepLowerEl | ExceptionClass::SoftwareStepSameEl => {
+            decode_iss_software_step(iss)
+        }
+        ExceptionClass::WatchpointLowerEl | ExceptionClass::WatchpointSameEl => {
+            decode_iss_watchpoint(WatchpointIss::new(iss), features)
+        }
+        ExceptionClass::Bkpt32 | ExceptionClass::Brk64 => decode_iss_breakpoint(iss),
+        ExceptionClass::Pmu => decode_iss_pmu(iss),
+    }
+}
+
+/// An Exception Syndrome Register value decoded without allocating, as returned by
+/// [`decode_static`].
+///
+/// The `Display` implementation prints the fields in the same format as the command-line tool. The
+/// alternate form (`{:#}`) includes the long names of fields too.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecodedEsr {
+    /// The top-level fields, in the same order as returned by `decode`: RES0, ISS2, EC, IL and ISS.
+    pub fields: [StaticFieldInfo; 5],
+    /// The subfields of ISS2.
+    pub iss2_subfields: FieldList,
+    /// The subfields of ISS.
+    pub iss_subfields: FieldList,
+    /// A description of the ISS as a whole, such as the trapped instruction, if available.
+    pub iss_description: Option<IssDescription>,
+}
+
+impl DecodedEsr {
+    /// Returns the diagnostics of all fields and subfields, along with where they were found.
+    pub fn diagnostics(&self) -> impl Iterator<Item = FieldError> {
+        let ec = ExceptionClass::try_from(self.fields[2].value).ok();
+        self.fields
+            .iter()
+            .enumerate()
+            .flat_map(|(index, parent)| {
+                let subfields = self
+                    .subfields(index)
+                    .iter()
+                    .map(move |field| (Some(parent.name), field));
+                core::iter::once((None, parent)).chain(subfields)
+            })
+            .filter_map(move |(parent, field)| {
+                Some(FieldError {
+                    field: FieldPath {
+                        parent,
+                        name: field.name,
+                        start: field.start,
+                        width: field.width,
+                    },
+                    ec,
+                    error: field.diagnostic?,
+                })
+            })
+    }
+
+    /// Returns the field with the given path, such as `"ISS.DFSC"`, if there is one.
+    ///
+    /// Paths are in the same format as for [`get_field`](crate::get_field).
+    pub fn get(&self, path: &str) -> Option<&StaticFieldInfo> {
+        let (segment, rest) = split_path(path);
+        let matches = |field: &StaticFieldInfo| {
+            path_segment_matches(segment, field.name, field.start, field.width)
+        };
+        let index = self.fields.iter().position(matches)?;
+        let Some(rest) = rest else {
+            return Some(&self.fields[index]);
+        };
+        let (segment, rest) = split_path(rest);
+        if rest.is_some() {
+            return None;
+        }
+        self.subfields(index)
+            .iter()
+            .find(|field| path_segment_matches(segment, field.name, field.start, field.width))
+    }
+
+    /// Returns the subfields of the top-level field with the given index in `fields`.
+    pub fn subfields(&self, index: usize) -> &[StaticFieldInfo] {
+        match index {
+            1 => &self.iss2_subfields,
+            4 => &self.iss_subfields,
+            _ => &[],
+        }
+    }
+}
+
+impl Display for DecodedEsr {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let options = FormatOptions {
+            long_names: f.alternate(),
+            ..Default::default()
+        };
+        self.fmt_with_options(f, &options)
+    }
+}
+
+impl DecodedEsr {
+    /// Returns a value which formats the fields with `Display` using the given options.
+    pub fn display_with(&self, options: FormatOptions) -> impl Display + '_ {
+        DisplayDecodedEsr {
+            decoded: self,
+            options,
+        }
+    }
+
+    fn fmt_with_options(&self, f: &mut Formatter, options: &FormatOptions) -> fmt::Result {
+        for (index, field) in self.fields.iter().enumerate() {
+            let mut line = FieldLine::from(field);
+            if index == 4
+                && let Some(iss_description) = &self.iss_description
+            {
+                line.description = Some(iss_description);
+            }
+            line.fmt(f, options, 0)?;
+            for subfield in self.subfields(index) {
+                FieldLine::from(subfield).fmt(f, options, 1)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A `DecodedEsr` to be formatted with particular options.
+struct DisplayDecodedEsr<'a> {
+    decoded: &'a DecodedEsr,
+    options: FormatOptions,
+}
+
+impl Display for DisplayDecodedEsr<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.decoded.fmt_with_options(f, &self.options)
+    }
+}
+
+/// A description of an ISS value as a whole, such as the mnemonic of the trapped instruction.
+///
+/// This doesn't need a heap, but may be formatted with `Display`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct IssDescription {
+    class: ExceptionClass,
+    iss: u64,
+}
+
+impl IssDescription {
+    /// Returns the description for the given valid ISS value, if there is one for the exception
+    /// class.
+    fn new(class: ExceptionClass, iss: u64) -> Option<Self> {
+        let has_description = match class {
+            ExceptionClass::McrMrc15
+            | ExceptionClass::McrrMrrc15
+            | ExceptionClass::McrMrc14
+            | ExceptionClass::Mrrc14
+            | ExceptionClass::SysReg128
+            | ExceptionClass::SysReg
+            | ExceptionClass::Eret
+            | ExceptionClass::Tstart
+            | ExceptionClass::Mops => true,
+            ExceptionClass::Gcs => has_gcs_instruction(iss),
+            ExceptionClass::WatchpointLowerEl | ExceptionClass::WatchpointSameEl => {
+                WatchpointIss::new(iss).wptv()
+            }
+            _ => false,
+        };
+        has_description.then_some(Self { class, iss })
+    }
+}
+
+impl Display for IssDescription {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.class {
+            ExceptionClass::McrMrc15 => fmt_iss_mcr(f, self.iss, 15),
+            ExceptionClass::McrrMrrc15 => fmt_iss_mcrr(f, self.iss, 15),
+            ExceptionClass::McrMrc14 => fmt_iss_mcr(f, self.iss, 14),
+            ExceptionClass::Mrrc14 => fmt_iss_mcrr(f, self.iss, 14),
+            ExceptionClass::SysReg128 => fmt_iss_msrr(f, self.iss),
+            ExceptionClass::SysReg => fmt_iss_msr(f, self.iss),
+            ExceptionClass::Eret => f.write_str(describe_iss_eret(self.iss)),
+            ExceptionClass::Tstart => fmt_iss_tstart(f, self.iss),
+            ExceptionClass::Mops => fmt_iss_mops(f, self.iss),
+            ExceptionClass::Gcs => fmt_iss_gcs(f, self.iss),
+            ExceptionClass::WatchpointLowerEl | ExceptionClass::WatchpointSameEl => {
+                fmt_iss_watchpoint(f, WatchpointIss::new(self.iss))
+            }
+            _ => unreachable!(),
+        }
+    }
 }
 
 fn describe_il(il: bool) -> &'static str {
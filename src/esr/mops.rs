@@ -0,0 +1,154 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::IssField;
+use crate::{FieldList, StaticFieldInfo};
+use bit_field::BitField;
+use core::fmt::{self, Formatter};
+
+/// The non-reserved ISS fields which may be present for a Memory Copy or Memory Set exception.
+pub const MOPS_FIELDS: &[IssField] = &[
+    ("MemInst", 24..25),
+    ("isSETG", 23..24),
+    ("Options", 19..23),
+    ("FromEpilogue", 18..19),
+    ("wrong_option", 17..18),
+    ("option_A", 16..17),
+    ("destreg", 10..15),
+    ("srcreg", 5..10),
+    ("sizereg", 0..5),
+];
+
+/// Decodes the ISS value for a Memory Copy or Memory Set exception.
+pub fn decode_iss_mops(iss: u64) -> FieldList {
+    let mem_inst = StaticFieldInfo::get_bit(iss, "MemInst", Some("Memory Instruction"), 24)
+        .describe_bit(describe_mem_inst);
+    let is_setg = StaticFieldInfo::get_bit(iss, "isSETG", Some("SETG* instruction"), 23)
+        .describe_bit(describe_is_setg);
+    let options = StaticFieldInfo::get(iss, "Options", None, 19, 23);
+    let from_epilogue = StaticFieldInfo::get_bit(iss, "FromEpilogue", None, 18)
+        .describe_bit(describe_from_epilogue);
+    let wrong_option =
+        StaticFieldInfo::get_bit(iss, "wrong_option", None, 17).describe_bit(describe_wrong_option);
+    let option_a =
+        StaticFieldInfo::get_bit(iss, "option_A", None, 16).describe_bit(describe_option_a);
+    let res0 = StaticFieldInfo::get_bit(iss, "RES0", Some("Reserved"), 15).check_res0();
+    let destreg = StaticFieldInfo::get(iss, "destreg", Some("Destination register"), 10, 15);
+    let srcreg = StaticFieldInfo::get(iss, "srcreg", Some("Source register"), 5, 10);
+    let sizereg = StaticFieldInfo::get(iss, "sizereg", Some("Size register"), 0, 5);
+
+    FieldList::from([
+        mem_inst,
+        is_setg,
+        options,
+        from_epilogue,
+        wrong_option,
+        option_a,
+        res0,
+        destreg,
+        srcreg,
+        sizereg,
+    ])
+}
+
+/// Writes the trapped instruction for the given ISS value.
+pub fn fmt_iss_mops(f: &mut Formatter, iss: u64) -> fmt::Result {
+    let options = iss.get_bits(19..23);
+    let destreg = iss.get_bits(10..15);
+    let srcreg = iss.get_bits(5..10);
+    let sizereg = iss.get_bits(0..5);
+    let stage = if iss.get_bit(18) { "E" } else { "M" };
+    if iss.get_bit(24) {
+        let prefix = if iss.get_bit(23) { "SETG" } else { "SET" };
+        let suffix = set_options_suffix(options);
+        write!(
+            f,
+            "{prefix}{stage}{suffix} [x{destreg}]!, x{sizereg}!, x{srcreg}"
+        )
+    } else {
+        let (unprivileged, non_temporal) = cpy_options_suffix(options);
+        write!(
+            f,
+            "CPY[F]{stage}{unprivileged}{non_temporal} [x{destreg}]!, [x{srcreg}]!, x{sizereg}!"
+        )
+    }
+}
+
+/// Returns the unprivileged and non-temporal parts of the mnemonic suffix for the given options of
+/// a CPY* or CPYF* instruction.
+fn cpy_options_suffix(options: u64) -> (&'static str, &'static str) {
+    let unprivileged = match options & 0b0011 {
+        0b00 => "",
+        0b01 => "WT",
+        0b10 => "RT",
+        _ => "T",
+    };
+    let non_temporal = match options >> 2 {
+        0b00 => "",
+        0b01 => "WN",
+        0b10 => "RN",
+        _ => "N",
+    };
+    (unprivileged, non_temporal)
+}
+
+/// Returns the mnemonic suffix for the given options of a SET* or SETG* instruction.
+fn set_options_suffix(options: u64) -> &'static str {
+    match options & 0b0011 {
+        0b00 => "",
+        0b01 => "T",
+        0b10 => "N",
+        _ => "TN",
+    }
+}
+
+fn describe_mem_inst(mem_inst: bool) -> &'static str {
+    if mem_inst {
+        "SET* or SETG* instruction"
+    } else {
+        "CPY* or CPYF* instruction"
+    }
+}
+
+fn describe_is_setg(is_setg: bool) -> &'static str {
+    if is_setg {
+        "SETG* instruction"
+    } else {
+        "Not a SETG* instruction"
+    }
+}
+
+fn describe_from_epilogue(from_epilogue: bool) -> &'static str {
+    if from_epilogue {
+        "Exception taken on the epilogue instruction"
+    } else {
+        "Exception taken on the main instruction"
+    }
+}
+
+fn describe_wrong_option(wrong_option: bool) -> &'static str {
+    if wrong_option {
+        "The instruction was executed with the wrong algorithm option for this PE"
+    } else {
+        "The algorithm option was not the cause of the exception"
+    }
+}
+
+fn describe_option_a(option_a: bool) -> &'static str {
+    if option_a {
+        "The algorithm option used was option A"
+    } else {
+        "The algorithm option used was option B"
+    }
+}
@@ -12,42 +12,189 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{DecodeError, FieldInfo};
+use super::sysreg_names::{sys_instruction_name, sysreg_name};
+use super::{IssField, bit};
+use crate::{FieldList, StaticFieldInfo};
+use bit_field::BitField;
+use core::fmt::{self, Formatter};
+use core::ops::Range;
+
+/// The ISS value of a trapped MSR, MRS or System instruction.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SysRegTrapIss {
+    iss: u64,
+}
+
+impl SysRegTrapIss {
+    const OP0: Range<usize> = 20..22;
+    const OP2: Range<usize> = 17..20;
+    const OP1: Range<usize> = 14..17;
+    const CRN: Range<usize> = 10..14;
+    const RT: Range<usize> = 5..10;
+    const CRM: Range<usize> = 1..5;
+    const DIRECTION: usize = 0;
+
+    /// Creates a new `SysRegTrapIss` from the given ISS field value.
+    pub fn new(iss: u64) -> Self {
+        Self { iss }
+    }
+
+    /// Returns the raw ISS value.
+    pub fn iss(self) -> u64 {
+        self.iss
+    }
+
+    /// Returns the Op0 value of the trapped instruction.
+    pub fn op0(self) -> u64 {
+        self.iss.get_bits(Self::OP0)
+    }
+
+    /// Returns the Op2 value of the trapped instruction.
+    pub fn op2(self) -> u64 {
+        self.iss.get_bits(Self::OP2)
+    }
+
+    /// Returns the Op1 value of the trapped instruction.
+    pub fn op1(self) -> u64 {
+        self.iss.get_bits(Self::OP1)
+    }
+
+    /// Returns the CRn value of the trapped instruction.
+    pub fn crn(self) -> u64 {
+        self.iss.get_bits(Self::CRN)
+    }
+
+    /// Returns the general-purpose register number of the trapped instruction.
+    pub fn rt(self) -> u64 {
+        self.iss.get_bits(Self::RT)
+    }
+
+    /// Returns the CRm value of the trapped instruction.
+    pub fn crm(self) -> u64 {
+        self.iss.get_bits(Self::CRM)
+    }
+
+    /// Returns true if the trapped instruction was a read (MRS), or false if it was a write (MSR).
+    pub fn is_read(self) -> bool {
+        self.iss.get_bit(Self::DIRECTION)
+    }
+
+    /// Returns the name of the system register accessed, or "unknown" if it is not known.
+    pub fn name(self) -> &'static str {
+        sysreg_name(
+            self.op0(),
+            self.op1(),
+            self.op2(),
+            self.crn(),
+            self.crm(),
+            self.is_read(),
+        )
+        .unwrap_or("unknown")
+    }
+
+    /// Returns whether the trapped instruction was a System instruction such as `TLBI` or `DC`,
+    /// rather than an access to a system register.
+    pub fn is_sys_instruction(self) -> bool {
+        self.op0() == 1
+    }
+
+    /// Returns the name of the trapped System instruction, such as `"TLBI VAE1IS"`, if it was one
+    /// and is known.
+    pub fn sys_instruction_name(self) -> Option<&'static str> {
+        self.sys_instruction().map(|(name, _)| name)
+    }
+
+    /// Returns the name of the trapped System instruction and whether it takes a register operand.
+    fn sys_instruction(self) -> Option<(&'static str, bool)> {
+        if !self.is_sys_instruction() || self.is_read() {
+            return None;
+        }
+        sys_instruction_name(self.op1(), self.op2(), self.crn(), self.crm())
+    }
+}
+
+/// The non-reserved ISS fields which may be present for an MSR or MRS instruction.
+pub const MSR_FIELDS: &[IssField] = &[
+    ("Op0", SysRegTrapIss::OP0),
+    ("Op2", SysRegTrapIss::OP2),
+    ("Op1", SysRegTrapIss::OP1),
+    ("CRn", SysRegTrapIss::CRN),
+    ("Rt", SysRegTrapIss::RT),
+    ("CRm", SysRegTrapIss::CRM),
+    ("Direction", bit(SysRegTrapIss::DIRECTION)),
+];
 
 /// Decodes the ISS value for an MSR or MRS instruction.
-pub fn decode_iss_msr(iss: u64) -> Result<(Vec<FieldInfo>, Option<String>), DecodeError> {
-    let res0 = FieldInfo::get(iss, "RES0", Some("Reserved"), 22, 25).check_res0()?;
-    let op0 = FieldInfo::get(iss, "Op0", None, 20, 22);
-    let op2 = FieldInfo::get(iss, "Op2", None, 17, 20);
-    let op1 = FieldInfo::get(iss, "Op1", None, 14, 17);
-    let crn = FieldInfo::get(iss, "CRn", None, 10, 14);
-    let rt = FieldInfo::get(
-        iss,
+pub fn decode_iss_msr(iss: u64) -> FieldList {
+    let sys_reg_trap = SysRegTrapIss::new(iss);
+    let res0 = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 22, 25).check_res0();
+    let op0 = StaticFieldInfo::new("Op0", None, SysRegTrapIss::OP0, sys_reg_trap.op0());
+    let op2 = StaticFieldInfo::new("Op2", None, SysRegTrapIss::OP2, sys_reg_trap.op2());
+    let op1 = StaticFieldInfo::new("Op1", None, SysRegTrapIss::OP1, sys_reg_trap.op1());
+    let crn = StaticFieldInfo::new("CRn", None, SysRegTrapIss::CRN, sys_reg_trap.crn());
+    let rt = StaticFieldInfo::new(
         "Rt",
         Some("General-purpose register number of the trapped instruction"),
-        5,
-        10,
+        SysRegTrapIss::RT,
+        sys_reg_trap.rt(),
     );
-    let crm = FieldInfo::get(iss, "CRm", None, 1, 5);
-    let direction = FieldInfo::get_bit(
-        iss,
+    let crm = StaticFieldInfo::new("CRm", None, SysRegTrapIss::CRM, sys_reg_trap.crm());
+    let direction = StaticFieldInfo::new_bit(
         "Direction",
         Some("Direction of the trapped instruction"),
-        0,
+        SysRegTrapIss::DIRECTION,
+        sys_reg_trap.is_read(),
     )
     .describe_bit(describe_direction);
 
-    let name = sysreg_name(op0.value, op1.value, op2.value, crn.value, crm.value);
-    let description = if direction.value == 0 {
-        format!("MSR {}, x{}", name, rt.value)
-    } else {
-        format!("MRS x{}, {}", rt.value, name)
-    };
+    FieldList::from([res0, op0, op2, op1, crn, rt, crm, direction])
+}
 
-    Ok((
-        vec![res0, op0, op2, op1, crn, rt, crm, direction],
-        Some(description),
-    ))
+/// Writes the trapped instruction for the given ISS value.
+pub fn fmt_iss_msr(f: &mut Formatter, iss: u64) -> fmt::Result {
+    let sys_reg_trap = SysRegTrapIss::new(iss);
+    let rt = sys_reg_trap.rt();
+    if sys_reg_trap.is_sys_instruction() {
+        let takes_register = match sys_reg_trap.sys_instruction() {
+            Some((name, takes_register)) => {
+                f.write_str(name)?;
+                takes_register
+            }
+            None if sys_reg_trap.is_read() => {
+                return write!(
+                    f,
+                    "SYSL x{rt}, #{}, C{}, C{}, #{}",
+                    sys_reg_trap.op1(),
+                    sys_reg_trap.crn(),
+                    sys_reg_trap.crm(),
+                    sys_reg_trap.op2()
+                );
+            }
+            None => {
+                write!(
+                    f,
+                    "SYS #{}, C{}, C{}, #{}",
+                    sys_reg_trap.op1(),
+                    sys_reg_trap.crn(),
+                    sys_reg_trap.crm(),
+                    sys_reg_trap.op2()
+                )?;
+                false
+            }
+        };
+        // The register operand is optional for instructions which don't use it, and defaults to
+        // XZR.
+        if takes_register || rt != 31 {
+            write!(f, ", x{rt}")?;
+        }
+        return Ok(());
+    }
+    let name = sys_reg_trap.name();
+    if sys_reg_trap.is_read() {
+        write!(f, "MRS x{}, {}", sys_reg_trap.rt(), name)
+    } else {
+        write!(f, "MSR {}, x{}", name, sys_reg_trap.rt())
+    }
 }
 
 fn describe_direction(direction: bool) -> &'static str {
@@ -57,583 +204,3 @@ fn describe_direction(direction: bool) -> &'static str {
         "Write to system register (MSR)"
     }
 }
-
-fn sysreg_name(op0: u64, op1: u64, op2: u64, crn: u64, crm: u64) -> &'static str {
-    match (op0, crn, op1, crm, op2) {
-        (3, 13, 0, 0, 5) => "ACCDATA_EL1",
-        (3, 1, 0, 4, 5) => "ACTLRALIAS_EL1",
-        (3, 1, 0, 4, 1) => "ACTLRMASK_EL1",
-        (3, 1, 5, 4, 1) => "ACTLRMASK_EL12",
-        (3, 1, 4, 4, 1) => "ACTLRMASK_EL2",
-        (3, 1, 0, 0, 1) => "ACTLR_EL1",
-        (3, 1, 5, 0, 1) => "ACTLR_EL12",
-        (3, 1, 4, 0, 1) => "ACTLR_EL2",
-        (3, 1, 6, 0, 1) => "ACTLR_EL3",
-        (3, 5, 0, 1, 0) => "AFSR0_EL1",
-        (3, 5, 5, 1, 0) => "AFSR0_EL12",
-        (3, 5, 4, 1, 0) => "AFSR0_EL2",
-        (3, 5, 6, 1, 0) => "AFSR0_EL3",
-        (3, 5, 0, 1, 1) => "AFSR1_EL1",
-        (3, 5, 5, 1, 1) => "AFSR1_EL12",
-        (3, 5, 4, 1, 1) => "AFSR1_EL2",
-        (3, 5, 6, 1, 1) => "AFSR1_EL3",
-        (3, 0, 1, 0, 7) => "AIDR_EL1",
-        (3, 4, 0, 3, 0) => "ALLINT",
-        (3, 10, 0, 3, 1) => "AMAIR2_EL1",
-        (3, 10, 5, 3, 1) => "AMAIR2_EL12",
-        (3, 10, 4, 3, 1) => "AMAIR2_EL2",
-        (3, 10, 6, 3, 1) => "AMAIR2_EL3",
-        (3, 10, 0, 3, 0) => "AMAIR_EL1",
-        (3, 10, 5, 3, 0) => "AMAIR_EL12",
-        (3, 10, 4, 3, 0) => "AMAIR_EL2",
-        (3, 10, 6, 3, 0) => "AMAIR_EL3",
-        (3, 13, 3, 2, 1) => "AMCFGR_EL0",
-        (3, 13, 3, 2, 6) => "AMCG1IDR_EL0",
-        (3, 13, 3, 2, 2) => "AMCGCR_EL0",
-        (3, 13, 3, 2, 4) => "AMCNTENCLR0_EL0",
-        (3, 13, 3, 3, 0) => "AMCNTENCLR1_EL0",
-        (3, 13, 3, 2, 5) => "AMCNTENSET0_EL0",
-        (3, 13, 3, 3, 1) => "AMCNTENSET1_EL0",
-        (3, 13, 3, 2, 0) => "AMCR_EL0",
-        (3, 13, 3, 2, 3) => "AMUSERENR_EL0",
-        (3, 2, 0, 2, 1) => "APDAKeyHi_EL1",
-        (3, 2, 0, 2, 0) => "APDAKeyLo_EL1",
-        (3, 2, 0, 2, 3) => "APDBKeyHi_EL1",
-        (3, 2, 0, 2, 2) => "APDBKeyLo_EL1",
-        (3, 2, 0, 3, 1) => "APGAKeyHi_EL1",
-        (3, 2, 0, 3, 0) => "APGAKeyLo_EL1",
-        (3, 2, 0, 1, 1) => "APIAKeyHi_EL1",
-        (3, 2, 0, 1, 0) => "APIAKeyLo_EL1",
-        (3, 2, 0, 1, 3) => "APIBKeyHi_EL1",
-        (3, 2, 0, 1, 2) => "APIBKeyLo_EL1",
-        (2, 9, 1, 0, 0) => "BRBCR_EL1",
-        (2, 9, 5, 0, 0) => "BRBCR_EL12",
-        (2, 9, 4, 0, 0) => "BRBCR_EL2",
-        (2, 9, 1, 0, 1) => "BRBFCR_EL1",
-        (2, 9, 1, 2, 0) => "BRBIDR0_EL1",
-        (2, 9, 1, 1, 0) => "BRBINFINJ_EL1",
-        (2, 9, 1, 1, 1) => "BRBSRCINJ_EL1",
-        (2, 9, 1, 1, 2) => "BRBTGTINJ_EL1",
-        (2, 9, 1, 0, 2) => "BRBTS_EL1",
-        (3, 0, 1, 0, 2) => "CCSIDR2_EL1",
-        (3, 0, 1, 0, 0) => "CCSIDR_EL1",
-        (3, 0, 1, 0, 1) => "CLIDR_EL1",
-        (3, 14, 3, 0, 0) => "CNTFRQ_EL0",
-        (3, 14, 4, 1, 0) => "CNTHCTL_EL2",
-        (3, 14, 4, 5, 1) => "CNTHPS_CTL_EL2",
-        (3, 14, 4, 5, 2) => "CNTHPS_CVAL_EL2",
-        (3, 14, 4, 5, 0) => "CNTHPS_TVAL_EL2",
-        (3, 14, 4, 2, 1) => "CNTHP_CTL_EL2",
-        (3, 14, 4, 2, 2) => "CNTHP_CVAL_EL2",
-        (3, 14, 4, 2, 0) => "CNTHP_TVAL_EL2",
-        (3, 14, 4, 4, 1) => "CNTHVS_CTL_EL2",
-        (3, 14, 4, 4, 2) => "CNTHVS_CVAL_EL2",
-        (3, 14, 4, 4, 0) => "CNTHVS_TVAL_EL2",
-        (3, 14, 4, 3, 1) => "CNTHV_CTL_EL2",
-        (3, 14, 4, 3, 2) => "CNTHV_CVAL_EL2",
-        (3, 14, 4, 3, 0) => "CNTHV_TVAL_EL2",
-        (3, 14, 0, 1, 0) => "CNTKCTL_EL1",
-        (3, 14, 5, 1, 0) => "CNTKCTL_EL12",
-        (3, 14, 3, 0, 5) => "CNTPCTSS_EL0",
-        (3, 14, 3, 0, 1) => "CNTPCT_EL0",
-        (3, 14, 4, 0, 6) => "CNTPOFF_EL2",
-        (3, 14, 7, 2, 1) => "CNTPS_CTL_EL1",
-        (3, 14, 7, 2, 2) => "CNTPS_CVAL_EL1",
-        (3, 14, 7, 2, 0) => "CNTPS_TVAL_EL1",
-        (3, 14, 3, 2, 1) => "CNTP_CTL_EL0",
-        (3, 14, 5, 2, 1) => "CNTP_CTL_EL02",
-        (3, 14, 3, 2, 2) => "CNTP_CVAL_EL0",
-        (3, 14, 5, 2, 2) => "CNTP_CVAL_EL02",
-        (3, 14, 3, 2, 0) => "CNTP_TVAL_EL0",
-        (3, 14, 5, 2, 0) => "CNTP_TVAL_EL02",
-        (3, 14, 3, 0, 6) => "CNTVCTSS_EL0",
-        (3, 14, 3, 0, 2) => "CNTVCT_EL0",
-        (3, 14, 4, 0, 3) => "CNTVOFF_EL2",
-        (3, 14, 3, 3, 1) => "CNTV_CTL_EL0",
-        (3, 14, 5, 3, 1) => "CNTV_CTL_EL02",
-        (3, 14, 3, 3, 2) => "CNTV_CVAL_EL0",
-        (3, 14, 5, 3, 2) => "CNTV_CVAL_EL02",
-        (3, 14, 3, 3, 0) => "CNTV_TVAL_EL0",
-        (3, 14, 5, 3, 0) => "CNTV_TVAL_EL02",
-        (3, 13, 0, 0, 1) => "CONTEXTIDR_EL1",
-        (3, 13, 5, 0, 1) => "CONTEXTIDR_EL12",
-        (3, 13, 4, 0, 1) => "CONTEXTIDR_EL2",
-        (3, 1, 0, 4, 4) => "CPACRALIAS_EL1",
-        (3, 1, 0, 4, 2) => "CPACRMASK_EL1",
-        (3, 1, 5, 4, 2) => "CPACRMASK_EL12",
-        (3, 1, 0, 0, 2) => "CPACR_EL1",
-        (3, 1, 5, 0, 2) => "CPACR_EL12",
-        (3, 1, 4, 4, 2) => "CPTRMASK_EL2",
-        (3, 1, 4, 1, 2) => "CPTR_EL2",
-        (3, 1, 6, 1, 2) => "CPTR_EL3",
-        (3, 0, 2, 0, 0) => "CSSELR_EL1",
-        (3, 0, 3, 0, 1) => "CTR_EL0",
-        (3, 4, 0, 2, 2) => "CurrentEL",
-        (3, 3, 4, 0, 0) => "DACR32_EL2",
-        (3, 4, 3, 2, 1) => "DAIF",
-        (2, 7, 0, 14, 6) => "DBGAUTHSTATUS_EL1",
-        (2, 7, 0, 9, 6) => "DBGCLAIMCLR_EL1",
-        (2, 7, 0, 8, 6) => "DBGCLAIMSET_EL1",
-        (2, 0, 3, 5, 0) => "DBGDTRRX_EL0",
-        (2, 0, 3, 4, 0) => "DBGDTR_EL0",
-        (2, 1, 0, 4, 4) => "DBGPRCR_EL1",
-        (2, 0, 4, 7, 0) => "DBGVCR32_EL2",
-        (3, 0, 3, 0, 7) => "DCZID_EL0",
-        (3, 12, 0, 1, 1) => "DISR_EL1",
-        (3, 4, 3, 2, 5) => "DIT",
-        (3, 4, 3, 5, 1) => "DLR_EL0",
-        (3, 4, 3, 5, 0) => "DSPSR_EL0",
-        (3, 4, 0, 0, 1) => "ELR_EL1",
-        (3, 4, 5, 0, 1) => "ELR_EL12",
-        (3, 4, 4, 0, 1) => "ELR_EL2",
-        (3, 4, 6, 0, 1) => "ELR_EL3",
-        (3, 5, 0, 3, 0) => "ERRIDR_EL1",
-        (3, 5, 0, 3, 1) => "ERRSELR_EL1",
-        (3, 5, 0, 4, 3) => "ERXADDR_EL1",
-        (3, 5, 0, 4, 1) => "ERXCTLR_EL1",
-        (3, 5, 0, 4, 0) => "ERXFR_EL1",
-        (3, 5, 0, 3, 2) => "ERXGSR_EL1",
-        (3, 5, 0, 5, 0) => "ERXMISC0_EL1",
-        (3, 5, 0, 5, 1) => "ERXMISC1_EL1",
-        (3, 5, 0, 5, 2) => "ERXMISC2_EL1",
-        (3, 5, 0, 5, 3) => "ERXMISC3_EL1",
-        (3, 5, 0, 4, 6) => "ERXPFGCDN_EL1",
-        (3, 5, 0, 4, 5) => "ERXPFGCTL_EL1",
-        (3, 5, 0, 4, 4) => "ERXPFGF_EL1",
-        (3, 5, 0, 4, 2) => "ERXSTATUS_EL1",
-        (3, 5, 0, 2, 0) => "ESR_EL1",
-        (3, 5, 5, 2, 0) => "ESR_EL12",
-        (3, 5, 4, 2, 0) => "ESR_EL2",
-        (3, 5, 6, 2, 0) => "ESR_EL3",
-        (3, 6, 0, 0, 0) => "FAR_EL1",
-        (3, 6, 5, 0, 0) => "FAR_EL12",
-        (3, 6, 4, 0, 0) => "FAR_EL2",
-        (3, 6, 6, 0, 0) => "FAR_EL3",
-        (3, 1, 6, 1, 5) => "FGWTE3_EL3",
-        (3, 4, 3, 4, 0) => "FPCR",
-        (3, 5, 4, 3, 0) => "FPEXC32_EL2",
-        (3, 4, 3, 4, 2) => "FPMR",
-        (3, 4, 3, 4, 1) => "FPSR",
-        (3, 1, 0, 0, 6) => "GCR_EL1",
-        (3, 2, 0, 5, 2) => "GCSCRE0_EL1",
-        (3, 2, 0, 5, 0) => "GCSCR_EL1",
-        (3, 2, 5, 5, 0) => "GCSCR_EL12",
-        (3, 2, 4, 5, 0) => "GCSCR_EL2",
-        (3, 2, 6, 5, 0) => "GCSCR_EL3",
-        (3, 2, 3, 5, 1) => "GCSPR_EL0",
-        (3, 2, 0, 5, 1) => "GCSPR_EL1",
-        (3, 2, 5, 5, 1) => "GCSPR_EL12",
-        (3, 2, 4, 5, 1) => "GCSPR_EL2",
-        (3, 2, 6, 5, 1) => "GCSPR_EL3",
-        (3, 0, 1, 0, 4) => "GMID_EL1",
-        (3, 2, 6, 1, 5) => "GPCBW_EL3",
-        (3, 2, 6, 1, 6) => "GPCCR_EL3",
-        (3, 2, 6, 1, 4) => "GPTBR_EL3",
-        (3, 2, 4, 3, 4) => "HACDBSBR_EL2",
-        (3, 2, 4, 3, 5) => "HACDBSCONS_EL2",
-        (3, 1, 4, 1, 7) => "HACR_EL2",
-        (3, 3, 4, 1, 6) => "HAFGRTR_EL2",
-        (3, 1, 4, 2, 2) => "HCRX_EL2",
-        (3, 1, 4, 1, 0) => "HCR_EL2",
-        (3, 2, 4, 3, 2) => "HDBSSBR_EL2",
-        (3, 2, 4, 3, 3) => "HDBSSPROD_EL2",
-        (3, 3, 4, 1, 0) => "HDFGRTR2_EL2",
-        (3, 3, 4, 1, 4) => "HDFGRTR_EL2",
-        (3, 3, 4, 1, 1) => "HDFGWTR2_EL2",
-        (3, 3, 4, 1, 5) => "HDFGWTR_EL2",
-        (3, 3, 4, 1, 7) => "HFGITR2_EL2",
-        (3, 1, 4, 1, 6) => "HFGITR_EL2",
-        (3, 3, 4, 1, 2) => "HFGRTR2_EL2",
-        (3, 1, 4, 1, 4) => "HFGRTR_EL2",
-        (3, 3, 4, 1, 3) => "HFGWTR2_EL2",
-        (3, 1, 4, 1, 5) => "HFGWTR_EL2",
-        (3, 6, 4, 0, 4) => "HPFAR_EL2",
-        (3, 1, 4, 1, 3) => "HSTR_EL2",
-        (3, 12, 0, 8, 3) => "ICC_BPR0_EL1",
-        (3, 12, 0, 12, 3) => "ICC_BPR1_EL1",
-        (3, 12, 0, 12, 4) => "ICC_CTLR_EL1",
-        (3, 12, 6, 12, 4) => "ICC_CTLR_EL3",
-        (3, 12, 0, 8, 2) => "ICC_HPPIR0_EL1",
-        (3, 12, 0, 12, 2) => "ICC_HPPIR1_EL1",
-        (3, 12, 0, 8, 0) => "ICC_IAR0_EL1",
-        (3, 12, 0, 12, 0) => "ICC_IAR1_EL1",
-        (3, 12, 0, 12, 6) => "ICC_IGRPEN0_EL1",
-        (3, 12, 0, 12, 7) => "ICC_IGRPEN1_EL1",
-        (3, 12, 6, 12, 7) => "ICC_IGRPEN1_EL3",
-        (3, 12, 0, 9, 5) => "ICC_NMIAR1_EL1",
-        (3, 4, 0, 6, 0) => "ICC_PMR_EL1",
-        (3, 12, 0, 11, 3) => "ICC_RPR_EL1",
-        (3, 12, 0, 12, 5) => "ICC_SRE_EL1",
-        (3, 12, 4, 9, 5) => "ICC_SRE_EL2",
-        (3, 12, 6, 12, 5) => "ICC_SRE_EL3",
-        (3, 12, 4, 11, 3) => "ICH_EISR_EL2",
-        (3, 12, 4, 11, 5) => "ICH_ELRSR_EL2",
-        (3, 12, 4, 11, 0) => "ICH_HCR_EL2",
-        (3, 12, 4, 11, 2) => "ICH_MISR_EL2",
-        (3, 12, 4, 11, 7) => "ICH_VMCR_EL2",
-        (3, 12, 4, 11, 1) => "ICH_VTR_EL2",
-        (3, 0, 0, 5, 4) => "ID_AA64AFR0_EL1",
-        (3, 0, 0, 5, 5) => "ID_AA64AFR1_EL1",
-        (3, 0, 0, 5, 0) => "ID_AA64DFR0_EL1",
-        (3, 0, 0, 5, 1) => "ID_AA64DFR1_EL1",
-        (3, 0, 0, 5, 2) => "ID_AA64DFR2_EL1",
-        (3, 0, 0, 4, 7) => "ID_AA64FPFR0_EL1",
-        (3, 0, 0, 6, 0) => "ID_AA64ISAR0_EL1",
-        (3, 0, 0, 6, 1) => "ID_AA64ISAR1_EL1",
-        (3, 0, 0, 6, 2) => "ID_AA64ISAR2_EL1",
-        (3, 0, 0, 6, 3) => "ID_AA64ISAR3_EL1",
-        (3, 0, 0, 7, 0) => "ID_AA64MMFR0_EL1",
-        (3, 0, 0, 7, 1) => "ID_AA64MMFR1_EL1",
-        (3, 0, 0, 7, 2) => "ID_AA64MMFR2_EL1",
-        (3, 0, 0, 7, 3) => "ID_AA64MMFR3_EL1",
-        (3, 0, 0, 7, 4) => "ID_AA64MMFR4_EL1",
-        (3, 0, 0, 4, 0) => "ID_AA64PFR0_EL1",
-        (3, 0, 0, 4, 1) => "ID_AA64PFR1_EL1",
-        (3, 0, 0, 4, 2) => "ID_AA64PFR2_EL1",
-        (3, 0, 0, 4, 5) => "ID_AA64SMFR0_EL1",
-        (3, 0, 0, 4, 4) => "ID_AA64ZFR0_EL1",
-        (3, 0, 0, 1, 3) => "ID_AFR0_EL1",
-        (3, 0, 0, 1, 2) => "ID_DFR0_EL1",
-        (3, 0, 0, 3, 5) => "ID_DFR1_EL1",
-        (3, 0, 0, 2, 0) => "ID_ISAR0_EL1",
-        (3, 0, 0, 2, 1) => "ID_ISAR1_EL1",
-        (3, 0, 0, 2, 2) => "ID_ISAR2_EL1",
-        (3, 0, 0, 2, 3) => "ID_ISAR3_EL1",
-        (3, 0, 0, 2, 4) => "ID_ISAR4_EL1",
-        (3, 0, 0, 2, 5) => "ID_ISAR5_EL1",
-        (3, 0, 0, 2, 7) => "ID_ISAR6_EL1",
-        (3, 0, 0, 1, 4) => "ID_MMFR0_EL1",
-        (3, 0, 0, 1, 5) => "ID_MMFR1_EL1",
-        (3, 0, 0, 1, 6) => "ID_MMFR2_EL1",
-        (3, 0, 0, 1, 7) => "ID_MMFR3_EL1",
-        (3, 0, 0, 2, 6) => "ID_MMFR4_EL1",
-        (3, 0, 0, 3, 6) => "ID_MMFR5_EL1",
-        (3, 0, 0, 1, 0) => "ID_PFR0_EL1",
-        (3, 0, 0, 1, 1) => "ID_PFR1_EL1",
-        (3, 0, 0, 3, 4) => "ID_PFR2_EL1",
-        (3, 5, 4, 0, 1) => "IFSR32_EL2",
-        (3, 12, 0, 1, 0) => "ISR_EL1",
-        (3, 10, 0, 4, 3) => "LORC_EL1",
-        (3, 10, 0, 4, 1) => "LOREA_EL1",
-        (3, 10, 0, 4, 7) => "LORID_EL1",
-        (3, 10, 0, 4, 2) => "LORN_EL1",
-        (3, 10, 0, 4, 0) => "LORSA_EL1",
-        (3, 10, 0, 2, 1) => "MAIR2_EL1",
-        (3, 10, 5, 2, 1) => "MAIR2_EL12",
-        (3, 10, 4, 1, 1) => "MAIR2_EL2",
-        (3, 10, 6, 1, 1) => "MAIR2_EL3",
-        (3, 10, 0, 2, 0) => "MAIR_EL1",
-        (3, 10, 5, 2, 0) => "MAIR_EL12",
-        (3, 10, 4, 2, 0) => "MAIR_EL2",
-        (3, 10, 6, 2, 0) => "MAIR_EL3",
-        (2, 0, 0, 2, 0) => "MDCCINT_EL1",
-        (2, 0, 3, 1, 0) => "MDCCSR_EL0",
-        (3, 1, 4, 1, 1) => "MDCR_EL2",
-        (3, 1, 6, 3, 1) => "MDCR_EL3",
-        (2, 1, 0, 0, 0) => "MDRAR_EL1",
-        (2, 0, 0, 2, 2) => "MDSCR_EL1",
-        (2, 0, 0, 4, 2) => "MDSELR_EL1",
-        (2, 0, 0, 5, 2) => "MDSTEPOP_EL1",
-        (3, 10, 4, 8, 7) => "MECIDR_EL2",
-        (3, 10, 4, 8, 1) => "MECID_A0_EL2",
-        (3, 10, 4, 8, 3) => "MECID_A1_EL2",
-        (3, 10, 4, 8, 0) => "MECID_P0_EL2",
-        (3, 10, 4, 8, 2) => "MECID_P1_EL2",
-        (3, 10, 6, 10, 1) => "MECID_RL_A_EL3",
-        (3, 6, 6, 0, 5) => "MFAR_EL3",
-        (3, 0, 0, 0, 0) => "MIDR_EL1",
-        (3, 10, 0, 5, 1) => "MPAM0_EL1",
-        (3, 10, 0, 5, 0) => "MPAM1_EL1",
-        (3, 10, 5, 5, 0) => "MPAM1_EL12",
-        (3, 10, 4, 5, 0) => "MPAM2_EL2",
-        (3, 10, 6, 5, 0) => "MPAM3_EL3",
-        (3, 10, 0, 5, 5) => "MPAMBW0_EL1",
-        (3, 10, 0, 5, 4) => "MPAMBW1_EL1",
-        (3, 10, 5, 5, 4) => "MPAMBW1_EL12",
-        (3, 10, 4, 5, 4) => "MPAMBW2_EL2",
-        (3, 10, 6, 5, 4) => "MPAMBW3_EL3",
-        (3, 10, 4, 5, 6) => "MPAMBWCAP_EL2",
-        (3, 10, 0, 4, 5) => "MPAMBWIDR_EL1",
-        (3, 10, 0, 5, 7) => "MPAMBWSM_EL1",
-        (3, 10, 4, 4, 0) => "MPAMHCR_EL2",
-        (3, 10, 0, 4, 4) => "MPAMIDR_EL1",
-        (3, 10, 0, 5, 3) => "MPAMSM_EL1",
-        (3, 10, 4, 6, 0) => "MPAMVPM0_EL2",
-        (3, 10, 4, 6, 1) => "MPAMVPM1_EL2",
-        (3, 10, 4, 6, 2) => "MPAMVPM2_EL2",
-        (3, 10, 4, 6, 3) => "MPAMVPM3_EL2",
-        (3, 10, 4, 6, 4) => "MPAMVPM4_EL2",
-        (3, 10, 4, 6, 5) => "MPAMVPM5_EL2",
-        (3, 10, 4, 6, 6) => "MPAMVPM6_EL2",
-        (3, 10, 4, 6, 7) => "MPAMVPM7_EL2",
-        (3, 10, 4, 4, 1) => "MPAMVPMV_EL2",
-        (3, 0, 0, 0, 5) => "MPIDR_EL1",
-        (3, 0, 0, 3, 0) => "MVFR0_EL1",
-        (3, 0, 0, 3, 1) => "MVFR1_EL1",
-        (3, 0, 0, 3, 2) => "MVFR2_EL1",
-        (3, 4, 3, 2, 0) => "NZCV",
-        (2, 1, 0, 3, 4) => "OSDLR_EL1",
-        (2, 0, 0, 0, 2) => "OSDTRRX_EL1",
-        (2, 0, 0, 3, 2) => "OSDTRTX_EL1",
-        (2, 0, 0, 6, 2) => "OSECCR_EL1",
-        (2, 1, 0, 1, 4) => "OSLSR_EL1",
-        (3, 4, 0, 2, 3) => "PAN",
-        (3, 7, 0, 4, 0) => "PAR_EL1",
-        (3, 6, 0, 0, 5) => "PFAR_EL1",
-        (3, 6, 5, 0, 5) => "PFAR_EL12",
-        (3, 6, 4, 0, 5) => "PFAR_EL2",
-        (3, 10, 0, 2, 2) => "PIRE0_EL1",
-        (3, 10, 5, 2, 2) => "PIRE0_EL12",
-        (3, 10, 4, 2, 2) => "PIRE0_EL2",
-        (3, 10, 0, 2, 3) => "PIR_EL1",
-        (3, 10, 5, 2, 3) => "PIR_EL12",
-        (3, 10, 4, 2, 3) => "PIR_EL2",
-        (3, 10, 6, 2, 3) => "PIR_EL3",
-        (3, 4, 0, 3, 1) => "PM",
-        (3, 9, 0, 10, 7) => "PMBIDR_EL1",
-        (3, 9, 0, 10, 0) => "PMBLIMITR_EL1",
-        (3, 9, 0, 10, 5) => "PMBMAR_EL1",
-        (3, 9, 0, 10, 1) => "PMBPTR_EL1",
-        (3, 9, 0, 10, 3) => "PMBSR_EL1",
-        (3, 9, 5, 10, 3) => "PMBSR_EL12",
-        (3, 9, 4, 10, 3) => "PMBSR_EL2",
-        (3, 9, 6, 10, 3) => "PMBSR_EL3",
-        (3, 14, 3, 15, 7) => "PMCCFILTR_EL0",
-        (3, 9, 3, 13, 0) => "PMCCNTR_EL0",
-        (2, 14, 0, 11, 7) => "PMCCNTSVR_EL1",
-        (3, 9, 3, 12, 6) => "PMCEID0_EL0",
-        (3, 9, 3, 12, 7) => "PMCEID1_EL0",
-        (3, 9, 3, 12, 2) => "PMCNTENCLR_EL0",
-        (3, 9, 3, 12, 1) => "PMCNTENSET_EL0",
-        (3, 9, 3, 12, 0) => "PMCR_EL0",
-        (3, 9, 0, 14, 5) => "PMECR_EL1",
-        (3, 9, 0, 14, 7) => "PMIAR_EL1",
-        (3, 9, 3, 6, 0) => "PMICFILTR_EL0",
-        (3, 9, 3, 4, 0) => "PMICNTR_EL0",
-        (2, 14, 0, 12, 0) => "PMICNTSVR_EL1",
-        (3, 9, 0, 14, 2) => "PMINTENCLR_EL1",
-        (3, 9, 0, 14, 1) => "PMINTENSET_EL1",
-        (3, 9, 0, 14, 6) => "PMMIR_EL1",
-        (3, 9, 3, 12, 3) => "PMOVSCLR_EL0",
-        (3, 9, 3, 14, 3) => "PMOVSSET_EL0",
-        (3, 9, 0, 9, 0) => "PMSCR_EL1",
-        (3, 9, 5, 9, 0) => "PMSCR_EL12",
-        (3, 9, 4, 9, 0) => "PMSCR_EL2",
-        (3, 9, 0, 10, 4) => "PMSDSFR_EL1",
-        (3, 9, 3, 12, 5) => "PMSELR_EL0",
-        (3, 9, 0, 9, 5) => "PMSEVFR_EL1",
-        (3, 9, 0, 9, 4) => "PMSFCR_EL1",
-        (3, 9, 0, 9, 2) => "PMSICR_EL1",
-        (3, 9, 0, 9, 7) => "PMSIDR_EL1",
-        (3, 9, 0, 9, 3) => "PMSIRR_EL1",
-        (3, 9, 0, 9, 6) => "PMSLATFR_EL1",
-        (3, 9, 0, 9, 1) => "PMSNEVFR_EL1",
-        (3, 9, 0, 13, 3) => "PMSSCR_EL1",
-        (3, 9, 0, 14, 4) => "PMUACR_EL1",
-        (3, 9, 3, 14, 0) => "PMUSERENR_EL0",
-        (3, 9, 3, 13, 2) => "PMXEVCNTR_EL0",
-        (3, 9, 3, 13, 1) => "PMXEVTYPER_EL0",
-        (3, 10, 3, 2, 4) => "POR_EL0",
-        (3, 10, 0, 2, 4) => "POR_EL1",
-        (3, 10, 5, 2, 4) => "POR_EL12",
-        (3, 10, 4, 2, 4) => "POR_EL2",
-        (3, 10, 6, 2, 4) => "POR_EL3",
-        (3, 13, 0, 0, 6) => "RCWMASK_EL1",
-        (3, 13, 0, 0, 3) => "RCWSMASK_EL1",
-        (3, 0, 0, 0, 6) => "REVIDR_EL1",
-        (3, 1, 0, 0, 5) => "RGSR_EL1",
-        (3, 12, 0, 0, 2) => "RMR_EL1",
-        (3, 12, 4, 0, 2) => "RMR_EL2",
-        (3, 12, 6, 0, 2) => "RMR_EL3",
-        (3, 2, 3, 4, 0) => "RNDR",
-        (3, 2, 3, 4, 1) => "RNDRRS",
-        (3, 12, 0, 0, 1) => "RVBAR_EL1",
-        (3, 12, 4, 0, 1) => "RVBAR_EL2",
-        (3, 12, 6, 0, 1) => "RVBAR_EL3",
-        (3, 10, 4, 2, 5) => "S2PIR_EL2",
-        (3, 10, 0, 2, 5) => "S2POR_EL1",
-        (3, 1, 6, 1, 0) => "SCR_EL3",
-        (3, 1, 0, 4, 7) => "SCTLR2ALIAS_EL1",
-        (3, 1, 0, 4, 3) => "SCTLR2MASK_EL1",
-        (3, 1, 5, 4, 3) => "SCTLR2MASK_EL12",
-        (3, 1, 4, 4, 3) => "SCTLR2MASK_EL2",
-        (3, 1, 0, 0, 3) => "SCTLR2_EL1",
-        (3, 1, 5, 0, 3) => "SCTLR2_EL12",
-        (3, 1, 4, 0, 3) => "SCTLR2_EL2",
-        (3, 1, 6, 0, 3) => "SCTLR2_EL3",
-        (3, 1, 0, 4, 6) => "SCTLRALIAS_EL1",
-        (3, 1, 0, 4, 0) => "SCTLRMASK_EL1",
-        (3, 1, 5, 4, 0) => "SCTLRMASK_EL12",
-        (3, 1, 4, 4, 0) => "SCTLRMASK_EL2",
-        (3, 1, 0, 0, 0) => "SCTLR_EL1",
-        (3, 1, 5, 0, 0) => "SCTLR_EL12",
-        (3, 1, 4, 0, 0) => "SCTLR_EL2",
-        (3, 1, 6, 0, 0) => "SCTLR_EL3",
-        (3, 13, 3, 0, 7) => "SCXTNUM_EL0",
-        (3, 13, 0, 0, 7) => "SCXTNUM_EL1",
-        (3, 13, 5, 0, 7) => "SCXTNUM_EL12",
-        (3, 13, 4, 0, 7) => "SCXTNUM_EL2",
-        (3, 13, 6, 0, 7) => "SCXTNUM_EL3",
-        (3, 1, 4, 3, 1) => "SDER32_EL2",
-        (3, 1, 6, 1, 1) => "SDER32_EL3",
-        (3, 1, 0, 2, 6) => "SMCR_EL1",
-        (3, 1, 5, 2, 6) => "SMCR_EL12",
-        (3, 1, 4, 2, 6) => "SMCR_EL2",
-        (3, 1, 6, 2, 6) => "SMCR_EL3",
-        (3, 0, 1, 0, 6) => "SMIDR_EL1",
-        (3, 1, 4, 2, 5) => "SMPRIMAP_EL2",
-        (3, 1, 0, 2, 4) => "SMPRI_EL1",
-        (2, 9, 0, 13, 3) => "SPMACCESSR_EL1",
-        (2, 9, 5, 13, 3) => "SPMACCESSR_EL12",
-        (2, 9, 4, 13, 3) => "SPMACCESSR_EL2",
-        (2, 9, 6, 13, 3) => "SPMACCESSR_EL3",
-        (2, 9, 0, 13, 7) => "SPMCFGR_EL1",
-        (2, 9, 3, 12, 2) => "SPMCNTENCLR_EL0",
-        (2, 9, 3, 12, 1) => "SPMCNTENSET_EL0",
-        (2, 9, 3, 12, 0) => "SPMCR_EL0",
-        (2, 9, 0, 13, 6) => "SPMDEVAFF_EL1",
-        (2, 9, 0, 13, 5) => "SPMDEVARCH_EL1",
-        (2, 9, 0, 13, 4) => "SPMIIDR_EL1",
-        (2, 9, 0, 14, 2) => "SPMINTENCLR_EL1",
-        (2, 9, 0, 14, 1) => "SPMINTENSET_EL1",
-        (2, 9, 3, 12, 3) => "SPMOVSCLR_EL0",
-        (2, 9, 3, 14, 3) => "SPMOVSSET_EL0",
-        (2, 9, 6, 14, 7) => "SPMROOTCR_EL3",
-        (2, 9, 7, 14, 7) => "SPMSCR_EL1",
-        (2, 9, 3, 12, 5) => "SPMSELR_EL0",
-        (3, 4, 0, 0, 0) => "SPSR_EL1",
-        (3, 4, 5, 0, 0) => "SPSR_EL12",
-        (3, 4, 4, 0, 0) => "SPSR_EL2",
-        (3, 4, 6, 0, 0) => "SPSR_EL3",
-        (3, 4, 4, 3, 1) => "SPSR_abt",
-        (3, 4, 4, 3, 3) => "SPSR_fiq",
-        (3, 4, 4, 3, 0) => "SPSR_irq",
-        (3, 4, 4, 3, 2) => "SPSR_und",
-        (3, 4, 0, 2, 0) => "SPSel",
-        (3, 4, 0, 1, 0) => "SP_EL0",
-        (3, 4, 4, 1, 0) => "SP_EL1",
-        (3, 4, 6, 1, 0) => "SP_EL2",
-        (3, 4, 3, 2, 6) => "SSBS",
-        (3, 4, 3, 2, 2) => "SVCR",
-        (3, 4, 3, 2, 7) => "TCO",
-        (3, 2, 0, 7, 7) => "TCR2ALIAS_EL1",
-        (3, 2, 0, 7, 3) => "TCR2MASK_EL1",
-        (3, 2, 5, 7, 3) => "TCR2MASK_EL12",
-        (3, 2, 4, 7, 3) => "TCR2MASK_EL2",
-        (3, 2, 0, 0, 3) => "TCR2_EL1",
-        (3, 2, 5, 0, 3) => "TCR2_EL12",
-        (3, 2, 4, 0, 3) => "TCR2_EL2",
-        (3, 2, 0, 7, 6) => "TCRALIAS_EL1",
-        (3, 2, 0, 7, 2) => "TCRMASK_EL1",
-        (3, 2, 5, 7, 2) => "TCRMASK_EL12",
-        (3, 2, 4, 7, 2) => "TCRMASK_EL2",
-        (3, 2, 0, 0, 2) => "TCR_EL1",
-        (3, 2, 5, 0, 2) => "TCR_EL12",
-        (3, 2, 4, 0, 2) => "TCR_EL2",
-        (3, 2, 6, 0, 2) => "TCR_EL3",
-        (3, 5, 0, 6, 1) => "TFSRE0_EL1",
-        (3, 5, 0, 6, 0) => "TFSR_EL1",
-        (3, 5, 5, 6, 0) => "TFSR_EL12",
-        (3, 5, 4, 6, 0) => "TFSR_EL2",
-        (3, 5, 6, 6, 0) => "TFSR_EL3",
-        (3, 13, 3, 0, 5) => "TPIDR2_EL0",
-        (3, 13, 3, 0, 3) => "TPIDRRO_EL0",
-        (3, 13, 3, 0, 2) => "TPIDR_EL0",
-        (3, 13, 0, 0, 4) => "TPIDR_EL1",
-        (3, 13, 4, 0, 2) => "TPIDR_EL2",
-        (3, 13, 6, 0, 2) => "TPIDR_EL3",
-        (3, 9, 0, 11, 2) => "TRBBASER_EL1",
-        (3, 9, 0, 11, 7) => "TRBIDR_EL1",
-        (3, 9, 0, 11, 0) => "TRBLIMITR_EL1",
-        (3, 9, 0, 11, 4) => "TRBMAR_EL1",
-        (3, 9, 0, 11, 5) => "TRBMPAM_EL1",
-        (3, 9, 0, 11, 1) => "TRBPTR_EL1",
-        (3, 9, 0, 11, 3) => "TRBSR_EL1",
-        (3, 9, 5, 11, 3) => "TRBSR_EL12",
-        (3, 9, 4, 11, 3) => "TRBSR_EL2",
-        (3, 9, 6, 11, 3) => "TRBSR_EL3",
-        (3, 9, 0, 11, 6) => "TRBTRG_EL1",
-        (2, 7, 1, 14, 6) => "TRCAUTHSTATUS",
-        (2, 0, 1, 6, 0) => "TRCAUXCTLR",
-        (2, 0, 1, 15, 0) => "TRCBBCTLR",
-        (2, 0, 1, 14, 0) => "TRCCCCTLR",
-        (2, 3, 1, 0, 2) => "TRCCIDCCTLR0",
-        (2, 3, 1, 1, 2) => "TRCCIDCCTLR1",
-        (2, 7, 1, 9, 6) => "TRCCLAIMCLR",
-        (2, 7, 1, 8, 6) => "TRCCLAIMSET",
-        (2, 0, 1, 4, 0) => "TRCCONFIGR",
-        (2, 7, 1, 15, 6) => "TRCDEVARCH",
-        (2, 7, 1, 2, 7) => "TRCDEVID",
-        (2, 0, 1, 8, 0) => "TRCEVENTCTL0R",
-        (2, 0, 1, 9, 0) => "TRCEVENTCTL1R",
-        (2, 0, 1, 8, 7) => "TRCIDR0",
-        (2, 0, 1, 9, 7) => "TRCIDR1",
-        (2, 0, 1, 2, 6) => "TRCIDR10",
-        (2, 0, 1, 3, 6) => "TRCIDR11",
-        (2, 0, 1, 4, 6) => "TRCIDR12",
-        (2, 0, 1, 5, 6) => "TRCIDR13",
-        (2, 0, 1, 10, 7) => "TRCIDR2",
-        (2, 0, 1, 11, 7) => "TRCIDR3",
-        (2, 0, 1, 12, 7) => "TRCIDR4",
-        (2, 0, 1, 13, 7) => "TRCIDR5",
-        (2, 0, 1, 14, 7) => "TRCIDR6",
-        (2, 0, 1, 15, 7) => "TRCIDR7",
-        (2, 0, 1, 0, 6) => "TRCIDR8",
-        (2, 0, 1, 1, 6) => "TRCIDR9",
-        (2, 0, 1, 0, 7) => "TRCIMSPEC0",
-        (3, 1, 0, 2, 3) => "TRCITECR_EL1",
-        (3, 1, 5, 2, 3) => "TRCITECR_EL12",
-        (3, 1, 4, 2, 3) => "TRCITECR_EL2",
-        (2, 0, 1, 2, 1) => "TRCITEEDCR",
-        (2, 1, 1, 1, 4) => "TRCOSLSR",
-        (2, 0, 1, 1, 0) => "TRCPRGCTLR",
-        (2, 0, 1, 1, 1) => "TRCQCTLR",
-        (2, 0, 1, 10, 0) => "TRCRSR",
-        (2, 0, 1, 6, 4) => "TRCSEQRSTEVR",
-        (2, 0, 1, 7, 4) => "TRCSEQSTR",
-        (2, 0, 1, 11, 0) => "TRCSTALLCTLR",
-        (2, 0, 1, 3, 0) => "TRCSTATR",
-        (2, 0, 1, 13, 0) => "TRCSYNCPR",
-        (2, 0, 1, 0, 1) => "TRCTRACEIDR",
-        (2, 0, 1, 12, 0) => "TRCTSCTLR",
-        (2, 0, 1, 0, 2) => "TRCVICTLR",
-        (2, 0, 1, 1, 2) => "TRCVIIECTLR",
-        (2, 0, 1, 3, 2) => "TRCVIPCSSCTLR",
-        (2, 0, 1, 2, 2) => "TRCVISSCTLR",
-        (2, 3, 1, 2, 2) => "TRCVMIDCCTLR0",
-        (2, 3, 1, 3, 2) => "TRCVMIDCCTLR1",
-        (3, 1, 0, 2, 1) => "TRFCR_EL1",
-        (3, 1, 5, 2, 1) => "TRFCR_EL12",
-        (3, 1, 4, 2, 1) => "TRFCR_EL2",
-        (3, 2, 0, 0, 0) => "TTBR0_EL1",
-        (3, 2, 5, 0, 0) => "TTBR0_EL12",
-        (3, 2, 4, 0, 0) => "TTBR0_EL2",
-        (3, 2, 6, 0, 0) => "TTBR0_EL3",
-        (3, 2, 0, 0, 1) => "TTBR1_EL1",
-        (3, 2, 5, 0, 1) => "TTBR1_EL12",
-        (3, 2, 4, 0, 1) => "TTBR1_EL2",
-        (3, 4, 0, 2, 4) => "UAO",
-        (3, 12, 0, 0, 0) => "VBAR_EL1",
-        (3, 12, 5, 0, 0) => "VBAR_EL12",
-        (3, 12, 4, 0, 0) => "VBAR_EL2",
-        (3, 12, 6, 0, 0) => "VBAR_EL3",
-        (3, 12, 4, 1, 1) => "VDISR_EL2",
-        (3, 12, 6, 1, 1) => "VDISR_EL3",
-        (3, 10, 4, 9, 1) => "VMECID_A_EL2",
-        (3, 10, 4, 9, 0) => "VMECID_P_EL2",
-        (3, 0, 4, 0, 5) => "VMPIDR_EL2",
-        (3, 2, 4, 2, 0) => "VNCR_EL2",
-        (3, 0, 4, 0, 0) => "VPIDR_EL2",
-        (3, 5, 4, 2, 3) => "VSESR_EL2",
-        (3, 5, 6, 2, 3) => "VSESR_EL3",
-        (3, 2, 4, 6, 2) => "VSTCR_EL2",
-        (3, 2, 4, 6, 0) => "VSTTBR_EL2",
-        (3, 2, 4, 1, 2) => "VTCR_EL2",
-        (3, 2, 4, 1, 0) => "VTTBR_EL2",
-        (3, 1, 0, 2, 0) => "ZCR_EL1",
-        (3, 1, 5, 2, 0) => "ZCR_EL12",
-        (3, 1, 4, 2, 0) => "ZCR_EL2",
-        (3, 1, 6, 2, 0) => "ZCR_EL3",
-        _ => "unknown",
-    }
-}
@@ -0,0 +1,107 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::IssField;
+use super::sysreg_names::{sysp_instruction_name, sysreg128_name};
+use crate::{FieldList, StaticFieldInfo};
+use bit_field::BitField;
+use core::fmt::{self, Formatter};
+
+/// The non-reserved ISS fields which may be present for an MSRR, MRRS or SYSP instruction.
+pub const MSRR_FIELDS: &[IssField] = &[
+    ("Op0", 20..22),
+    ("Op2", 17..20),
+    ("Op1", 14..17),
+    ("CRn", 10..14),
+    ("Rt", 6..10),
+    ("CRm", 1..5),
+    ("Direction", 0..1),
+];
+
+/// Decodes the ISS value for an MSRR, MRRS or SYSP instruction.
+pub fn decode_iss_msrr(iss: u64) -> FieldList {
+    let res0a = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 22, 25).check_res0();
+    let op0 = StaticFieldInfo::get(iss, "Op0", None, 20, 22);
+    let op2 = StaticFieldInfo::get(iss, "Op2", None, 17, 20);
+    let op1 = StaticFieldInfo::get(iss, "Op1", None, 14, 17);
+    let crn = StaticFieldInfo::get(iss, "CRn", None, 10, 14);
+    let rt = StaticFieldInfo::get(
+        iss,
+        "Rt",
+        Some("Bits [4:1] of the first general-purpose register of the pair"),
+        6,
+        10,
+    );
+    let res0b = StaticFieldInfo::get_bit(iss, "RES0", Some("Reserved"), 5).check_res0();
+    let crm = StaticFieldInfo::get(iss, "CRm", None, 1, 5);
+    let direction = StaticFieldInfo::get_bit(
+        iss,
+        "Direction",
+        Some("Direction of the trapped instruction"),
+        0,
+    )
+    .describe_bit(if op0.value == 1 {
+        describe_sysp_direction
+    } else {
+        describe_direction
+    });
+
+    FieldList::from([res0a, op0, op2, op1, crn, rt, res0b, crm, direction])
+}
+
+/// Writes the trapped instruction for the given ISS value.
+///
+/// Op0 1 is a System instruction with a pair of registers, such as `TLBIP VAE1IS`, so is written as
+/// its name or as `SYSP` if it is unknown.
+pub fn fmt_iss_msrr(f: &mut Formatter, iss: u64) -> fmt::Result {
+    let op0 = iss.get_bits(20..22);
+    let op2 = iss.get_bits(17..20);
+    let op1 = iss.get_bits(14..17);
+    let crn = iss.get_bits(10..14);
+    let crm = iss.get_bits(1..5);
+    let read = iss.get_bit(0);
+    let rt_low = iss.get_bits(6..10) << 1;
+    let rt_high = rt_low + 1;
+    if op0 == 1 && !read {
+        return match sysp_instruction_name(op1, op2, crn, crm) {
+            Some(name) => write!(f, "{name}, x{rt_low}, x{rt_high}"),
+            None => write!(
+                f,
+                "SYSP #{op1}, C{crn}, C{crm}, #{op2}, x{rt_low}, x{rt_high}"
+            ),
+        };
+    }
+    let name = sysreg128_name(op0, op1, op2, crn, crm, read).unwrap_or("unknown");
+    if read {
+        write!(f, "MRRS x{rt_low}, x{rt_high}, {name}")
+    } else {
+        write!(f, "MSRR {name}, x{rt_low}, x{rt_high}")
+    }
+}
+
+fn describe_direction(direction: bool) -> &'static str {
+    if direction {
+        "Read from 128-bit system register (MRRS)"
+    } else {
+        "Write to 128-bit system register (MSRR)"
+    }
+}
+
+fn describe_sysp_direction(direction: bool) -> &'static str {
+    if direction {
+        "Read from 128-bit system register (MRRS)"
+    } else {
+        "System instruction with a register pair (SYSP)"
+    }
+}
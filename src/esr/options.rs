@@ -0,0 +1,227 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{ExceptionClass, Features};
+use crate::DecodeError;
+use core::fmt::{self, Display, Formatter};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// An Exception level.
+#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum ExceptionLevel {
+    /// EL0, for applications.
+    El0,
+    /// EL1, for an OS kernel.
+    El1,
+    /// EL2, for a hypervisor.
+    El2,
+    /// EL3, for secure monitor firmware.
+    El3,
+}
+
+impl Display for ExceptionLevel {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let s = match self {
+            Self::El0 => "EL0",
+            Self::El1 => "EL1",
+            Self::El2 => "EL2",
+            Self::El3 => "EL3",
+        };
+        f.write_str(s)
+    }
+}
+
+/// An Exception Syndrome Register which a value may be read from.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum EsrRegister {
+    /// ESR_EL1, which is also accessible from EL2 as ESR_EL12 when HCR_EL2.E2H is set.
+    EsrEl1,
+    /// ESR_EL2, which is also accessible from EL2 as ESR_EL1 when HCR_EL2.E2H is set.
+    EsrEl2,
+    /// ESR_EL3.
+    EsrEl3,
+}
+
+impl EsrRegister {
+    /// Returns the Exception level which exceptions reported in this register are taken to.
+    pub fn target_el(self) -> ExceptionLevel {
+        match self {
+            Self::EsrEl1 => ExceptionLevel::El1,
+            Self::EsrEl2 => ExceptionLevel::El2,
+            Self::EsrEl3 => ExceptionLevel::El3,
+        }
+    }
+}
+
+/// Returns a static description of an exception of the given kind with the given `Route`.
+macro_rules! describe_route {
+    ($kind:literal, $route:expr) => {
+        match $route {
+            Route::El0ToEl1 => concat!($kind, " from EL0, taken to EL1"),
+            Route::El1ToEl1 => concat!($kind, " from EL1, taken to EL1"),
+            Route::El0ToEl2 => concat!($kind, " from EL0, taken to EL2"),
+            Route::El0HostToEl2 => concat!($kind, " from EL0 in the EL2&0 regime, taken to EL2"),
+            Route::El0OrEl1ToEl2 => concat!($kind, " from EL0 or EL1, taken to EL2"),
+            Route::El2ToEl2 => concat!($kind, " from EL2, taken to EL2"),
+            Route::LowerToEl3 => concat!($kind, " from EL0, EL1 or EL2, taken to EL3"),
+            Route::El3ToEl3 => concat!($kind, " from EL3, taken to EL3"),
+        }
+    };
+}
+
+/// Context about where an ESR value came from, to allow it to be decoded more precisely.
+///
+/// The default is to assume nothing.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct DecodeOptions {
+    /// The register which the value was read from, if known.
+    pub register: Option<EsrRegister>,
+    /// The value of HCR_EL2.E2H when the exception was taken, if known.
+    pub e2h: Option<bool>,
+    /// The value of HCR_EL2.TGE when the exception was taken, if known.
+    pub tge: Option<bool>,
+    /// The architecture features implemented by the CPU. By default all are assumed.
+    pub features: Features,
+    /// Whether to return all the fields that can be decoded even if some are invalid, rather than
+    /// an error. Problems are reported as diagnostics on the affected fields instead.
+    pub lenient: bool,
+}
+
+impl DecodeOptions {
+    /// Returns options for a value read from the given register.
+    pub fn for_register(register: EsrRegister) -> Self {
+        Self {
+            register: Some(register),
+            ..Default::default()
+        }
+    }
+
+    /// Returns an error if an exception with the given class can't be taken to the Exception level
+    /// of the register.
+    pub(super) fn check(&self, class: ExceptionClass) -> Result<(), DecodeError> {
+        let Some(register) = self.register else {
+            return Ok(());
+        };
+        let el = register.target_el();
+        if el == ExceptionLevel::El1 && self.tge == Some(true) {
+            return Err(DecodeError::El1WithTge);
+        }
+        let possible = match class {
+            // HVC can't be executed at EL0, and is taken to EL2 from EL1 or to EL3 from EL3.
+            ExceptionClass::Hvc32 | ExceptionClass::Hvc64 => el != ExceptionLevel::El1,
+            // SMC is either trapped to EL2 or taken to EL3.
+            ExceptionClass::Smc32 | ExceptionClass::Smc64 => el != ExceptionLevel::El1,
+            // Pointer authentication instructions are only trapped by HCR_EL2.API and SCR_EL3.API.
+            ExceptionClass::PacTrap => el != ExceptionLevel::El1,
+            // These are only trapped by EL2 controls.
+            ExceptionClass::Vmrs | ExceptionClass::Eret => el == ExceptionLevel::El2,
+            // Vector catch is only for an AArch32 EL1, so must be taken to EL2.
+            ExceptionClass::VectorCatch32 => el == ExceptionLevel::El2,
+            ExceptionClass::GranuleProtectionCheck | ExceptionClass::ImpDefEl3 => {
+                el == ExceptionLevel::El3
+            }
+            // Debug exceptions other than BRK are never taken to EL3.
+            ExceptionClass::BreakpointLowerEl
+            | ExceptionClass::BreakpointSameEl
+            | ExceptionClass::SoftwareStepLowerEl
+            | ExceptionClass::SoftwareStepSameEl
+            | ExceptionClass::WatchpointLowerEl
+            | ExceptionClass::WatchpointSameEl => el != ExceptionLevel::El3,
+            _ => true,
+        };
+        if possible {
+            Ok(())
+        } else {
+            Err(DecodeError::ImpossibleEc {
+                ec: class as u64,
+                el,
+            })
+        }
+    }
+
+    /// Returns a description of the given exception class, worded for the source and target
+    /// Exception levels where they are known.
+    pub(super) fn describe_class(&self, class: ExceptionClass) -> &'static str {
+        let Some(route) = self.route(class) else {
+            return class.description();
+        };
+        match class {
+            ExceptionClass::InstructionAbortLowerEl | ExceptionClass::InstructionAbortSameEl => {
+                describe_route!("Instruction Abort", route)
+            }
+            ExceptionClass::DataAbortLowerEl | ExceptionClass::DataAbortSameEl => {
+                describe_route!("Data Abort", route)
+            }
+            ExceptionClass::BreakpointLowerEl | ExceptionClass::BreakpointSameEl => {
+                describe_route!("Breakpoint exception", route)
+            }
+            ExceptionClass::SoftwareStepLowerEl | ExceptionClass::SoftwareStepSameEl => {
+                describe_route!("Software Step exception", route)
+            }
+            ExceptionClass::WatchpointLowerEl | ExceptionClass::WatchpointSameEl => {
+                describe_route!("Watchpoint exception", route)
+            }
+            _ => class.description(),
+        }
+    }
+
+    /// Returns the route of an exception with the given class, if it distinguishes between lower
+    /// and same Exception levels and the register is known.
+    fn route(&self, class: ExceptionClass) -> Option<Route> {
+        let lower = match class {
+            ExceptionClass::InstructionAbortLowerEl
+            | ExceptionClass::DataAbortLowerEl
+            | ExceptionClass::BreakpointLowerEl
+            | ExceptionClass::SoftwareStepLowerEl
+            | ExceptionClass::WatchpointLowerEl => true,
+            ExceptionClass::InstructionAbortSameEl
+            | ExceptionClass::DataAbortSameEl
+            | ExceptionClass::BreakpointSameEl
+            | ExceptionClass::SoftwareStepSameEl
+            | ExceptionClass::WatchpointSameEl => false,
+            _ => return None,
+        };
+        Some(match (self.register?.target_el(), lower) {
+            (ExceptionLevel::El1, true) => Route::El0ToEl1,
+            (ExceptionLevel::El1, false) => Route::El1ToEl1,
+            // When HCR_EL2.TGE is set EL1 can't be entered, so exceptions from a lower Exception
+            // level must be from EL0. If HCR_EL2.E2H is also set then EL0 is running under a host
+            // OS at EL2.
+            (ExceptionLevel::El2, true) => match (self.tge, self.e2h) {
+                (Some(true), Some(true)) => Route::El0HostToEl2,
+                (Some(true), _) => Route::El0ToEl2,
+                _ => Route::El0OrEl1ToEl2,
+            },
+            (ExceptionLevel::El2, false) => Route::El2ToEl2,
+            (ExceptionLevel::El3, true) => Route::LowerToEl3,
+            (ExceptionLevel::El3, false) => Route::El3ToEl3,
+            (ExceptionLevel::El0, _) => unreachable!(),
+        })
+    }
+}
+
+/// The source and target Exception levels of an exception.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Route {
+    El0ToEl1,
+    El1ToEl1,
+    El0ToEl2,
+    El0HostToEl2,
+    El0OrEl1ToEl2,
+    El2ToEl2,
+    LowerToEl3,
+    El3ToEl3,
+}
@@ -12,18 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{DecodeError, FieldInfo};
+use super::IssField;
+use crate::{FieldList, StaticFieldInfo};
+
+/// The non-reserved ISS fields which may be present for a Pointer Authentication failure.
+pub const PAUTH_FIELDS: &[IssField] = &[("IorD", 1..2), ("AorB", 0..1)];
 
 /// Decodes the ISS value for a Pointer Authentication failure.
-pub fn decode_iss_pauth(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let res0 = FieldInfo::get(iss, "RES0", Some("Reserved"), 2, 25).check_res0()?;
+pub fn decode_iss_pauth(iss: u64) -> FieldList {
+    let res0 = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 2, 25).check_res0();
     let instruction_or_data =
-        FieldInfo::get_bit(iss, "IorD", Some("Instruction key or Data key"), 1)
+        StaticFieldInfo::get_bit(iss, "IorD", Some("Instruction key or Data key"), 1)
             .describe_bit(describe_instruction_or_data);
-    let a_or_b =
-        FieldInfo::get_bit(iss, "AorB", Some("A key or B key"), 0).describe_bit(describe_a_or_b);
+    let a_or_b = StaticFieldInfo::get_bit(iss, "AorB", Some("A key or B key"), 0)
+        .describe_bit(describe_a_or_b);
 
-    Ok(vec![res0, instruction_or_data, a_or_b])
+    FieldList::from([res0, instruction_or_data, a_or_b])
 }
 
 fn describe_instruction_or_data(instruction_or_data: bool) -> &'static str {
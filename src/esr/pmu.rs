@@ -0,0 +1,36 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::IssField;
+use crate::{FieldList, StaticFieldInfo};
+
+/// The non-reserved ISS fields which may be present for a PMU exception.
+pub const PMU_FIELDS: &[IssField] = &[("SYNC", 0..1)];
+
+/// Decodes the ISS value for a PMU exception.
+pub fn decode_iss_pmu(iss: u64) -> FieldList {
+    let res0 = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 1, 25).check_res0();
+    let sync = StaticFieldInfo::get_bit(iss, "SYNC", Some("Synchronous PMU exception"), 0)
+        .describe_bit(describe_sync);
+
+    FieldList::from([res0, sync])
+}
+
+fn describe_sync(sync: bool) -> &'static str {
+    if sync {
+        "The PMU exception was taken synchronously"
+    } else {
+        "The PMU exception was taken asynchronously"
+    }
+}
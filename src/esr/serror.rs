@@ -12,22 +12,33 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{DecodeError, FieldInfo};
+use super::IssField;
+use crate::{DecodeError, FieldList, StaticFieldInfo};
+
+/// The non-reserved ISS fields which may be present for an SError interrupt.
+pub const SERROR_FIELDS: &[IssField] = &[
+    ("IDS", 24..25),
+    ("IMPDEF", 0..24),
+    ("IESB", 13..14),
+    ("AET", 10..13),
+    ("EA", 9..10),
+    ("DFSC", 0..6),
+];
 
 /// Decodes the ISS value for an SError interrupt.
-pub fn decode_iss_serror(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let ids = FieldInfo::get_bit(iss, "IDS", Some("Implementation Defined Syndrome"), 24)
+pub fn decode_iss_serror(iss: u64) -> FieldList {
+    let ids = StaticFieldInfo::get_bit(iss, "IDS", Some("Implementation Defined Syndrome"), 24)
         .describe_bit(describe_ids);
     let platform_fields = if ids.as_bit() {
-        let impdef = FieldInfo::get(iss, "IMPDEF", Some("Implementation defined"), 0, 24);
-        vec![impdef]
+        let impdef = StaticFieldInfo::get(iss, "IMPDEF", Some("Implementation defined"), 0, 24);
+        FieldList::from([impdef])
     } else {
-        let dfsc = FieldInfo::get(iss, "DFSC", Some("Data Fault Status Code"), 0, 6)
-            .describe(describe_dfsc)?;
+        let dfsc = StaticFieldInfo::get(iss, "DFSC", Some("Data Fault Status Code"), 0, 6)
+            .describe(describe_dfsc);
 
-        let res0a = FieldInfo::get(iss, "RES0", Some("Reserved"), 14, 24).check_res0()?;
+        let res0a = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 14, 24).check_res0();
         let iesb = if dfsc.value == 0b010001 {
-            FieldInfo::get_bit(
+            StaticFieldInfo::get_bit(
                 iss,
                 "IESB",
                 Some("Implicit Error Synchronisation event"),
@@ -35,19 +46,19 @@ pub fn decode_iss_serror(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
             )
             .describe_bit(describe_iesb)
         } else {
-            FieldInfo::get_bit(iss, "RES0", Some("Reserved for this DFSC value"), 13)
-                .check_res0()?
+            StaticFieldInfo::get_bit(iss, "RES0", Some("Reserved for this DFSC value"), 13)
+                .check_res0()
         };
-        let aet = FieldInfo::get(iss, "AET", Some("Asynchronous Error Type"), 10, 13)
-            .describe(describe_aet)?;
-        let ea = FieldInfo::get_bit(iss, "EA", Some("External Abort type"), 9);
-        let res0b = FieldInfo::get(iss, "RES0", Some("Reserved"), 6, 9).check_res0()?;
-        vec![res0a, iesb, aet, ea, res0b, dfsc]
+        let aet = StaticFieldInfo::get(iss, "AET", Some("Asynchronous Error Type"), 10, 13)
+            .describe(describe_aet);
+        let ea = StaticFieldInfo::get_bit(iss, "EA", Some("External Abort type"), 9);
+        let res0b = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 6, 9).check_res0();
+        FieldList::from([res0a, iesb, aet, ea, res0b, dfsc])
     };
 
-    let mut fields = vec![ids];
+    let mut fields = FieldList::from([ids]);
     fields.extend(platform_fields);
-    Ok(fields)
+    fields
 }
 
 fn describe_ids(ids: bool) -> &'static str {
@@ -0,0 +1,46 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::IssField;
+use crate::{DecodeError, FieldList, StaticFieldInfo};
+
+/// The non-reserved ISS fields which may be present for a trapped SME instruction or access to
+/// SME state.
+pub const SME_FIELDS: &[IssField] = &[("SMTC", 0..3)];
+
+/// Decodes the ISS value for a trapped SME instruction or access to SME state.
+pub fn decode_iss_sme(iss: u64) -> FieldList {
+    let res0 = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 3, 25).check_res0();
+    let smtc =
+        StaticFieldInfo::get(iss, "SMTC", Some("SME Trap Code"), 0, 3).describe(describe_smtc);
+
+    FieldList::from([res0, smtc])
+}
+
+fn describe_smtc(smtc: u64) -> Result<&'static str, DecodeError> {
+    Ok(match smtc {
+        0b000 => {
+            "Access to SME functionality trapped as a result of CPACR_EL1.SMEN, CPTR_EL2.SMEN, \
+             CPTR_EL2.TSM or CPTR_EL3.ESM (SME disabled)"
+        }
+        0b001 => {
+            "Advanced SIMD, SVE or SVE2 instruction trapped because PSTATE.SM is 1 (illegal in \
+             Streaming SVE mode)"
+        }
+        0b010 => "SME instruction trapped because PSTATE.SM is 0 (Streaming SVE mode not enabled)",
+        0b011 => "SME instruction trapped because PSTATE.ZA is 0 (ZA storage disabled)",
+        0b100 => "SME2 lookup table (ZT0) access trapped because SMCR_ELx.EZT0 is 0 (ZT0 disabled)",
+        _ => return Err(DecodeError::InvalidSmtc { smtc }),
+    })
+}
@@ -12,21 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::common::describe_cv;
-use crate::{DecodeError, FieldInfo};
+use super::IssField;
+use super::common::{describe_cond, describe_cv};
+use crate::{FieldList, StaticFieldInfo};
+
+/// The non-reserved ISS fields which may be present for a trapped SVE, Advanced SIMD or FP
+/// instruction.
+pub const SVE_FIELDS: &[IssField] = &[("CV", 24..25), ("COND", 20..24)];
 
 /// Decodes the ISS value for a trapped SVE, Advanced SIMD or FP instruction.
-pub fn decode_iss_sve(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let cv =
-        FieldInfo::get_bit(iss, "CV", Some("Condition code valid"), 24).describe_bit(describe_cv);
-    let cond = FieldInfo::get(
+pub fn decode_iss_sve(iss: u64) -> FieldList {
+    let cv = StaticFieldInfo::get_bit(iss, "CV", Some("Condition code valid"), 24)
+        .describe_bit(describe_cv);
+    let cond = StaticFieldInfo::get(
         iss,
         "COND",
         Some("Condition code of the trapped instruction"),
         20,
         24,
-    );
-    let res0 = FieldInfo::get(iss, "RES0", Some("Reserved"), 0, 20).check_res0()?;
+    )
+    .describe(describe_cond);
+    let res0 = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 0, 20).check_res0();
 
-    Ok(vec![cv, cond, res0])
+    FieldList::from([cv, cond, res0])
 }
@@ -0,0 +1,1413 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Generated by the `generate_decoder` example of `arm-sysregs-xml`. Don't edit by hand.
+
+/// The release of the Arm system register XML which the names were generated from.
+pub const SYSREG_NAMES_RELEASE: &str = "SysReg_xml_A_profile-2025-06";
+
+/// Returns the name of the AArch64 system register with the given encoding which is read if `read`
+/// is true or written otherwise, if there is one.
+pub fn sysreg_name(
+    op0: u64,
+    op1: u64,
+    op2: u64,
+    crn: u64,
+    crm: u64,
+    read: bool,
+) -> Option<&'static str> {
+    Some(match (op0, crn, op1, crm, op2, read) {
+        (3, 13, 0, 0, 5, _) => "ACCDATA_EL1",
+        (3, 1, 0, 4, 5, _) => "ACTLRALIAS_EL1",
+        (3, 1, 0, 4, 1, _) => "ACTLRMASK_EL1",
+        (3, 1, 5, 4, 1, _) => "ACTLRMASK_EL12",
+        (3, 1, 4, 4, 1, _) => "ACTLRMASK_EL2",
+        (3, 1, 0, 0, 1, _) => "ACTLR_EL1",
+        (3, 1, 5, 0, 1, _) => "ACTLR_EL12",
+        (3, 1, 4, 0, 1, _) => "ACTLR_EL2",
+        (3, 1, 6, 0, 1, _) => "ACTLR_EL3",
+        (3, 5, 0, 1, 0, _) => "AFSR0_EL1",
+        (3, 5, 5, 1, 0, _) => "AFSR0_EL12",
+        (3, 5, 4, 1, 0, _) => "AFSR0_EL2",
+        (3, 5, 6, 1, 0, _) => "AFSR0_EL3",
+        (3, 5, 0, 1, 1, _) => "AFSR1_EL1",
+        (3, 5, 5, 1, 1, _) => "AFSR1_EL12",
+        (3, 5, 4, 1, 1, _) => "AFSR1_EL2",
+        (3, 5, 6, 1, 1, _) => "AFSR1_EL3",
+        (3, 0, 1, 0, 7, _) => "AIDR_EL1",
+        (3, 4, 0, 3, 0, _) => "ALLINT",
+        (3, 10, 0, 3, 1, _) => "AMAIR2_EL1",
+        (3, 10, 5, 3, 1, _) => "AMAIR2_EL12",
+        (3, 10, 4, 3, 1, _) => "AMAIR2_EL2",
+        (3, 10, 6, 3, 1, _) => "AMAIR2_EL3",
+        (3, 10, 0, 3, 0, _) => "AMAIR_EL1",
+        (3, 10, 5, 3, 0, _) => "AMAIR_EL12",
+        (3, 10, 4, 3, 0, _) => "AMAIR_EL2",
+        (3, 10, 6, 3, 0, _) => "AMAIR_EL3",
+        (3, 13, 3, 2, 1, _) => "AMCFGR_EL0",
+        (3, 13, 3, 2, 6, _) => "AMCG1IDR_EL0",
+        (3, 13, 3, 2, 2, _) => "AMCGCR_EL0",
+        (3, 13, 3, 2, 4, _) => "AMCNTENCLR0_EL0",
+        (3, 13, 3, 3, 0, _) => "AMCNTENCLR1_EL0",
+        (3, 13, 3, 2, 5, _) => "AMCNTENSET0_EL0",
+        (3, 13, 3, 3, 1, _) => "AMCNTENSET1_EL0",
+        (3, 13, 3, 2, 0, _) => "AMCR_EL0",
+        (3, 13, 3, 2, 3, _) => "AMUSERENR_EL0",
+        (3, 2, 0, 2, 1, _) => "APDAKeyHi_EL1",
+        (3, 2, 0, 2, 0, _) => "APDAKeyLo_EL1",
+        (3, 2, 0, 2, 3, _) => "APDBKeyHi_EL1",
+        (3, 2, 0, 2, 2, _) => "APDBKeyLo_EL1",
+        (3, 2, 0, 3, 1, _) => "APGAKeyHi_EL1",
+        (3, 2, 0, 3, 0, _) => "APGAKeyLo_EL1",
+        (3, 2, 0, 1, 1, _) => "APIAKeyHi_EL1",
+        (3, 2, 0, 1, 0, _) => "APIAKeyLo_EL1",
+        (3, 2, 0, 1, 3, _) => "APIBKeyHi_EL1",
+        (3, 2, 0, 1, 2, _) => "APIBKeyLo_EL1",
+        (2, 9, 1, 0, 0, _) => "BRBCR_EL1",
+        (2, 9, 5, 0, 0, _) => "BRBCR_EL12",
+        (2, 9, 4, 0, 0, _) => "BRBCR_EL2",
+        (2, 9, 1, 0, 1, _) => "BRBFCR_EL1",
+        (2, 9, 1, 2, 0, _) => "BRBIDR0_EL1",
+        (2, 9, 1, 1, 0, _) => "BRBINFINJ_EL1",
+        (2, 9, 1, 1, 1, _) => "BRBSRCINJ_EL1",
+        (2, 9, 1, 1, 2, _) => "BRBTGTINJ_EL1",
+        (2, 9, 1, 0, 2, _) => "BRBTS_EL1",
+        (3, 0, 1, 0, 2, _) => "CCSIDR2_EL1",
+        (3, 0, 1, 0, 0, _) => "CCSIDR_EL1",
+        (3, 0, 1, 0, 1, _) => "CLIDR_EL1",
+        (3, 14, 3, 0, 0, _) => "CNTFRQ_EL0",
+        (3, 14, 4, 1, 0, _) => "CNTHCTL_EL2",
+        (3, 14, 4, 5, 1, _) => "CNTHPS_CTL_EL2",
+        (3, 14, 4, 5, 2, _) => "CNTHPS_CVAL_EL2",
+        (3, 14, 4, 5, 0, _) => "CNTHPS_TVAL_EL2",
+        (3, 14, 4, 2, 1, _) => "CNTHP_CTL_EL2",
+        (3, 14, 4, 2, 2, _) => "CNTHP_CVAL_EL2",
+        (3, 14, 4, 2, 0, _) => "CNTHP_TVAL_EL2",
+        (3, 14, 4, 4, 1, _) => "CNTHVS_CTL_EL2",
+        (3, 14, 4, 4, 2, _) => "CNTHVS_CVAL_EL2",
+        (3, 14, 4, 4, 0, _) => "CNTHVS_TVAL_EL2",
+        (3, 14, 4, 3, 1, _) => "CNTHV_CTL_EL2",
+        (3, 14, 4, 3, 2, _) => "CNTHV_CVAL_EL2",
+        (3, 14, 4, 3, 0, _) => "CNTHV_TVAL_EL2",
+        (3, 14, 0, 1, 0, _) => "CNTKCTL_EL1",
+        (3, 14, 5, 1, 0, _) => "CNTKCTL_EL12",
+        (3, 14, 3, 0, 5, _) => "CNTPCTSS_EL0",
+        (3, 14, 3, 0, 1, _) => "CNTPCT_EL0",
+        (3, 14, 4, 0, 6, _) => "CNTPOFF_EL2",
+        (3, 14, 7, 2, 1, _) => "CNTPS_CTL_EL1",
+        (3, 14, 7, 2, 2, _) => "CNTPS_CVAL_EL1",
+        (3, 14, 7, 2, 0, _) => "CNTPS_TVAL_EL1",
+        (3, 14, 3, 2, 1, _) => "CNTP_CTL_EL0",
+        (3, 14, 5, 2, 1, _) => "CNTP_CTL_EL02",
+        (3, 14, 3, 2, 2, _) => "CNTP_CVAL_EL0",
+        (3, 14, 5, 2, 2, _) => "CNTP_CVAL_EL02",
+        (3, 14, 3, 2, 0, _) => "CNTP_TVAL_EL0",
+        (3, 14, 5, 2, 0, _) => "CNTP_TVAL_EL02",
+        (3, 14, 3, 0, 6, _) => "CNTVCTSS_EL0",
+        (3, 14, 3, 0, 2, _) => "CNTVCT_EL0",
+        (3, 14, 4, 0, 3, _) => "CNTVOFF_EL2",
+        (3, 14, 3, 3, 1, _) => "CNTV_CTL_EL0",
+        (3, 14, 5, 3, 1, _) => "CNTV_CTL_EL02",
+        (3, 14, 3, 3, 2, _) => "CNTV_CVAL_EL0",
+        (3, 14, 5, 3, 2, _) => "CNTV_CVAL_EL02",
+        (3, 14, 3, 3, 0, _) => "CNTV_TVAL_EL0",
+        (3, 14, 5, 3, 0, _) => "CNTV_TVAL_EL02",
+        (3, 13, 0, 0, 1, _) => "CONTEXTIDR_EL1",
+        (3, 13, 5, 0, 1, _) => "CONTEXTIDR_EL12",
+        (3, 13, 4, 0, 1, _) => "CONTEXTIDR_EL2",
+        (3, 1, 0, 4, 4, _) => "CPACRALIAS_EL1",
+        (3, 1, 0, 4, 2, _) => "CPACRMASK_EL1",
+        (3, 1, 5, 4, 2, _) => "CPACRMASK_EL12",
+        (3, 1, 0, 0, 2, _) => "CPACR_EL1",
+        (3, 1, 5, 0, 2, _) => "CPACR_EL12",
+        (3, 1, 4, 4, 2, _) => "CPTRMASK_EL2",
+        (3, 1, 4, 1, 2, _) => "CPTR_EL2",
+        (3, 1, 6, 1, 2, _) => "CPTR_EL3",
+        (3, 0, 2, 0, 0, _) => "CSSELR_EL1",
+        (3, 0, 3, 0, 1, _) => "CTR_EL0",
+        (3, 4, 0, 2, 2, _) => "CurrentEL",
+        (3, 3, 4, 0, 0, _) => "DACR32_EL2",
+        (3, 4, 3, 2, 1, _) => "DAIF",
+        (2, 7, 0, 14, 6, _) => "DBGAUTHSTATUS_EL1",
+        (2, 7, 0, 9, 6, _) => "DBGCLAIMCLR_EL1",
+        (2, 7, 0, 8, 6, _) => "DBGCLAIMSET_EL1",
+        (2, 0, 3, 5, 0, true) => "DBGDTRRX_EL0",
+        (2, 0, 3, 5, 0, false) => "DBGDTRTX_EL0",
+        (2, 0, 3, 4, 0, _) => "DBGDTR_EL0",
+        (2, 1, 0, 4, 4, _) => "DBGPRCR_EL1",
+        (2, 0, 4, 7, 0, _) => "DBGVCR32_EL2",
+        (3, 0, 3, 0, 7, _) => "DCZID_EL0",
+        (3, 12, 0, 1, 1, _) => "DISR_EL1",
+        (3, 4, 3, 2, 5, _) => "DIT",
+        (3, 4, 3, 5, 1, _) => "DLR_EL0",
+        (3, 4, 3, 5, 0, _) => "DSPSR_EL0",
+        (3, 4, 0, 0, 1, _) => "ELR_EL1",
+        (3, 4, 5, 0, 1, _) => "ELR_EL12",
+        (3, 4, 4, 0, 1, _) => "ELR_EL2",
+        (3, 4, 6, 0, 1, _) => "ELR_EL3",
+        (3, 5, 0, 3, 0, _) => "ERRIDR_EL1",
+        (3, 5, 0, 3, 1, _) => "ERRSELR_EL1",
+        (3, 5, 0, 4, 3, _) => "ERXADDR_EL1",
+        (3, 5, 0, 4, 1, _) => "ERXCTLR_EL1",
+        (3, 5, 0, 4, 0, _) => "ERXFR_EL1",
+        (3, 5, 0, 3, 2, _) => "ERXGSR_EL1",
+        (3, 5, 0, 5, 0, _) => "ERXMISC0_EL1",
+        (3, 5, 0, 5, 1, _) => "ERXMISC1_EL1",
+        (3, 5, 0, 5, 2, _) => "ERXMISC2_EL1",
+        (3, 5, 0, 5, 3, _) => "ERXMISC3_EL1",
+        (3, 5, 0, 4, 6, _) => "ERXPFGCDN_EL1",
+        (3, 5, 0, 4, 5, _) => "ERXPFGCTL_EL1",
+        (3, 5, 0, 4, 4, _) => "ERXPFGF_EL1",
+        (3, 5, 0, 4, 2, _) => "ERXSTATUS_EL1",
+        (3, 5, 0, 2, 0, _) => "ESR_EL1",
+        (3, 5, 5, 2, 0, _) => "ESR_EL12",
+        (3, 5, 4, 2, 0, _) => "ESR_EL2",
+        (3, 5, 6, 2, 0, _) => "ESR_EL3",
+        (3, 6, 0, 0, 0, _) => "FAR_EL1",
+        (3, 6, 5, 0, 0, _) => "FAR_EL12",
+        (3, 6, 4, 0, 0, _) => "FAR_EL2",
+        (3, 6, 6, 0, 0, _) => "FAR_EL3",
+        (3, 1, 6, 1, 5, _) => "FGWTE3_EL3",
+        (3, 4, 3, 4, 0, _) => "FPCR",
+        (3, 5, 4, 3, 0, _) => "FPEXC32_EL2",
+        (3, 4, 3, 4, 2, _) => "FPMR",
+        (3, 4, 3, 4, 1, _) => "FPSR",
+        (3, 1, 0, 0, 6, _) => "GCR_EL1",
+        (3, 2, 0, 5, 2, _) => "GCSCRE0_EL1",
+        (3, 2, 0, 5, 0, _) => "GCSCR_EL1",
+        (3, 2, 5, 5, 0, _) => "GCSCR_EL12",
+        (3, 2, 4, 5, 0, _) => "GCSCR_EL2",
+        (3, 2, 6, 5, 0, _) => "GCSCR_EL3",
+        (3, 2, 3, 5, 1, _) => "GCSPR_EL0",
+        (3, 2, 0, 5, 1, _) => "GCSPR_EL1",
+        (3, 2, 5, 5, 1, _) => "GCSPR_EL12",
+        (3, 2, 4, 5, 1, _) => "GCSPR_EL2",
+        (3, 2, 6, 5, 1, _) => "GCSPR_EL3",
+        (3, 0, 1, 0, 4, _) => "GMID_EL1",
+        (3, 2, 6, 1, 5, _) => "GPCBW_EL3",
+        (3, 2, 6, 1, 6, _) => "GPCCR_EL3",
+        (3, 2, 6, 1, 4, _) => "GPTBR_EL3",
+        (3, 2, 4, 3, 4, _) => "HACDBSBR_EL2",
+        (3, 2, 4, 3, 5, _) => "HACDBSCONS_EL2",
+        (3, 1, 4, 1, 7, _) => "HACR_EL2",
+        (3, 3, 4, 1, 6, _) => "HAFGRTR_EL2",
+        (3, 1, 4, 2, 2, _) => "HCRX_EL2",
+        (3, 1, 4, 1, 0, _) => "HCR_EL2",
+        (3, 2, 4, 3, 2, _) => "HDBSSBR_EL2",
+        (3, 2, 4, 3, 3, _) => "HDBSSPROD_EL2",
+        (3, 3, 4, 1, 0, _) => "HDFGRTR2_EL2",
+        (3, 3, 4, 1, 4, _) => "HDFGRTR_EL2",
+        (3, 3, 4, 1, 1, _) => "HDFGWTR2_EL2",
+        (3, 3, 4, 1, 5, _) => "HDFGWTR_EL2",
+        (3, 3, 4, 1, 7, _) => "HFGITR2_EL2",
+        (3, 1, 4, 1, 6, _) => "HFGITR_EL2",
+        (3, 3, 4, 1, 2, _) => "HFGRTR2_EL2",
+        (3, 1, 4, 1, 4, _) => "HFGRTR_EL2",
+        (3, 3, 4, 1, 3, _) => "HFGWTR2_EL2",
+        (3, 1, 4, 1, 5, _) => "HFGWTR_EL2",
+        (3, 6, 4, 0, 4, _) => "HPFAR_EL2",
+        (3, 1, 4, 1, 3, _) => "HSTR_EL2",
+        (3, 12, 0, 11, 6, _) => "ICC_ASGI1R_EL1",
+        (3, 12, 0, 8, 3, _) => "ICC_BPR0_EL1",
+        (3, 12, 0, 12, 3, _) => "ICC_BPR1_EL1",
+        (3, 12, 0, 12, 4, _) => "ICC_CTLR_EL1",
+        (3, 12, 6, 12, 4, _) => "ICC_CTLR_EL3",
+        (3, 12, 0, 11, 1, _) => "ICC_DIR_EL1",
+        (3, 12, 0, 8, 1, _) => "ICC_EOIR0_EL1",
+        (3, 12, 0, 12, 1, _) => "ICC_EOIR1_EL1",
+        (3, 12, 0, 8, 2, _) => "ICC_HPPIR0_EL1",
+        (3, 12, 0, 12, 2, _) => "ICC_HPPIR1_EL1",
+        (3, 12, 0, 8, 0, _) => "ICC_IAR0_EL1",
+        (3, 12, 0, 12, 0, _) => "ICC_IAR1_EL1",
+        (3, 12, 0, 12, 6, _) => "ICC_IGRPEN0_EL1",
+        (3, 12, 0, 12, 7, _) => "ICC_IGRPEN1_EL1",
+        (3, 12, 6, 12, 7, _) => "ICC_IGRPEN1_EL3",
+        (3, 12, 0, 9, 5, _) => "ICC_NMIAR1_EL1",
+        (3, 4, 0, 6, 0, _) => "ICC_PMR_EL1",
+        (3, 12, 0, 11, 3, _) => "ICC_RPR_EL1",
+        (3, 12, 0, 11, 7, _) => "ICC_SGI0R_EL1",
+        (3, 12, 0, 11, 5, _) => "ICC_SGI1R_EL1",
+        (3, 12, 0, 12, 5, _) => "ICC_SRE_EL1",
+        (3, 12, 4, 9, 5, _) => "ICC_SRE_EL2",
+        (3, 12, 6, 12, 5, _) => "ICC_SRE_EL3",
+        (3, 12, 4, 11, 3, _) => "ICH_EISR_EL2",
+        (3, 12, 4, 11, 5, _) => "ICH_ELRSR_EL2",
+        (3, 12, 4, 11, 0, _) => "ICH_HCR_EL2",
+        (3, 12, 4, 11, 2, _) => "ICH_MISR_EL2",
+        (3, 12, 4, 11, 7, _) => "ICH_VMCR_EL2",
+        (3, 12, 4, 11, 1, _) => "ICH_VTR_EL2",
+        (3, 0, 0, 5, 4, _) => "ID_AA64AFR0_EL1",
+        (3, 0, 0, 5, 5, _) => "ID_AA64AFR1_EL1",
+        (3, 0, 0, 5, 0, _) => "ID_AA64DFR0_EL1",
+        (3, 0, 0, 5, 1, _) => "ID_AA64DFR1_EL1",
+        (3, 0, 0, 5, 2, _) => "ID_AA64DFR2_EL1",
+        (3, 0, 0, 4, 7, _) => "ID_AA64FPFR0_EL1",
+        (3, 0, 0, 6, 0, _) => "ID_AA64ISAR0_EL1",
+        (3, 0, 0, 6, 1, _) => "ID_AA64ISAR1_EL1",
+        (3, 0, 0, 6, 2, _) => "ID_AA64ISAR2_EL1",
+        (3, 0, 0, 6, 3, _) => "ID_AA64ISAR3_EL1",
+        (3, 0, 0, 7, 0, _) => "ID_AA64MMFR0_EL1",
+        (3, 0, 0, 7, 1, _) => "ID_AA64MMFR1_EL1",
+        (3, 0, 0, 7, 2, _) => "ID_AA64MMFR2_EL1",
+        (3, 0, 0, 7, 3, _) => "ID_AA64MMFR3_EL1",
+        (3, 0, 0, 7, 4, _) => "ID_AA64MMFR4_EL1",
+        (3, 0, 0, 4, 0, _) => "ID_AA64PFR0_EL1",
+        (3, 0, 0, 4, 1, _) => "ID_AA64PFR1_EL1",
+        (3, 0, 0, 4, 2, _) => "ID_AA64PFR2_EL1",
+        (3, 0, 0, 4, 5, _) => "ID_AA64SMFR0_EL1",
+        (3, 0, 0, 4, 4, _) => "ID_AA64ZFR0_EL1",
+        (3, 0, 0, 1, 3, _) => "ID_AFR0_EL1",
+        (3, 0, 0, 1, 2, _) => "ID_DFR0_EL1",
+        (3, 0, 0, 3, 5, _) => "ID_DFR1_EL1",
+        (3, 0, 0, 2, 0, _) => "ID_ISAR0_EL1",
+        (3, 0, 0, 2, 1, _) => "ID_ISAR1_EL1",
+        (3, 0, 0, 2, 2, _) => "ID_ISAR2_EL1",
+        (3, 0, 0, 2, 3, _) => "ID_ISAR3_EL1",
+        (3, 0, 0, 2, 4, _) => "ID_ISAR4_EL1",
+        (3, 0, 0, 2, 5, _) => "ID_ISAR5_EL1",
+        (3, 0, 0, 2, 7, _) => "ID_ISAR6_EL1",
+        (3, 0, 0, 1, 4, _) => "ID_MMFR0_EL1",
+        (3, 0, 0, 1, 5, _) => "ID_MMFR1_EL1",
+        (3, 0, 0, 1, 6, _) => "ID_MMFR2_EL1",
+        (3, 0, 0, 1, 7, _) => "ID_MMFR3_EL1",
+        (3, 0, 0, 2, 6, _) => "ID_MMFR4_EL1",
+        (3, 0, 0, 3, 6, _) => "ID_MMFR5_EL1",
+        (3, 0, 0, 1, 0, _) => "ID_PFR0_EL1",
+        (3, 0, 0, 1, 1, _) => "ID_PFR1_EL1",
+        (3, 0, 0, 3, 4, _) => "ID_PFR2_EL1",
+        (3, 5, 4, 0, 1, _) => "IFSR32_EL2",
+        (3, 12, 0, 1, 0, _) => "ISR_EL1",
+        (3, 10, 0, 4, 3, _) => "LORC_EL1",
+        (3, 10, 0, 4, 1, _) => "LOREA_EL1",
+        (3, 10, 0, 4, 7, _) => "LORID_EL1",
+        (3, 10, 0, 4, 2, _) => "LORN_EL1",
+        (3, 10, 0, 4, 0, _) => "LORSA_EL1",
+        (3, 10, 0, 2, 1, _) => "MAIR2_EL1",
+        (3, 10, 5, 2, 1, _) => "MAIR2_EL12",
+        (3, 10, 4, 1, 1, _) => "MAIR2_EL2",
+        (3, 10, 6, 1, 1, _) => "MAIR2_EL3",
+        (3, 10, 0, 2, 0, _) => "MAIR_EL1",
+        (3, 10, 5, 2, 0, _) => "MAIR_EL12",
+        (3, 10, 4, 2, 0, _) => "MAIR_EL2",
+        (3, 10, 6, 2, 0, _) => "MAIR_EL3",
+        (2, 0, 0, 2, 0, _) => "MDCCINT_EL1",
+        (2, 0, 3, 1, 0, _) => "MDCCSR_EL0",
+        (3, 1, 4, 1, 1, _) => "MDCR_EL2",
+        (3, 1, 6, 3, 1, _) => "MDCR_EL3",
+        (2, 1, 0, 0, 0, _) => "MDRAR_EL1",
+        (2, 0, 0, 2, 2, _) => "MDSCR_EL1",
+        (2, 0, 0, 4, 2, _) => "MDSELR_EL1",
+        (2, 0, 0, 5, 2, _) => "MDSTEPOP_EL1",
+        (3, 10, 4, 8, 7, _) => "MECIDR_EL2",
+        (3, 10, 4, 8, 1, _) => "MECID_A0_EL2",
+        (3, 10, 4, 8, 3, _) => "MECID_A1_EL2",
+        (3, 10, 4, 8, 0, _) => "MECID_P0_EL2",
+        (3, 10, 4, 8, 2, _) => "MECID_P1_EL2",
+        (3, 10, 6, 10, 1, _) => "MECID_RL_A_EL3",
+        (3, 6, 6, 0, 5, _) => "MFAR_EL3",
+        (3, 0, 0, 0, 0, _) => "MIDR_EL1",
+        (3, 10, 0, 5, 1, _) => "MPAM0_EL1",
+        (3, 10, 0, 5, 0, _) => "MPAM1_EL1",
+        (3, 10, 5, 5, 0, _) => "MPAM1_EL12",
+        (3, 10, 4, 5, 0, _) => "MPAM2_EL2",
+        (3, 10, 6, 5, 0, _) => "MPAM3_EL3",
+        (3, 10, 0, 5, 5, _) => "MPAMBW0_EL1",
+        (3, 10, 0, 5, 4, _) => "MPAMBW1_EL1",
+        (3, 10, 5, 5, 4, _) => "MPAMBW1_EL12",
+        (3, 10, 4, 5, 4, _) => "MPAMBW2_EL2",
+        (3, 10, 6, 5, 4, _) => "MPAMBW3_EL3",
+        (3, 10, 4, 5, 6, _) => "MPAMBWCAP_EL2",
+        (3, 10, 0, 4, 5, _) => "MPAMBWIDR_EL1",
+        (3, 10, 0, 5, 7, _) => "MPAMBWSM_EL1",
+        (3, 10, 4, 4, 0, _) => "MPAMHCR_EL2",
+        (3, 10, 0, 4, 4, _) => "MPAMIDR_EL1",
+        (3, 10, 0, 5, 3, _) => "MPAMSM_EL1",
+        (3, 10, 4, 6, 0, _) => "MPAMVPM0_EL2",
+        (3, 10, 4, 6, 1, _) => "MPAMVPM1_EL2",
+        (3, 10, 4, 6, 2, _) => "MPAMVPM2_EL2",
+        (3, 10, 4, 6, 3, _) => "MPAMVPM3_EL2",
+        (3, 10, 4, 6, 4, _) => "MPAMVPM4_EL2",
+        (3, 10, 4, 6, 5, _) => "MPAMVPM5_EL2",
+        (3, 10, 4, 6, 6, _) => "MPAMVPM6_EL2",
+        (3, 10, 4, 6, 7, _) => "MPAMVPM7_EL2",
+        (3, 10, 4, 4, 1, _) => "MPAMVPMV_EL2",
+        (3, 0, 0, 0, 5, _) => "MPIDR_EL1",
+        (3, 0, 0, 3, 0, _) => "MVFR0_EL1",
+        (3, 0, 0, 3, 1, _) => "MVFR1_EL1",
+        (3, 0, 0, 3, 2, _) => "MVFR2_EL1",
+        (3, 4, 3, 2, 0, _) => "NZCV",
+        (2, 1, 0, 3, 4, _) => "OSDLR_EL1",
+        (2, 0, 0, 0, 2, _) => "OSDTRRX_EL1",
+        (2, 0, 0, 3, 2, _) => "OSDTRTX_EL1",
+        (2, 0, 0, 6, 2, _) => "OSECCR_EL1",
+        (2, 1, 0, 0, 4, _) => "OSLAR_EL1",
+        (2, 1, 0, 1, 4, _) => "OSLSR_EL1",
+        (3, 4, 0, 2, 3, _) => "PAN",
+        (3, 7, 0, 4, 0, _) => "PAR_EL1",
+        (3, 6, 0, 0, 5, _) => "PFAR_EL1",
+        (3, 6, 5, 0, 5, _) => "PFAR_EL12",
+        (3, 6, 4, 0, 5, _) => "PFAR_EL2",
+        (3, 10, 0, 2, 2, _) => "PIRE0_EL1",
+        (3, 10, 5, 2, 2, _) => "PIRE0_EL12",
+        (3, 10, 4, 2, 2, _) => "PIRE0_EL2",
+        (3, 10, 0, 2, 3, _) => "PIR_EL1",
+        (3, 10, 5, 2, 3, _) => "PIR_EL12",
+        (3, 10, 4, 2, 3, _) => "PIR_EL2",
+        (3, 10, 6, 2, 3, _) => "PIR_EL3",
+        (3, 4, 0, 3, 1, _) => "PM",
+        (3, 9, 0, 10, 7, _) => "PMBIDR_EL1",
+        (3, 9, 0, 10, 0, _) => "PMBLIMITR_EL1",
+        (3, 9, 0, 10, 5, _) => "PMBMAR_EL1",
+        (3, 9, 0, 10, 1, _) => "PMBPTR_EL1",
+        (3, 9, 0, 10, 3, _) => "PMBSR_EL1",
+        (3, 9, 5, 10, 3, _) => "PMBSR_EL12",
+        (3, 9, 4, 10, 3, _) => "PMBSR_EL2",
+        (3, 9, 6, 10, 3, _) => "PMBSR_EL3",
+        (3, 14, 3, 15, 7, _) => "PMCCFILTR_EL0",
+        (3, 9, 3, 13, 0, _) => "PMCCNTR_EL0",
+        (2, 14, 0, 11, 7, _) => "PMCCNTSVR_EL1",
+        (3, 9, 3, 12, 6, _) => "PMCEID0_EL0",
+        (3, 9, 3, 12, 7, _) => "PMCEID1_EL0",
+        (3, 9, 3, 12, 2, _) => "PMCNTENCLR_EL0",
+        (3, 9, 3, 12, 1, _) => "PMCNTENSET_EL0",
+        (3, 9, 3, 12, 0, _) => "PMCR_EL0",
+        (3, 9, 0, 14, 5, _) => "PMECR_EL1",
+        (3, 9, 0, 14, 7, _) => "PMIAR_EL1",
+        (3, 9, 3, 6, 0, _) => "PMICFILTR_EL0",
+        (3, 9, 3, 4, 0, _) => "PMICNTR_EL0",
+        (2, 14, 0, 12, 0, _) => "PMICNTSVR_EL1",
+        (3, 9, 0, 14, 2, _) => "PMINTENCLR_EL1",
+        (3, 9, 0, 14, 1, _) => "PMINTENSET_EL1",
+        (3, 9, 0, 14, 6, _) => "PMMIR_EL1",
+        (3, 9, 3, 12, 3, _) => "PMOVSCLR_EL0",
+        (3, 9, 3, 14, 3, _) => "PMOVSSET_EL0",
+        (3, 9, 0, 9, 0, _) => "PMSCR_EL1",
+        (3, 9, 5, 9, 0, _) => "PMSCR_EL12",
+        (3, 9, 4, 9, 0, _) => "PMSCR_EL2",
+        (3, 9, 0, 10, 4, _) => "PMSDSFR_EL1",
+        (3, 9, 3, 12, 5, _) => "PMSELR_EL0",
+        (3, 9, 0, 9, 5, _) => "PMSEVFR_EL1",
+        (3, 9, 0, 9, 4, _) => "PMSFCR_EL1",
+        (3, 9, 0, 9, 2, _) => "PMSICR_EL1",
+        (3, 9, 0, 9, 7, _) => "PMSIDR_EL1",
+        (3, 9, 0, 9, 3, _) => "PMSIRR_EL1",
+        (3, 9, 0, 9, 6, _) => "PMSLATFR_EL1",
+        (3, 9, 0, 9, 1, _) => "PMSNEVFR_EL1",
+        (3, 9, 0, 13, 3, _) => "PMSSCR_EL1",
+        (3, 9, 3, 12, 4, _) => "PMSWINC_EL0",
+        (3, 9, 0, 14, 4, _) => "PMUACR_EL1",
+        (3, 9, 3, 14, 0, _) => "PMUSERENR_EL0",
+        (3, 9, 3, 13, 2, _) => "PMXEVCNTR_EL0",
+        (3, 9, 3, 13, 1, _) => "PMXEVTYPER_EL0",
+        (3, 10, 3, 2, 4, _) => "POR_EL0",
+        (3, 10, 0, 2, 4, _) => "POR_EL1",
+        (3, 10, 5, 2, 4, _) => "POR_EL12",
+        (3, 10, 4, 2, 4, _) => "POR_EL2",
+        (3, 10, 6, 2, 4, _) => "POR_EL3",
+        (3, 13, 0, 0, 6, _) => "RCWMASK_EL1",
+        (3, 13, 0, 0, 3, _) => "RCWSMASK_EL1",
+        (3, 0, 0, 0, 6, _) => "REVIDR_EL1",
+        (3, 1, 0, 0, 5, _) => "RGSR_EL1",
+        (3, 12, 0, 0, 2, _) => "RMR_EL1",
+        (3, 12, 4, 0, 2, _) => "RMR_EL2",
+        (3, 12, 6, 0, 2, _) => "RMR_EL3",
+        (3, 2, 3, 4, 0, _) => "RNDR",
+        (3, 2, 3, 4, 1, _) => "RNDRRS",
+        (3, 12, 0, 0, 1, _) => "RVBAR_EL1",
+        (3, 12, 4, 0, 1, _) => "RVBAR_EL2",
+        (3, 12, 6, 0, 1, _) => "RVBAR_EL3",
+        (3, 10, 4, 2, 5, _) => "S2PIR_EL2",
+        (3, 10, 0, 2, 5, _) => "S2POR_EL1",
+        (3, 1, 6, 1, 0, _) => "SCR_EL3",
+        (3, 1, 0, 4, 7, _) => "SCTLR2ALIAS_EL1",
+        (3, 1, 0, 4, 3, _) => "SCTLR2MASK_EL1",
+        (3, 1, 5, 4, 3, _) => "SCTLR2MASK_EL12",
+        (3, 1, 4, 4, 3, _) => "SCTLR2MASK_EL2",
+        (3, 1, 0, 0, 3, _) => "SCTLR2_EL1",
+        (3, 1, 5, 0, 3, _) => "SCTLR2_EL12",
+        (3, 1, 4, 0, 3, _) => "SCTLR2_EL2",
+        (3, 1, 6, 0, 3, _) => "SCTLR2_EL3",
+        (3, 1, 0, 4, 6, _) => "SCTLRALIAS_EL1",
+        (3, 1, 0, 4, 0, _) => "SCTLRMASK_EL1",
+        (3, 1, 5, 4, 0, _) => "SCTLRMASK_EL12",
+        (3, 1, 4, 4, 0, _) => "SCTLRMASK_EL2",
+        (3, 1, 0, 0, 0, _) => "SCTLR_EL1",
+        (3, 1, 5, 0, 0, _) => "SCTLR_EL12",
+        (3, 1, 4, 0, 0, _) => "SCTLR_EL2",
+        (3, 1, 6, 0, 0, _) => "SCTLR_EL3",
+        (3, 13, 3, 0, 7, _) => "SCXTNUM_EL0",
+        (3, 13, 0, 0, 7, _) => "SCXTNUM_EL1",
+        (3, 13, 5, 0, 7, _) => "SCXTNUM_EL12",
+        (3, 13, 4, 0, 7, _) => "SCXTNUM_EL2",
+        (3, 13, 6, 0, 7, _) => "SCXTNUM_EL3",
+        (3, 1, 4, 3, 1, _) => "SDER32_EL2",
+        (3, 1, 6, 1, 1, _) => "SDER32_EL3",
+        (3, 1, 0, 2, 6, _) => "SMCR_EL1",
+        (3, 1, 5, 2, 6, _) => "SMCR_EL12",
+        (3, 1, 4, 2, 6, _) => "SMCR_EL2",
+        (3, 1, 6, 2, 6, _) => "SMCR_EL3",
+        (3, 0, 1, 0, 6, _) => "SMIDR_EL1",
+        (3, 1, 4, 2, 5, _) => "SMPRIMAP_EL2",
+        (3, 1, 0, 2, 4, _) => "SMPRI_EL1",
+        (2, 9, 0, 13, 3, _) => "SPMACCESSR_EL1",
+        (2, 9, 5, 13, 3, _) => "SPMACCESSR_EL12",
+        (2, 9, 4, 13, 3, _) => "SPMACCESSR_EL2",
+        (2, 9, 6, 13, 3, _) => "SPMACCESSR_EL3",
+        (2, 9, 0, 13, 7, _) => "SPMCFGR_EL1",
+        (2, 9, 3, 12, 2, _) => "SPMCNTENCLR_EL0",
+        (2, 9, 3, 12, 1, _) => "SPMCNTENSET_EL0",
+        (2, 9, 3, 12, 0, _) => "SPMCR_EL0",
+        (2, 9, 0, 13, 6, _) => "SPMDEVAFF_EL1",
+        (2, 9, 0, 13, 5, _) => "SPMDEVARCH_EL1",
+        (2, 9, 0, 13, 4, _) => "SPMIIDR_EL1",
+        (2, 9, 0, 14, 2, _) => "SPMINTENCLR_EL1",
+        (2, 9, 0, 14, 1, _) => "SPMINTENSET_EL1",
+        (2, 9, 3, 12, 3, _) => "SPMOVSCLR_EL0",
+        (2, 9, 3, 14, 3, _) => "SPMOVSSET_EL0",
+        (2, 9, 6, 14, 7, _) => "SPMROOTCR_EL3",
+        (2, 9, 7, 14, 7, _) => "SPMSCR_EL1",
+        (2, 9, 3, 12, 5, _) => "SPMSELR_EL0",
+        (3, 4, 0, 0, 0, _) => "SPSR_EL1",
+        (3, 4, 5, 0, 0, _) => "SPSR_EL12",
+        (3, 4, 4, 0, 0, _) => "SPSR_EL2",
+        (3, 4, 6, 0, 0, _) => "SPSR_EL3",
+        (3, 4, 4, 3, 1, _) => "SPSR_abt",
+        (3, 4, 4, 3, 3, _) => "SPSR_fiq",
+        (3, 4, 4, 3, 0, _) => "SPSR_irq",
+        (3, 4, 4, 3, 2, _) => "SPSR_und",
+        (3, 4, 0, 2, 0, _) => "SPSel",
+        (3, 4, 0, 1, 0, _) => "SP_EL0",
+        (3, 4, 4, 1, 0, _) => "SP_EL1",
+        (3, 4, 6, 1, 0, _) => "SP_EL2",
+        (3, 4, 3, 2, 6, _) => "SSBS",
+        (3, 4, 3, 2, 2, _) => "SVCR",
+        (3, 4, 3, 2, 7, _) => "TCO",
+        (3, 2, 0, 7, 7, _) => "TCR2ALIAS_EL1",
+        (3, 2, 0, 7, 3, _) => "TCR2MASK_EL1",
+        (3, 2, 5, 7, 3, _) => "TCR2MASK_EL12",
+        (3, 2, 4, 7, 3, _) => "TCR2MASK_EL2",
+        (3, 2, 0, 0, 3, _) => "TCR2_EL1",
+        (3, 2, 5, 0, 3, _) => "TCR2_EL12",
+        (3, 2, 4, 0, 3, _) => "TCR2_EL2",
+        (3, 2, 0, 7, 6, _) => "TCRALIAS_EL1",
+        (3, 2, 0, 7, 2, _) => "TCRMASK_EL1",
+        (3, 2, 5, 7, 2, _) => "TCRMASK_EL12",
+        (3, 2, 4, 7, 2, _) => "TCRMASK_EL2",
+        (3, 2, 0, 0, 2, _) => "TCR_EL1",
+        (3, 2, 5, 0, 2, _) => "TCR_EL12",
+        (3, 2, 4, 0, 2, _) => "TCR_EL2",
+        (3, 2, 6, 0, 2, _) => "TCR_EL3",
+        (3, 5, 0, 6, 1, _) => "TFSRE0_EL1",
+        (3, 5, 0, 6, 0, _) => "TFSR_EL1",
+        (3, 5, 5, 6, 0, _) => "TFSR_EL12",
+        (3, 5, 4, 6, 0, _) => "TFSR_EL2",
+        (3, 5, 6, 6, 0, _) => "TFSR_EL3",
+        (3, 13, 3, 0, 5, _) => "TPIDR2_EL0",
+        (3, 13, 3, 0, 3, _) => "TPIDRRO_EL0",
+        (3, 13, 3, 0, 2, _) => "TPIDR_EL0",
+        (3, 13, 0, 0, 4, _) => "TPIDR_EL1",
+        (3, 13, 4, 0, 2, _) => "TPIDR_EL2",
+        (3, 13, 6, 0, 2, _) => "TPIDR_EL3",
+        (3, 9, 0, 11, 2, _) => "TRBBASER_EL1",
+        (3, 9, 0, 11, 7, _) => "TRBIDR_EL1",
+        (3, 9, 0, 11, 0, _) => "TRBLIMITR_EL1",
+        (3, 9, 0, 11, 4, _) => "TRBMAR_EL1",
+        (3, 9, 0, 11, 5, _) => "TRBMPAM_EL1",
+        (3, 9, 0, 11, 1, _) => "TRBPTR_EL1",
+        (3, 9, 0, 11, 3, _) => "TRBSR_EL1",
+        (3, 9, 5, 11, 3, _) => "TRBSR_EL12",
+        (3, 9, 4, 11, 3, _) => "TRBSR_EL2",
+        (3, 9, 6, 11, 3, _) => "TRBSR_EL3",
+        (3, 9, 0, 11, 6, _) => "TRBTRG_EL1",
+        (2, 7, 1, 14, 6, _) => "TRCAUTHSTATUS",
+        (2, 0, 1, 6, 0, _) => "TRCAUXCTLR",
+        (2, 0, 1, 15, 0, _) => "TRCBBCTLR",
+        (2, 0, 1, 14, 0, _) => "TRCCCCTLR",
+        (2, 3, 1, 0, 2, _) => "TRCCIDCCTLR0",
+        (2, 3, 1, 1, 2, _) => "TRCCIDCCTLR1",
+        (2, 7, 1, 9, 6, _) => "TRCCLAIMCLR",
+        (2, 7, 1, 8, 6, _) => "TRCCLAIMSET",
+        (2, 0, 1, 4, 0, _) => "TRCCONFIGR",
+        (2, 7, 1, 15, 6, _) => "TRCDEVARCH",
+        (2, 7, 1, 2, 7, _) => "TRCDEVID",
+        (2, 0, 1, 8, 0, _) => "TRCEVENTCTL0R",
+        (2, 0, 1, 9, 0, _) => "TRCEVENTCTL1R",
+        (2, 0, 1, 8, 7, _) => "TRCIDR0",
+        (2, 0, 1, 9, 7, _) => "TRCIDR1",
+        (2, 0, 1, 2, 6, _) => "TRCIDR10",
+        (2, 0, 1, 3, 6, _) => "TRCIDR11",
+        (2, 0, 1, 4, 6, _) => "TRCIDR12",
+        (2, 0, 1, 5, 6, _) => "TRCIDR13",
+        (2, 0, 1, 10, 7, _) => "TRCIDR2",
+        (2, 0, 1, 11, 7, _) => "TRCIDR3",
+        (2, 0, 1, 12, 7, _) => "TRCIDR4",
+        (2, 0, 1, 13, 7, _) => "TRCIDR5",
+        (2, 0, 1, 14, 7, _) => "TRCIDR6",
+        (2, 0, 1, 15, 7, _) => "TRCIDR7",
+        (2, 0, 1, 0, 6, _) => "TRCIDR8",
+        (2, 0, 1, 1, 6, _) => "TRCIDR9",
+        (2, 0, 1, 0, 7, _) => "TRCIMSPEC0",
+        (3, 1, 0, 2, 3, _) => "TRCITECR_EL1",
+        (3, 1, 5, 2, 3, _) => "TRCITECR_EL12",
+        (3, 1, 4, 2, 3, _) => "TRCITECR_EL2",
+        (2, 0, 1, 2, 1, _) => "TRCITEEDCR",
+        (2, 1, 1, 1, 4, _) => "TRCOSLSR",
+        (2, 0, 1, 1, 0, _) => "TRCPRGCTLR",
+        (2, 0, 1, 1, 1, _) => "TRCQCTLR",
+        (2, 0, 1, 10, 0, _) => "TRCRSR",
+        (2, 0, 1, 6, 4, _) => "TRCSEQRSTEVR",
+        (2, 0, 1, 7, 4, _) => "TRCSEQSTR",
+        (2, 0, 1, 11, 0, _) => "TRCSTALLCTLR",
+        (2, 0, 1, 3, 0, _) => "TRCSTATR",
+        (2, 0, 1, 13, 0, _) => "TRCSYNCPR",
+        (2, 0, 1, 0, 1, _) => "TRCTRACEIDR",
+        (2, 0, 1, 12, 0, _) => "TRCTSCTLR",
+        (2, 0, 1, 0, 2, _) => "TRCVICTLR",
+        (2, 0, 1, 1, 2, _) => "TRCVIIECTLR",
+        (2, 0, 1, 3, 2, _) => "TRCVIPCSSCTLR",
+        (2, 0, 1, 2, 2, _) => "TRCVISSCTLR",
+        (2, 3, 1, 2, 2, _) => "TRCVMIDCCTLR0",
+        (2, 3, 1, 3, 2, _) => "TRCVMIDCCTLR1",
+        (3, 1, 0, 2, 1, _) => "TRFCR_EL1",
+        (3, 1, 5, 2, 1, _) => "TRFCR_EL12",
+        (3, 1, 4, 2, 1, _) => "TRFCR_EL2",
+        (3, 2, 0, 0, 0, _) => "TTBR0_EL1",
+        (3, 2, 5, 0, 0, _) => "TTBR0_EL12",
+        (3, 2, 4, 0, 0, _) => "TTBR0_EL2",
+        (3, 2, 6, 0, 0, _) => "TTBR0_EL3",
+        (3, 2, 0, 0, 1, _) => "TTBR1_EL1",
+        (3, 2, 5, 0, 1, _) => "TTBR1_EL12",
+        (3, 2, 4, 0, 1, _) => "TTBR1_EL2",
+        (3, 4, 0, 2, 4, _) => "UAO",
+        (3, 12, 0, 0, 0, _) => "VBAR_EL1",
+        (3, 12, 5, 0, 0, _) => "VBAR_EL12",
+        (3, 12, 4, 0, 0, _) => "VBAR_EL2",
+        (3, 12, 6, 0, 0, _) => "VBAR_EL3",
+        (3, 12, 4, 1, 1, _) => "VDISR_EL2",
+        (3, 12, 6, 1, 1, _) => "VDISR_EL3",
+        (3, 10, 4, 9, 1, _) => "VMECID_A_EL2",
+        (3, 10, 4, 9, 0, _) => "VMECID_P_EL2",
+        (3, 0, 4, 0, 5, _) => "VMPIDR_EL2",
+        (3, 2, 4, 2, 0, _) => "VNCR_EL2",
+        (3, 0, 4, 0, 0, _) => "VPIDR_EL2",
+        (3, 5, 4, 2, 3, _) => "VSESR_EL2",
+        (3, 5, 6, 2, 3, _) => "VSESR_EL3",
+        (3, 2, 4, 6, 2, _) => "VSTCR_EL2",
+        (3, 2, 4, 6, 0, _) => "VSTTBR_EL2",
+        (3, 2, 4, 1, 2, _) => "VTCR_EL2",
+        (3, 2, 4, 1, 0, _) => "VTTBR_EL2",
+        (3, 1, 0, 2, 0, _) => "ZCR_EL1",
+        (3, 1, 5, 2, 0, _) => "ZCR_EL12",
+        (3, 1, 4, 2, 0, _) => "ZCR_EL2",
+        (3, 1, 6, 2, 0, _) => "ZCR_EL3",
+        _ => return None,
+    })
+}
+
+/// Returns the name of the System instruction with the given encoding and op0 1, such as
+/// `TLBI VAE1IS`, and whether it takes a general-purpose register operand, if there is one.
+pub fn sys_instruction_name(
+    op1: u64,
+    op2: u64,
+    crn: u64,
+    crm: u64,
+) -> Option<(&'static str, bool)> {
+    Some(match (crn, op1, crm, op2) {
+        (7, 4, 8, 6) => ("AT S12E0R", true),
+        (7, 4, 8, 7) => ("AT S12E0W", true),
+        (7, 4, 8, 4) => ("AT S12E1R", true),
+        (7, 4, 8, 5) => ("AT S12E1W", true),
+        (7, 0, 8, 2) => ("AT S1E0R", true),
+        (7, 0, 8, 3) => ("AT S1E0W", true),
+        (7, 0, 9, 2) => ("AT S1E1A", true),
+        (7, 0, 8, 0) => ("AT S1E1R", true),
+        (7, 0, 9, 0) => ("AT S1E1RP", true),
+        (7, 0, 8, 1) => ("AT S1E1W", true),
+        (7, 0, 9, 1) => ("AT S1E1WP", true),
+        (7, 4, 9, 2) => ("AT S1E2A", true),
+        (7, 4, 8, 0) => ("AT S1E2R", true),
+        (7, 4, 8, 1) => ("AT S1E2W", true),
+        (7, 6, 9, 2) => ("AT S1E3A", true),
+        (7, 6, 8, 0) => ("AT S1E3R", true),
+        (7, 6, 8, 1) => ("AT S1E3W", true),
+        (7, 1, 2, 4) => ("BRB IALL", false),
+        (7, 1, 2, 5) => ("BRB INJ", false),
+        (7, 3, 3, 4) => ("CFP RCTX", true),
+        (7, 3, 3, 6) => ("COSP RCTX", true),
+        (7, 3, 3, 7) => ("CPP RCTX", true),
+        (7, 0, 10, 6) => ("DC CGDSW", true),
+        (7, 3, 10, 5) => ("DC CGDVAC", true),
+        (7, 3, 13, 5) => ("DC CGDVADP", true),
+        (7, 3, 12, 5) => ("DC CGDVAP", true),
+        (7, 0, 10, 4) => ("DC CGSW", true),
+        (7, 3, 10, 3) => ("DC CGVAC", true),
+        (7, 3, 13, 3) => ("DC CGVADP", true),
+        (7, 3, 12, 3) => ("DC CGVAP", true),
+        (7, 0, 14, 6) => ("DC CIGDSW", true),
+        (7, 3, 14, 5) => ("DC CIGDVAC", true),
+        (7, 0, 14, 4) => ("DC CIGSW", true),
+        (7, 3, 14, 3) => ("DC CIGVAC", true),
+        (7, 0, 14, 2) => ("DC CISW", true),
+        (7, 3, 14, 1) => ("DC CIVAC", true),
+        (7, 0, 10, 2) => ("DC CSW", true),
+        (7, 3, 10, 1) => ("DC CVAC", true),
+        (7, 3, 13, 1) => ("DC CVADP", true),
+        (7, 3, 12, 1) => ("DC CVAP", true),
+        (7, 3, 11, 1) => ("DC CVAU", true),
+        (7, 3, 4, 3) => ("DC GVA", true),
+        (7, 3, 4, 4) => ("DC GZVA", true),
+        (7, 0, 6, 6) => ("DC IGDSW", true),
+        (7, 0, 6, 5) => ("DC IGDVAC", true),
+        (7, 0, 6, 4) => ("DC IGSW", true),
+        (7, 0, 6, 3) => ("DC IGVAC", true),
+        (7, 0, 6, 2) => ("DC ISW", true),
+        (7, 0, 6, 1) => ("DC IVAC", true),
+        (7, 3, 4, 1) => ("DC ZVA", true),
+        (7, 3, 3, 5) => ("DVP RCTX", true),
+        (7, 0, 5, 0) => ("IC IALLU", false),
+        (7, 0, 1, 0) => ("IC IALLUIS", false),
+        (7, 3, 5, 1) => ("IC IVAU", true),
+        (8, 4, 7, 4) => ("TLBI ALLE1", false),
+        (8, 4, 3, 4) => ("TLBI ALLE1IS", false),
+        (9, 4, 3, 4) => ("TLBI ALLE1ISNXS", false),
+        (9, 4, 7, 4) => ("TLBI ALLE1NXS", false),
+        (8, 4, 1, 4) => ("TLBI ALLE1OS", false),
+        (9, 4, 1, 4) => ("TLBI ALLE1OSNXS", false),
+        (8, 4, 7, 0) => ("TLBI ALLE2", false),
+        (8, 4, 3, 0) => ("TLBI ALLE2IS", false),
+        (9, 4, 3, 0) => ("TLBI ALLE2ISNXS", false),
+        (9, 4, 7, 0) => ("TLBI ALLE2NXS", false),
+        (8, 4, 1, 0) => ("TLBI ALLE2OS", false),
+        (9, 4, 1, 0) => ("TLBI ALLE2OSNXS", false),
+        (8, 6, 7, 0) => ("TLBI ALLE3", false),
+        (8, 6, 3, 0) => ("TLBI ALLE3IS", false),
+        (9, 6, 3, 0) => ("TLBI ALLE3ISNXS", false),
+        (9, 6, 7, 0) => ("TLBI ALLE3NXS", false),
+        (8, 6, 1, 0) => ("TLBI ALLE3OS", false),
+        (9, 6, 1, 0) => ("TLBI ALLE3OSNXS", false),
+        (8, 0, 7, 2) => ("TLBI ASIDE1", true),
+        (8, 0, 3, 2) => ("TLBI ASIDE1IS", true),
+        (9, 0, 3, 2) => ("TLBI ASIDE1ISNXS", true),
+        (9, 0, 7, 2) => ("TLBI ASIDE1NXS", true),
+        (8, 0, 1, 2) => ("TLBI ASIDE1OS", true),
+        (9, 0, 1, 2) => ("TLBI ASIDE1OSNXS", true),
+        (8, 4, 4, 1) => ("TLBI IPAS2E1", true),
+        (8, 4, 0, 1) => ("TLBI IPAS2E1IS", true),
+        (9, 4, 0, 1) => ("TLBI IPAS2E1ISNXS", true),
+        (9, 4, 4, 1) => ("TLBI IPAS2E1NXS", true),
+        (8, 4, 4, 0) => ("TLBI IPAS2E1OS", true),
+        (9, 4, 4, 0) => ("TLBI IPAS2E1OSNXS", true),
+        (8, 4, 4, 5) => ("TLBI IPAS2LE1", true),
+        (8, 4, 0, 5) => ("TLBI IPAS2LE1IS", true),
+        (9, 4, 0, 5) => ("TLBI IPAS2LE1ISNXS", true),
+        (9, 4, 4, 5) => ("TLBI IPAS2LE1NXS", true),
+        (8, 4, 4, 4) => ("TLBI IPAS2LE1OS", true),
+        (9, 4, 4, 4) => ("TLBI IPAS2LE1OSNXS", true),
+        (8, 6, 7, 4) => ("TLBI PAALL", false),
+        (8, 6, 1, 4) => ("TLBI PAALLOS", false),
+        (8, 4, 4, 2) => ("TLBI RIPAS2E1", true),
+        (8, 4, 0, 2) => ("TLBI RIPAS2E1IS", true),
+        (9, 4, 0, 2) => ("TLBI RIPAS2E1ISNXS", true),
+        (9, 4, 4, 2) => ("TLBI RIPAS2E1NXS", true),
+        (8, 4, 4, 3) => ("TLBI RIPAS2E1OS", true),
+        (9, 4, 4, 3) => ("TLBI RIPAS2E1OSNXS", true),
+        (8, 4, 4, 6) => ("TLBI RIPAS2LE1", true),
+        (8, 4, 0, 6) => ("TLBI RIPAS2LE1IS", true),
+        (9, 4, 0, 6) => ("TLBI RIPAS2LE1ISNXS", true),
+        (9, 4, 4, 6) => ("TLBI RIPAS2LE1NXS", true),
+        (8, 4, 4, 7) => ("TLBI RIPAS2LE1OS", true),
+        (9, 4, 4, 7) => ("TLBI RIPAS2LE1OSNXS", true),
+        (8, 6, 4, 7) => ("TLBI RPALOS", true),
+        (8, 6, 4, 3) => ("TLBI RPAOS", true),
+        (8, 0, 6, 3) => ("TLBI RVAAE1", true),
+        (8, 0, 2, 3) => ("TLBI RVAAE1IS", true),
+        (9, 0, 2, 3) => ("TLBI RVAAE1ISNXS", true),
+        (9, 0, 6, 3) => ("TLBI RVAAE1NXS", true),
+        (8, 0, 5, 3) => ("TLBI RVAAE1OS", true),
+        (9, 0, 5, 3) => ("TLBI RVAAE1OSNXS", true),
+        (8, 0, 6, 7) => ("TLBI RVAALE1", true),
+        (8, 0, 2, 7) => ("TLBI RVAALE1IS", true),
+        (9, 0, 2, 7) => ("TLBI RVAALE1ISNXS", true),
+        (9, 0, 6, 7) => ("TLBI RVAALE1NXS", true),
+        (8, 0, 5, 7) => ("TLBI RVAALE1OS", true),
+        (9, 0, 5, 7) => ("TLBI RVAALE1OSNXS", true),
+        (8, 0, 6, 1) => ("TLBI RVAE1", true),
+        (8, 0, 2, 1) => ("TLBI RVAE1IS", true),
+        (9, 0, 2, 1) => ("TLBI RVAE1ISNXS", true),
+        (9, 0, 6, 1) => ("TLBI RVAE1NXS", true),
+        (8, 0, 5, 1) => ("TLBI RVAE1OS", true),
+        (9, 0, 5, 1) => ("TLBI RVAE1OSNXS", true),
+        (8, 4, 6, 1) => ("TLBI RVAE2", true),
+        (8, 4, 2, 1) => ("TLBI RVAE2IS", true),
+        (9, 4, 2, 1) => ("TLBI RVAE2ISNXS", true),
+        (9, 4, 6, 1) => ("TLBI RVAE2NXS", true),
+        (8, 4, 5, 1) => ("TLBI RVAE2OS", true),
+        (9, 4, 5, 1) => ("TLBI RVAE2OSNXS", true),
+        (8, 6, 6, 1) => ("TLBI RVAE3", true),
+        (8, 6, 2, 1) => ("TLBI RVAE3IS", true),
+        (9, 6, 2, 1) => ("TLBI RVAE3ISNXS", true),
+        (9, 6, 6, 1) => ("TLBI RVAE3NXS", true),
+        (8, 6, 5, 1) => ("TLBI RVAE3OS", true),
+        (9, 6, 5, 1) => ("TLBI RVAE3OSNXS", true),
+        (8, 0, 6, 5) => ("TLBI RVALE1", true),
+        (8, 0, 2, 5) => ("TLBI RVALE1IS", true),
+        (9, 0, 2, 5) => ("TLBI RVALE1ISNXS", true),
+        (9, 0, 6, 5) => ("TLBI RVALE1NXS", true),
+        (8, 0, 5, 5) => ("TLBI RVALE1OS", true),
+        (9, 0, 5, 5) => ("TLBI RVALE1OSNXS", true),
+        (8, 4, 6, 5) => ("TLBI RVALE2", true),
+        (8, 4, 2, 5) => ("TLBI RVALE2IS", true),
+        (9, 4, 2, 5) => ("TLBI RVALE2ISNXS", true),
+        (9, 4, 6, 5) => ("TLBI RVALE2NXS", true),
+        (8, 4, 5, 5) => ("TLBI RVALE2OS", true),
+        (9, 4, 5, 5) => ("TLBI RVALE2OSNXS", true),
+        (8, 6, 6, 5) => ("TLBI RVALE3", true),
+        (8, 6, 2, 5) => ("TLBI RVALE3IS", true),
+        (9, 6, 2, 5) => ("TLBI RVALE3ISNXS", true),
+        (9, 6, 6, 5) => ("TLBI RVALE3NXS", true),
+        (8, 6, 5, 5) => ("TLBI RVALE3OS", true),
+        (9, 6, 5, 5) => ("TLBI RVALE3OSNXS", true),
+        (8, 0, 7, 3) => ("TLBI VAAE1", true),
+        (8, 0, 3, 3) => ("TLBI VAAE1IS", true),
+        (9, 0, 3, 3) => ("TLBI VAAE1ISNXS", true),
+        (9, 0, 7, 3) => ("TLBI VAAE1NXS", true),
+        (8, 0, 1, 3) => ("TLBI VAAE1OS", true),
+        (9, 0, 1, 3) => ("TLBI VAAE1OSNXS", true),
+        (8, 0, 7, 7) => ("TLBI VAALE1", true),
+        (8, 0, 3, 7) => ("TLBI VAALE1IS", true),
+        (9, 0, 3, 7) => ("TLBI VAALE1ISNXS", true),
+        (9, 0, 7, 7) => ("TLBI VAALE1NXS", true),
+        (8, 0, 1, 7) => ("TLBI VAALE1OS", true),
+        (9, 0, 1, 7) => ("TLBI VAALE1OSNXS", true),
+        (8, 0, 7, 1) => ("TLBI VAE1", true),
+        (8, 0, 3, 1) => ("TLBI VAE1IS", true),
+        (9, 0, 3, 1) => ("TLBI VAE1ISNXS", true),
+        (9, 0, 7, 1) => ("TLBI VAE1NXS", true),
+        (8, 0, 1, 1) => ("TLBI VAE1OS", true),
+        (9, 0, 1, 1) => ("TLBI VAE1OSNXS", true),
+        (8, 4, 7, 1) => ("TLBI VAE2", true),
+        (8, 4, 3, 1) => ("TLBI VAE2IS", true),
+        (9, 4, 3, 1) => ("TLBI VAE2ISNXS", true),
+        (9, 4, 7, 1) => ("TLBI VAE2NXS", true),
+        (8, 4, 1, 1) => ("TLBI VAE2OS", true),
+        (9, 4, 1, 1) => ("TLBI VAE2OSNXS", true),
+        (8, 6, 7, 1) => ("TLBI VAE3", true),
+        (8, 6, 3, 1) => ("TLBI VAE3IS", true),
+        (9, 6, 3, 1) => ("TLBI VAE3ISNXS", true),
+        (9, 6, 7, 1) => ("TLBI VAE3NXS", true),
+        (8, 6, 1, 1) => ("TLBI VAE3OS", true),
+        (9, 6, 1, 1) => ("TLBI VAE3OSNXS", true),
+        (8, 0, 7, 5) => ("TLBI VALE1", true),
+        (8, 0, 3, 5) => ("TLBI VALE1IS", true),
+        (9, 0, 3, 5) => ("TLBI VALE1ISNXS", true),
+        (9, 0, 7, 5) => ("TLBI VALE1NXS", true),
+        (8, 0, 1, 5) => ("TLBI VALE1OS", true),
+        (9, 0, 1, 5) => ("TLBI VALE1OSNXS", true),
+        (8, 4, 7, 5) => ("TLBI VALE2", true),
+        (8, 4, 3, 5) => ("TLBI VALE2IS", true),
+        (9, 4, 3, 5) => ("TLBI VALE2ISNXS", true),
+        (9, 4, 7, 5) => ("TLBI VALE2NXS", true),
+        (8, 4, 1, 5) => ("TLBI VALE2OS", true),
+        (9, 4, 1, 5) => ("TLBI VALE2OSNXS", true),
+        (8, 6, 7, 5) => ("TLBI VALE3", true),
+        (8, 6, 3, 5) => ("TLBI VALE3IS", true),
+        (9, 6, 3, 5) => ("TLBI VALE3ISNXS", true),
+        (9, 6, 7, 5) => ("TLBI VALE3NXS", true),
+        (8, 6, 1, 5) => ("TLBI VALE3OS", true),
+        (9, 6, 1, 5) => ("TLBI VALE3OSNXS", true),
+        (8, 0, 7, 0) => ("TLBI VMALLE1", false),
+        (8, 0, 3, 0) => ("TLBI VMALLE1IS", false),
+        (9, 0, 3, 0) => ("TLBI VMALLE1ISNXS", false),
+        (9, 0, 7, 0) => ("TLBI VMALLE1NXS", false),
+        (8, 0, 1, 0) => ("TLBI VMALLE1OS", false),
+        (9, 0, 1, 0) => ("TLBI VMALLE1OSNXS", false),
+        (8, 4, 7, 6) => ("TLBI VMALLS12E1", false),
+        (8, 4, 3, 6) => ("TLBI VMALLS12E1IS", false),
+        (9, 4, 3, 6) => ("TLBI VMALLS12E1ISNXS", false),
+        (9, 4, 7, 6) => ("TLBI VMALLS12E1NXS", false),
+        (8, 4, 1, 6) => ("TLBI VMALLS12E1OS", false),
+        (9, 4, 1, 6) => ("TLBI VMALLS12E1OSNXS", false),
+        _ => return None,
+    })
+}
+
+/// Returns the name of the 128-bit AArch64 system register with the given encoding which is read by
+/// MRRS if `read` is true or written by MSRR otherwise, if there is one.
+pub fn sysreg128_name(
+    op0: u64,
+    op1: u64,
+    op2: u64,
+    crn: u64,
+    crm: u64,
+    read: bool,
+) -> Option<&'static str> {
+    Some(match (op0, crn, op1, crm, op2, read) {
+        (3, 7, 0, 4, 0, _) => "PAR_EL1",
+        (3, 13, 0, 0, 6, _) => "RCWMASK_EL1",
+        (3, 13, 0, 0, 3, _) => "RCWSMASK_EL1",
+        (3, 2, 0, 0, 0, _) => "TTBR0_EL1",
+        (3, 2, 5, 0, 0, _) => "TTBR0_EL12",
+        (3, 2, 4, 0, 0, _) => "TTBR0_EL2",
+        (3, 2, 0, 0, 1, _) => "TTBR1_EL1",
+        (3, 2, 5, 0, 1, _) => "TTBR1_EL12",
+        (3, 2, 4, 0, 1, _) => "TTBR1_EL2",
+        (3, 2, 4, 1, 0, _) => "VTTBR_EL2",
+        _ => return None,
+    })
+}
+
+/// Returns the name of the SYSP instruction with the given encoding and op0 1, such as
+/// `TLBIP VAE1IS`, if there is one.
+pub fn sysp_instruction_name(op1: u64, op2: u64, crn: u64, crm: u64) -> Option<&'static str> {
+    Some(match (crn, op1, crm, op2) {
+        (8, 4, 4, 1) => "TLBIP IPAS2E1",
+        (8, 4, 0, 1) => "TLBIP IPAS2E1IS",
+        (9, 4, 0, 1) => "TLBIP IPAS2E1ISNXS",
+        (9, 4, 4, 1) => "TLBIP IPAS2E1NXS",
+        (8, 4, 4, 0) => "TLBIP IPAS2E1OS",
+        (9, 4, 4, 0) => "TLBIP IPAS2E1OSNXS",
+        (8, 4, 4, 5) => "TLBIP IPAS2LE1",
+        (8, 4, 0, 5) => "TLBIP IPAS2LE1IS",
+        (9, 4, 0, 5) => "TLBIP IPAS2LE1ISNXS",
+        (9, 4, 4, 5) => "TLBIP IPAS2LE1NXS",
+        (8, 4, 4, 4) => "TLBIP IPAS2LE1OS",
+        (9, 4, 4, 4) => "TLBIP IPAS2LE1OSNXS",
+        (8, 4, 4, 2) => "TLBIP RIPAS2E1",
+        (8, 4, 0, 2) => "TLBIP RIPAS2E1IS",
+        (9, 4, 0, 2) => "TLBIP RIPAS2E1ISNXS",
+        (9, 4, 4, 2) => "TLBIP RIPAS2E1NXS",
+        (8, 4, 4, 3) => "TLBIP RIPAS2E1OS",
+        (9, 4, 4, 3) => "TLBIP RIPAS2E1OSNXS",
+        (8, 4, 4, 6) => "TLBIP RIPAS2LE1",
+        (8, 4, 0, 6) => "TLBIP RIPAS2LE1IS",
+        (9, 4, 0, 6) => "TLBIP RIPAS2LE1ISNXS",
+        (9, 4, 4, 6) => "TLBIP RIPAS2LE1NXS",
+        (8, 4, 4, 7) => "TLBIP RIPAS2LE1OS",
+        (9, 4, 4, 7) => "TLBIP RIPAS2LE1OSNXS",
+        (8, 0, 6, 3) => "TLBIP RVAAE1",
+        (8, 0, 2, 3) => "TLBIP RVAAE1IS",
+        (9, 0, 2, 3) => "TLBIP RVAAE1ISNXS",
+        (9, 0, 6, 3) => "TLBIP RVAAE1NXS",
+        (8, 0, 5, 3) => "TLBIP RVAAE1OS",
+        (9, 0, 5, 3) => "TLBIP RVAAE1OSNXS",
+        (8, 0, 6, 7) => "TLBIP RVAALE1",
+        (8, 0, 2, 7) => "TLBIP RVAALE1IS",
+        (9, 0, 2, 7) => "TLBIP RVAALE1ISNXS",
+        (9, 0, 6, 7) => "TLBIP RVAALE1NXS",
+        (8, 0, 5, 7) => "TLBIP RVAALE1OS",
+        (9, 0, 5, 7) => "TLBIP RVAALE1OSNXS",
+        (8, 0, 6, 1) => "TLBIP RVAE1",
+        (8, 0, 2, 1) => "TLBIP RVAE1IS",
+        (9, 0, 2, 1) => "TLBIP RVAE1ISNXS",
+        (9, 0, 6, 1) => "TLBIP RVAE1NXS",
+        (8, 0, 5, 1) => "TLBIP RVAE1OS",
+        (9, 0, 5, 1) => "TLBIP RVAE1OSNXS",
+        (8, 4, 6, 1) => "TLBIP RVAE2",
+        (8, 4, 2, 1) => "TLBIP RVAE2IS",
+        (9, 4, 2, 1) => "TLBIP RVAE2ISNXS",
+        (9, 4, 6, 1) => "TLBIP RVAE2NXS",
+        (8, 4, 5, 1) => "TLBIP RVAE2OS",
+        (9, 4, 5, 1) => "TLBIP RVAE2OSNXS",
+        (8, 6, 6, 1) => "TLBIP RVAE3",
+        (8, 6, 2, 1) => "TLBIP RVAE3IS",
+        (9, 6, 2, 1) => "TLBIP RVAE3ISNXS",
+        (9, 6, 6, 1) => "TLBIP RVAE3NXS",
+        (8, 6, 5, 1) => "TLBIP RVAE3OS",
+        (9, 6, 5, 1) => "TLBIP RVAE3OSNXS",
+        (8, 0, 6, 5) => "TLBIP RVALE1",
+        (8, 0, 2, 5) => "TLBIP RVALE1IS",
+        (9, 0, 2, 5) => "TLBIP RVALE1ISNXS",
+        (9, 0, 6, 5) => "TLBIP RVALE1NXS",
+        (8, 0, 5, 5) => "TLBIP RVALE1OS",
+        (9, 0, 5, 5) => "TLBIP RVALE1OSNXS",
+        (8, 4, 6, 5) => "TLBIP RVALE2",
+        (8, 4, 2, 5) => "TLBIP RVALE2IS",
+        (9, 4, 2, 5) => "TLBIP RVALE2ISNXS",
+        (9, 4, 6, 5) => "TLBIP RVALE2NXS",
+        (8, 4, 5, 5) => "TLBIP RVALE2OS",
+        (9, 4, 5, 5) => "TLBIP RVALE2OSNXS",
+        (8, 6, 6, 5) => "TLBIP RVALE3",
+        (8, 6, 2, 5) => "TLBIP RVALE3IS",
+        (9, 6, 2, 5) => "TLBIP RVALE3ISNXS",
+        (9, 6, 6, 5) => "TLBIP RVALE3NXS",
+        (8, 6, 5, 5) => "TLBIP RVALE3OS",
+        (9, 6, 5, 5) => "TLBIP RVALE3OSNXS",
+        (8, 0, 7, 3) => "TLBIP VAAE1",
+        (8, 0, 3, 3) => "TLBIP VAAE1IS",
+        (9, 0, 3, 3) => "TLBIP VAAE1ISNXS",
+        (9, 0, 7, 3) => "TLBIP VAAE1NXS",
+        (8, 0, 1, 3) => "TLBIP VAAE1OS",
+        (9, 0, 1, 3) => "TLBIP VAAE1OSNXS",
+        (8, 0, 7, 7) => "TLBIP VAALE1",
+        (8, 0, 3, 7) => "TLBIP VAALE1IS",
+        (9, 0, 3, 7) => "TLBIP VAALE1ISNXS",
+        (9, 0, 7, 7) => "TLBIP VAALE1NXS",
+        (8, 0, 1, 7) => "TLBIP VAALE1OS",
+        (9, 0, 1, 7) => "TLBIP VAALE1OSNXS",
+        (8, 0, 7, 1) => "TLBIP VAE1",
+        (8, 0, 3, 1) => "TLBIP VAE1IS",
+        (9, 0, 3, 1) => "TLBIP VAE1ISNXS",
+        (9, 0, 7, 1) => "TLBIP VAE1NXS",
+        (8, 0, 1, 1) => "TLBIP VAE1OS",
+        (9, 0, 1, 1) => "TLBIP VAE1OSNXS",
+        (8, 4, 7, 1) => "TLBIP VAE2",
+        (8, 4, 3, 1) => "TLBIP VAE2IS",
+        (9, 4, 3, 1) => "TLBIP VAE2ISNXS",
+        (9, 4, 7, 1) => "TLBIP VAE2NXS",
+        (8, 4, 1, 1) => "TLBIP VAE2OS",
+        (9, 4, 1, 1) => "TLBIP VAE2OSNXS",
+        (8, 6, 7, 1) => "TLBIP VAE3",
+        (8, 6, 3, 1) => "TLBIP VAE3IS",
+        (9, 6, 3, 1) => "TLBIP VAE3ISNXS",
+        (9, 6, 7, 1) => "TLBIP VAE3NXS",
+        (8, 6, 1, 1) => "TLBIP VAE3OS",
+        (9, 6, 1, 1) => "TLBIP VAE3OSNXS",
+        (8, 0, 7, 5) => "TLBIP VALE1",
+        (8, 0, 3, 5) => "TLBIP VALE1IS",
+        (9, 0, 3, 5) => "TLBIP VALE1ISNXS",
+        (9, 0, 7, 5) => "TLBIP VALE1NXS",
+        (8, 0, 1, 5) => "TLBIP VALE1OS",
+        (9, 0, 1, 5) => "TLBIP VALE1OSNXS",
+        (8, 4, 7, 5) => "TLBIP VALE2",
+        (8, 4, 3, 5) => "TLBIP VALE2IS",
+        (9, 4, 3, 5) => "TLBIP VALE2ISNXS",
+        (9, 4, 7, 5) => "TLBIP VALE2NXS",
+        (8, 4, 1, 5) => "TLBIP VALE2OS",
+        (9, 4, 1, 5) => "TLBIP VALE2OSNXS",
+        (8, 6, 7, 5) => "TLBIP VALE3",
+        (8, 6, 3, 5) => "TLBIP VALE3IS",
+        (9, 6, 3, 5) => "TLBIP VALE3ISNXS",
+        (9, 6, 7, 5) => "TLBIP VALE3NXS",
+        (8, 6, 1, 5) => "TLBIP VALE3OS",
+        (9, 6, 1, 5) => "TLBIP VALE3OSNXS",
+        _ => return None,
+    })
+}
+
+/// Returns the name of the 32-bit AArch32 System register or System instruction with the given
+/// encoding which is read by MRC if `read` is true or written by MCR otherwise, if there is one.
+pub fn coproc_name(
+    coproc: u64,
+    opc1: u64,
+    crn: u64,
+    crm: u64,
+    opc2: u64,
+    read: bool,
+) -> Option<&'static str> {
+    Some(match (coproc, crn, opc1, crm, opc2, read) {
+        (15, 1, 0, 0, 1, _) => "ACTLR",
+        (15, 1, 0, 0, 3, _) => "ACTLR2",
+        (15, 5, 0, 1, 0, _) => "ADFSR",
+        (15, 0, 1, 0, 7, _) => "AIDR",
+        (15, 5, 0, 1, 1, _) => "AIFSR",
+        (15, 10, 0, 3, 0, _) => "AMAIR0",
+        (15, 10, 0, 3, 1, _) => "AMAIR1",
+        (15, 7, 0, 8, 4, _) => "ATS12NSOPR",
+        (15, 7, 0, 8, 5, _) => "ATS12NSOPW",
+        (15, 7, 0, 8, 6, _) => "ATS12NSOUR",
+        (15, 7, 0, 8, 7, _) => "ATS12NSOUW",
+        (15, 7, 0, 8, 0, _) => "ATS1CPR",
+        (15, 7, 0, 9, 0, _) => "ATS1CPRP",
+        (15, 7, 0, 8, 1, _) => "ATS1CPW",
+        (15, 7, 0, 9, 1, _) => "ATS1CPWP",
+        (15, 7, 0, 8, 2, _) => "ATS1CUR",
+        (15, 7, 0, 8, 3, _) => "ATS1CUW",
+        (15, 7, 4, 8, 0, _) => "ATS1HR",
+        (15, 7, 4, 8, 1, _) => "ATS1HW",
+        (15, 7, 0, 5, 6, _) => "BPIALL",
+        (15, 7, 0, 1, 6, _) => "BPIALLIS",
+        (15, 7, 0, 5, 7, _) => "BPIMVA",
+        (15, 0, 1, 0, 0, _) => "CCSIDR",
+        (15, 0, 1, 0, 2, _) => "CCSIDR2",
+        (15, 7, 0, 3, 4, _) => "CFPRCTX",
+        (15, 0, 1, 0, 1, _) => "CLIDR",
+        (15, 14, 0, 0, 0, _) => "CNTFRQ",
+        (15, 14, 4, 1, 0, _) => "CNTHCTL",
+        (15, 14, 4, 2, 1, _) => "CNTHP_CTL",
+        (15, 14, 4, 2, 0, _) => "CNTHP_TVAL",
+        (15, 14, 0, 1, 0, _) => "CNTKCTL",
+        (15, 14, 0, 2, 1, _) => "CNTP_CTL",
+        (15, 14, 0, 2, 0, _) => "CNTP_TVAL",
+        (15, 14, 0, 3, 1, _) => "CNTV_CTL",
+        (15, 14, 0, 3, 0, _) => "CNTV_TVAL",
+        (15, 13, 0, 0, 1, _) => "CONTEXTIDR",
+        (15, 7, 0, 10, 5, _) => "CP15DMB",
+        (15, 7, 0, 10, 4, _) => "CP15DSB",
+        (15, 7, 0, 5, 4, _) => "CP15ISB",
+        (15, 1, 0, 0, 2, _) => "CPACR",
+        (15, 7, 0, 3, 7, _) => "CPPRCTX",
+        (15, 0, 2, 0, 0, _) => "CSSELR",
+        (15, 0, 0, 0, 1, _) => "CTR",
+        (15, 3, 0, 0, 0, _) => "DACR",
+        (14, 7, 0, 14, 6, _) => "DBGAUTHSTATUS",
+        (14, 0, 0, 0, 5, _) => "DBGBCR0",
+        (14, 0, 0, 1, 5, _) => "DBGBCR1",
+        (14, 0, 0, 10, 5, _) => "DBGBCR10",
+        (14, 0, 0, 11, 5, _) => "DBGBCR11",
+        (14, 0, 0, 12, 5, _) => "DBGBCR12",
+        (14, 0, 0, 13, 5, _) => "DBGBCR13",
+        (14, 0, 0, 14, 5, _) => "DBGBCR14",
+        (14, 0, 0, 15, 5, _) => "DBGBCR15",
+        (14, 0, 0, 2, 5, _) => "DBGBCR2",
+        (14, 0, 0, 3, 5, _) => "DBGBCR3",
+        (14, 0, 0, 4, 5, _) => "DBGBCR4",
+        (14, 0, 0, 5, 5, _) => "DBGBCR5",
+        (14, 0, 0, 6, 5, _) => "DBGBCR6",
+        (14, 0, 0, 7, 5, _) => "DBGBCR7",
+        (14, 0, 0, 8, 5, _) => "DBGBCR8",
+        (14, 0, 0, 9, 5, _) => "DBGBCR9",
+        (14, 0, 0, 0, 4, _) => "DBGBVR0",
+        (14, 0, 0, 1, 4, _) => "DBGBVR1",
+        (14, 0, 0, 10, 4, _) => "DBGBVR10",
+        (14, 0, 0, 11, 4, _) => "DBGBVR11",
+        (14, 0, 0, 12, 4, _) => "DBGBVR12",
+        (14, 0, 0, 13, 4, _) => "DBGBVR13",
+        (14, 0, 0, 14, 4, _) => "DBGBVR14",
+        (14, 0, 0, 15, 4, _) => "DBGBVR15",
+        (14, 0, 0, 2, 4, _) => "DBGBVR2",
+        (14, 0, 0, 3, 4, _) => "DBGBVR3",
+        (14, 0, 0, 4, 4, _) => "DBGBVR4",
+        (14, 0, 0, 5, 4, _) => "DBGBVR5",
+        (14, 0, 0, 6, 4, _) => "DBGBVR6",
+        (14, 0, 0, 7, 4, _) => "DBGBVR7",
+        (14, 0, 0, 8, 4, _) => "DBGBVR8",
+        (14, 0, 0, 9, 4, _) => "DBGBVR9",
+        (14, 1, 0, 0, 1, _) => "DBGBXVR0",
+        (14, 1, 0, 1, 1, _) => "DBGBXVR1",
+        (14, 1, 0, 10, 1, _) => "DBGBXVR10",
+        (14, 1, 0, 11, 1, _) => "DBGBXVR11",
+        (14, 1, 0, 12, 1, _) => "DBGBXVR12",
+        (14, 1, 0, 13, 1, _) => "DBGBXVR13",
+        (14, 1, 0, 14, 1, _) => "DBGBXVR14",
+        (14, 1, 0, 15, 1, _) => "DBGBXVR15",
+        (14, 1, 0, 2, 1, _) => "DBGBXVR2",
+        (14, 1, 0, 3, 1, _) => "DBGBXVR3",
+        (14, 1, 0, 4, 1, _) => "DBGBXVR4",
+        (14, 1, 0, 5, 1, _) => "DBGBXVR5",
+        (14, 1, 0, 6, 1, _) => "DBGBXVR6",
+        (14, 1, 0, 7, 1, _) => "DBGBXVR7",
+        (14, 1, 0, 8, 1, _) => "DBGBXVR8",
+        (14, 1, 0, 9, 1, _) => "DBGBXVR9",
+        (14, 7, 0, 9, 6, _) => "DBGCLAIMCLR",
+        (14, 7, 0, 8, 6, _) => "DBGCLAIMSET",
+        (14, 0, 0, 2, 0, _) => "DBGDCCINT",
+        (14, 7, 0, 2, 7, _) => "DBGDEVID",
+        (14, 7, 0, 1, 7, _) => "DBGDEVID1",
+        (14, 7, 0, 0, 7, _) => "DBGDEVID2",
+        (14, 0, 0, 0, 0, _) => "DBGDIDR",
+        (14, 1, 0, 0, 0, _) => "DBGDRAR",
+        (14, 2, 0, 0, 0, _) => "DBGDSAR",
+        (14, 0, 0, 2, 2, _) => "DBGDSCRext",
+        (14, 0, 0, 1, 0, _) => "DBGDSCRint",
+        (14, 0, 0, 0, 2, _) => "DBGDTRRXext",
+        (14, 0, 0, 5, 0, true) => "DBGDTRRXint",
+        (14, 0, 0, 3, 2, _) => "DBGDTRTXext",
+        (14, 0, 0, 5, 0, false) => "DBGDTRTXint",
+        (14, 1, 0, 3, 4, _) => "DBGOSDLR",
+        (14, 0, 0, 6, 2, _) => "DBGOSECCR",
+        (14, 1, 0, 0, 4, _) => "DBGOSLAR",
+        (14, 1, 0, 1, 4, _) => "DBGOSLSR",
+        (14, 1, 0, 4, 4, _) => "DBGPRCR",
+        (14, 0, 0, 7, 0, _) => "DBGVCR",
+        (14, 0, 0, 0, 7, _) => "DBGWCR0",
+        (14, 0, 0, 1, 7, _) => "DBGWCR1",
+        (14, 0, 0, 10, 7, _) => "DBGWCR10",
+        (14, 0, 0, 11, 7, _) => "DBGWCR11",
+        (14, 0, 0, 12, 7, _) => "DBGWCR12",
+        (14, 0, 0, 13, 7, _) => "DBGWCR13",
+        (14, 0, 0, 14, 7, _) => "DBGWCR14",
+        (14, 0, 0, 15, 7, _) => "DBGWCR15",
+        (14, 0, 0, 2, 7, _) => "DBGWCR2",
+        (14, 0, 0, 3, 7, _) => "DBGWCR3",
+        (14, 0, 0, 4, 7, _) => "DBGWCR4",
+        (14, 0, 0, 5, 7, _) => "DBGWCR5",
+        (14, 0, 0, 6, 7, _) => "DBGWCR6",
+        (14, 0, 0, 7, 7, _) => "DBGWCR7",
+        (14, 0, 0, 8, 7, _) => "DBGWCR8",
+        (14, 0, 0, 9, 7, _) => "DBGWCR9",
+        (14, 0, 0, 6, 0, _) => "DBGWFAR",
+        (14, 0, 0, 0, 6, _) => "DBGWVR0",
+        (14, 0, 0, 1, 6, _) => "DBGWVR1",
+        (14, 0, 0, 10, 6, _) => "DBGWVR10",
+        (14, 0, 0, 11, 6, _) => "DBGWVR11",
+        (14, 0, 0, 12, 6, _) => "DBGWVR12",
+        (14, 0, 0, 13, 6, _) => "DBGWVR13",
+        (14, 0, 0, 14, 6, _) => "DBGWVR14",
+        (14, 0, 0, 15, 6, _) => "DBGWVR15",
+        (14, 0, 0, 2, 6, _) => "DBGWVR2",
+        (14, 0, 0, 3, 6, _) => "DBGWVR3",
+        (14, 0, 0, 4, 6, _) => "DBGWVR4",
+        (14, 0, 0, 5, 6, _) => "DBGWVR5",
+        (14, 0, 0, 6, 6, _) => "DBGWVR6",
+        (14, 0, 0, 7, 6, _) => "DBGWVR7",
+        (14, 0, 0, 8, 6, _) => "DBGWVR8",
+        (14, 0, 0, 9, 6, _) => "DBGWVR9",
+        (15, 7, 0, 14, 1, _) => "DCCIMVAC",
+        (15, 7, 0, 14, 2, _) => "DCCISW",
+        (15, 7, 0, 10, 1, _) => "DCCMVAC",
+        (15, 7, 0, 11, 1, _) => "DCCMVAU",
+        (15, 7, 0, 10, 2, _) => "DCCSW",
+        (15, 7, 0, 6, 1, _) => "DCIMVAC",
+        (15, 7, 0, 6, 2, _) => "DCISW",
+        (15, 6, 0, 0, 0, _) => "DFAR",
+        (15, 5, 0, 0, 0, _) => "DFSR",
+        (15, 12, 0, 1, 1, _) => "DISR",
+        (15, 8, 0, 6, 0, _) => "DTLBIALL",
+        (15, 8, 0, 6, 2, _) => "DTLBIASID",
+        (15, 8, 0, 6, 1, _) => "DTLBIMVA",
+        (15, 7, 0, 3, 5, _) => "DVPRCTX",
+        (15, 5, 0, 3, 0, _) => "ERRIDR",
+        (15, 5, 0, 3, 1, _) => "ERRSELR",
+        (15, 5, 0, 4, 3, _) => "ERXADDR",
+        (15, 5, 0, 4, 1, _) => "ERXCTLR",
+        (15, 5, 0, 4, 0, _) => "ERXFR",
+        (15, 5, 0, 4, 2, _) => "ERXSTATUS",
+        (15, 13, 0, 0, 0, _) => "FCSEIDR",
+        (15, 1, 4, 1, 7, _) => "HACR",
+        (15, 1, 4, 0, 1, _) => "HACTLR",
+        (15, 1, 4, 0, 3, _) => "HACTLR2",
+        (15, 5, 4, 1, 0, _) => "HADFSR",
+        (15, 5, 4, 1, 1, _) => "HAIFSR",
+        (15, 10, 4, 3, 0, _) => "HAMAIR0",
+        (15, 10, 4, 3, 1, _) => "HAMAIR1",
+        (15, 1, 4, 1, 2, _) => "HCPTR",
+        (15, 1, 4, 1, 0, _) => "HCR",
+        (15, 1, 4, 1, 4, _) => "HCR2",
+        (15, 1, 4, 1, 1, _) => "HDCR",
+        (15, 6, 4, 0, 0, _) => "HDFAR",
+        (15, 6, 4, 0, 2, _) => "HIFAR",
+        (15, 10, 4, 2, 0, _) => "HMAIR0",
+        (15, 10, 4, 2, 1, _) => "HMAIR1",
+        (15, 6, 4, 0, 4, _) => "HPFAR",
+        (15, 12, 4, 0, 2, _) => "HRMR",
+        (15, 1, 4, 0, 0, _) => "HSCTLR",
+        (15, 5, 4, 2, 0, _) => "HSR",
+        (15, 1, 4, 1, 3, _) => "HSTR",
+        (15, 2, 4, 0, 2, _) => "HTCR",
+        (15, 13, 4, 0, 2, _) => "HTPIDR",
+        (15, 1, 4, 2, 1, _) => "HTRFCR",
+        (15, 12, 4, 0, 0, _) => "HVBAR",
+        (15, 12, 0, 8, 3, _) => "ICC_BPR0",
+        (15, 12, 0, 12, 3, _) => "ICC_BPR1",
+        (15, 12, 0, 12, 4, _) => "ICC_CTLR",
+        (15, 12, 0, 11, 1, _) => "ICC_DIR",
+        (15, 12, 0, 8, 1, _) => "ICC_EOIR0",
+        (15, 12, 0, 12, 1, _) => "ICC_EOIR1",
+        (15, 12, 0, 8, 2, _) => "ICC_HPPIR0",
+        (15, 12, 0, 12, 2, _) => "ICC_HPPIR1",
+        (15, 12, 4, 9, 5, _) => "ICC_HSRE",
+        (15, 12, 0, 8, 0, _) => "ICC_IAR0",
+        (15, 12, 0, 12, 0, _) => "ICC_IAR1",
+        (15, 12, 0, 12, 6, _) => "ICC_IGRPEN0",
+        (15, 12, 0, 12, 7, _) => "ICC_IGRPEN1",
+        (15, 12, 6, 12, 4, _) => "ICC_MCTLR",
+        (15, 12, 6, 12, 7, _) => "ICC_MGRPEN1",
+        (15, 12, 6, 12, 5, _) => "ICC_MSRE",
+        (15, 4, 0, 6, 0, _) => "ICC_PMR",
+        (15, 12, 0, 11, 3, _) => "ICC_RPR",
+        (15, 12, 0, 12, 5, _) => "ICC_SRE",
+        (15, 7, 0, 5, 0, _) => "ICIALLU",
+        (15, 7, 0, 1, 0, _) => "ICIALLUIS",
+        (15, 7, 0, 5, 1, _) => "ICIMVAU",
+        (15, 0, 0, 1, 3, _) => "ID_AFR0",
+        (15, 0, 0, 1, 2, _) => "ID_DFR0",
+        (15, 0, 0, 3, 5, _) => "ID_DFR1",
+        (15, 0, 0, 2, 0, _) => "ID_ISAR0",
+        (15, 0, 0, 2, 1, _) => "ID_ISAR1",
+        (15, 0, 0, 2, 2, _) => "ID_ISAR2",
+        (15, 0, 0, 2, 3, _) => "ID_ISAR3",
+        (15, 0, 0, 2, 4, _) => "ID_ISAR4",
+        (15, 0, 0, 2, 5, _) => "ID_ISAR5",
+        (15, 0, 0, 2, 7, _) => "ID_ISAR6",
+        (15, 0, 0, 1, 4, _) => "ID_MMFR0",
+        (15, 0, 0, 1, 5, _) => "ID_MMFR1",
+        (15, 0, 0, 1, 6, _) => "ID_MMFR2",
+        (15, 0, 0, 1, 7, _) => "ID_MMFR3",
+        (15, 0, 0, 2, 6, _) => "ID_MMFR4",
+        (15, 0, 0, 3, 6, _) => "ID_MMFR5",
+        (15, 0, 0, 1, 0, _) => "ID_PFR0",
+        (15, 0, 0, 1, 1, _) => "ID_PFR1",
+        (15, 0, 0, 3, 4, _) => "ID_PFR2",
+        (15, 6, 0, 0, 2, _) => "IFAR",
+        (15, 5, 0, 0, 1, _) => "IFSR",
+        (15, 12, 0, 1, 0, _) => "ISR",
+        (15, 8, 0, 5, 0, _) => "ITLBIALL",
+        (15, 8, 0, 5, 2, _) => "ITLBIASID",
+        (15, 8, 0, 5, 1, _) => "ITLBIMVA",
+        (14, 0, 7, 0, 0, _) => "JIDR",
+        (14, 2, 7, 0, 0, _) => "JMCR",
+        (14, 1, 7, 0, 0, _) => "JOSCR",
+        (15, 10, 0, 2, 0, _) => "MAIR0",
+        (15, 10, 0, 2, 1, _) => "MAIR1",
+        (15, 0, 0, 0, 0, _) => "MIDR",
+        (15, 0, 0, 0, 5, _) => "MPIDR",
+        (15, 12, 0, 0, 1, _) => "MVBAR",
+        (15, 1, 0, 1, 2, _) => "NSACR",
+        (15, 7, 0, 4, 0, _) => "PAR",
+        (15, 14, 0, 15, 7, _) => "PMCCFILTR",
+        (15, 9, 0, 13, 0, _) => "PMCCNTR",
+        (15, 9, 0, 12, 6, _) => "PMCEID0",
+        (15, 9, 0, 12, 7, _) => "PMCEID1",
+        (15, 9, 0, 14, 4, _) => "PMCEID2",
+        (15, 9, 0, 14, 5, _) => "PMCEID3",
+        (15, 9, 0, 12, 2, _) => "PMCNTENCLR",
+        (15, 9, 0, 12, 1, _) => "PMCNTENSET",
+        (15, 9, 0, 12, 0, _) => "PMCR",
+        (15, 14, 0, 8, 0, _) => "PMEVCNTR0",
+        (15, 14, 0, 8, 1, _) => "PMEVCNTR1",
+        (15, 14, 0, 9, 2, _) => "PMEVCNTR10",
+        (15, 14, 0, 9, 3, _) => "PMEVCNTR11",
+        (15, 14, 0, 9, 4, _) => "PMEVCNTR12",
+        (15, 14, 0, 9, 5, _) => "PMEVCNTR13",
+        (15, 14, 0, 9, 6, _) => "PMEVCNTR14",
+        (15, 14, 0, 9, 7, _) => "PMEVCNTR15",
+        (15, 14, 0, 10, 0, _) => "PMEVCNTR16",
+        (15, 14, 0, 10, 1, _) => "PMEVCNTR17",
+        (15, 14, 0, 10, 2, _) => "PMEVCNTR18",
+        (15, 14, 0, 10, 3, _) => "PMEVCNTR19",
+        (15, 14, 0, 8, 2, _) => "PMEVCNTR2",
+        (15, 14, 0, 10, 4, _) => "PMEVCNTR20",
+        (15, 14, 0, 10, 5, _) => "PMEVCNTR21",
+        (15, 14, 0, 10, 6, _) => "PMEVCNTR22",
+        (15, 14, 0, 10, 7, _) => "PMEVCNTR23",
+        (15, 14, 0, 11, 0, _) => "PMEVCNTR24",
+        (15, 14, 0, 11, 1, _) => "PMEVCNTR25",
+        (15, 14, 0, 11, 2, _) => "PMEVCNTR26",
+        (15, 14, 0, 11, 3, _) => "PMEVCNTR27",
+        (15, 14, 0, 11, 4, _) => "PMEVCNTR28",
+        (15, 14, 0, 11, 5, _) => "PMEVCNTR29",
+        (15, 14, 0, 8, 3, _) => "PMEVCNTR3",
+        (15, 14, 0, 11, 6, _) => "PMEVCNTR30",
+        (15, 14, 0, 8, 4, _) => "PMEVCNTR4",
+        (15, 14, 0, 8, 5, _) => "PMEVCNTR5",
+        (15, 14, 0, 8, 6, _) => "PMEVCNTR6",
+        (15, 14, 0, 8, 7, _) => "PMEVCNTR7",
+        (15, 14, 0, 9, 0, _) => "PMEVCNTR8",
+        (15, 14, 0, 9, 1, _) => "PMEVCNTR9",
+        (15, 14, 0, 12, 0, _) => "PMEVTYPER0",
+        (15, 14, 0, 12, 1, _) => "PMEVTYPER1",
+        (15, 14, 0, 13, 2, _) => "PMEVTYPER10",
+        (15, 14, 0, 13, 3, _) => "PMEVTYPER11",
+        (15, 14, 0, 13, 4, _) => "PMEVTYPER12",
+        (15, 14, 0, 13, 5, _) => "PMEVTYPER13",
+        (15, 14, 0, 13, 6, _) => "PMEVTYPER14",
+        (15, 14, 0, 13, 7, _) => "PMEVTYPER15",
+        (15, 14, 0, 14, 0, _) => "PMEVTYPER16",
+        (15, 14, 0, 14, 1, _) => "PMEVTYPER17",
+        (15, 14, 0, 14, 2, _) => "PMEVTYPER18",
+        (15, 14, 0, 14, 3, _) => "PMEVTYPER19",
+        (15, 14, 0, 12, 2, _) => "PMEVTYPER2",
+        (15, 14, 0, 14, 4, _) => "PMEVTYPER20",
+        (15, 14, 0, 14, 5, _) => "PMEVTYPER21",
+        (15, 14, 0, 14, 6, _) => "PMEVTYPER22",
+        (15, 14, 0, 14, 7, _) => "PMEVTYPER23",
+        (15, 14, 0, 15, 0, _) => "PMEVTYPER24",
+        (15, 14, 0, 15, 1, _) => "PMEVTYPER25",
+        (15, 14, 0, 15, 2, _) => "PMEVTYPER26",
+        (15, 14, 0, 15, 3, _) => "PMEVTYPER27",
+        (15, 14, 0, 15, 4, _) => "PMEVTYPER28",
+        (15, 14, 0, 15, 5, _) => "PMEVTYPER29",
+        (15, 14, 0, 12, 3, _) => "PMEVTYPER3",
+        (15, 14, 0, 15, 6, _) => "PMEVTYPER30",
+        (15, 14, 0, 12, 4, _) => "PMEVTYPER4",
+        (15, 14, 0, 12, 5, _) => "PMEVTYPER5",
+        (15, 14, 0, 12, 6, _) => "PMEVTYPER6",
+        (15, 14, 0, 12, 7, _) => "PMEVTYPER7",
+        (15, 14, 0, 13, 0, _) => "PMEVTYPER8",
+        (15, 14, 0, 13, 1, _) => "PMEVTYPER9",
+        (15, 9, 0, 14, 2, _) => "PMINTENCLR",
+        (15, 9, 0, 14, 1, _) => "PMINTENSET",
+        (15, 9, 0, 14, 6, _) => "PMMIR",
+        (15, 9, 0, 12, 3, _) => "PMOVSR",
+        (15, 9, 0, 14, 3, _) => "PMOVSSET",
+        (15, 9, 0, 12, 5, _) => "PMSELR",
+        (15, 9, 0, 12, 4, _) => "PMSWINC",
+        (15, 9, 0, 14, 0, _) => "PMUSERENR",
+        (15, 9, 0, 13, 2, _) => "PMXEVCNTR",
+        (15, 9, 0, 13, 1, _) => "PMXEVTYPER",
+        (15, 0, 0, 0, 6, _) => "REVIDR",
+        (15, 12, 0, 0, 2, _) => "RMR",
+        (15, 1, 0, 1, 0, _) => "SCR",
+        (15, 1, 0, 0, 0, _) => "SCTLR",
+        (15, 1, 0, 1, 1, _) => "SDER",
+        (15, 0, 0, 0, 2, _) => "TCMTR",
+        (14, 0, 6, 0, 0, _) => "TEECR",
+        (14, 1, 6, 0, 0, _) => "TEEHBR",
+        (15, 8, 0, 7, 0, _) => "TLBIALL",
+        (15, 8, 4, 7, 0, _) => "TLBIALLH",
+        (15, 8, 4, 3, 0, _) => "TLBIALLHIS",
+        (15, 8, 0, 3, 0, _) => "TLBIALLIS",
+        (15, 8, 4, 7, 4, _) => "TLBIALLNSNH",
+        (15, 8, 4, 3, 4, _) => "TLBIALLNSNHIS",
+        (15, 8, 0, 7, 2, _) => "TLBIASID",
+        (15, 8, 0, 3, 2, _) => "TLBIASIDIS",
+        (15, 8, 4, 4, 1, _) => "TLBIIPAS2",
+        (15, 8, 4, 0, 1, _) => "TLBIIPAS2IS",
+        (15, 8, 4, 4, 5, _) => "TLBIIPAS2L",
+        (15, 8, 4, 0, 5, _) => "TLBIIPAS2LIS",
+        (15, 8, 0, 7, 1, _) => "TLBIMVA",
+        (15, 8, 0, 7, 3, _) => "TLBIMVAA",
+        (15, 8, 0, 3, 3, _) => "TLBIMVAAIS",
+        (15, 8, 0, 7, 7, _) => "TLBIMVAAL",
+        (15, 8, 0, 3, 7, _) => "TLBIMVAALIS",
+        (15, 8, 4, 7, 1, _) => "TLBIMVAH",
+        (15, 8, 4, 3, 1, _) => "TLBIMVAHIS",
+        (15, 8, 0, 3, 1, _) => "TLBIMVAIS",
+        (15, 8, 0, 7, 5, _) => "TLBIMVAL",
+        (15, 8, 4, 7, 5, _) => "TLBIMVALH",
+        (15, 8, 4, 3, 5, _) => "TLBIMVALHIS",
+        (15, 8, 0, 3, 5, _) => "TLBIMVALIS",
+        (15, 0, 0, 0, 3, _) => "TLBTR",
+        (15, 13, 0, 0, 4, _) => "TPIDRPRW",
+        (15, 13, 0, 0, 3, _) => "TPIDRURO",
+        (15, 13, 0, 0, 2, _) => "TPIDRURW",
+        (15, 1, 0, 2, 1, _) => "TRFCR",
+        (15, 2, 0, 0, 2, _) => "TTBCR",
+        (15, 2, 0, 0, 3, _) => "TTBCR2",
+        (15, 2, 0, 0, 0, _) => "TTBR0",
+        (15, 2, 0, 0, 1, _) => "TTBR1",
+        (15, 12, 0, 0, 0, _) => "VBAR",
+        (15, 12, 4, 1, 1, _) => "VDISR",
+        (15, 0, 4, 0, 5, _) => "VMPIDR",
+        (15, 0, 4, 0, 0, _) => "VPIDR",
+        (15, 2, 4, 1, 2, _) => "VTCR",
+        _ => return None,
+    })
+}
+
+/// Returns the name of the 64-bit AArch32 System register with the given encoding for MCRR or
+/// MRRC, if there is one.
+pub fn coproc64_name(coproc: u64, opc1: u64, crm: u64) -> Option<&'static str> {
+    Some(match (coproc, opc1, crm) {
+        (15, 6, 14) => "CNTHP_CVAL",
+        (15, 0, 14) => "CNTPCT",
+        (15, 8, 14) => "CNTPCTSS",
+        (15, 2, 14) => "CNTP_CVAL",
+        (15, 1, 14) => "CNTVCT",
+        (15, 9, 14) => "CNTVCTSS",
+        (15, 4, 14) => "CNTVOFF",
+        (15, 3, 14) => "CNTV_CVAL",
+        (14, 0, 1) => "DBGDRAR",
+        (14, 0, 2) => "DBGDSAR",
+        (15, 4, 2) => "HTTBR",
+        (15, 1, 12) => "ICC_ASGI1R",
+        (15, 2, 12) => "ICC_SGI0R",
+        (15, 0, 12) => "ICC_SGI1R",
+        (15, 0, 7) => "PAR",
+        (15, 0, 2) => "TTBR0",
+        (15, 1, 2) => "TTBR1",
+        (15, 6, 2) => "VTTBR",
+        _ => return None,
+    })
+}
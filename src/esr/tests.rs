@@ -1,5 +1,14 @@
-use super::decode;
-use crate::FieldInfo;
+use super::{
+    DataAbortIss, DecodeOptions, Esr, EsrBuilder, EsrRegister, ExceptionClass, ExceptionLevel,
+    FaultStatusCode, Features, IdRegisters, InstructionAbortIss, SyndromeAccessSize, SysRegTrapIss,
+    WatchpointIss, decode, decode_static, decode_static_with_options, decode_with_options,
+};
+use crate::{
+    DecodeError, EncodeError, FieldError, FieldInfo, FieldPath, FieldTree, FormatOptions, get_field,
+};
+use alloc::{format, string::ToString, vec, vec::Vec};
+use bit_field::BitField;
+use core::ops::Range;
 
 #[test]
 fn unknown() {
@@ -10,19 +19,21 @@ fn unknown() {
             FieldInfo {
                 name: "RES0",
                 long_name: Some("Reserved"),
-                start: 37,
-                width: 27,
+                start: 56,
+                width: 8,
                 value: 0,
                 description: None,
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
                 name: "ISS2",
                 long_name: None,
                 start: 32,
-                width: 5,
+                width: 24,
                 value: 0,
                 description: None,
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
@@ -32,6 +43,7 @@ fn unknown() {
                 width: 6,
                 value: 0,
                 description: Some("Unknown reason".to_string()),
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
@@ -41,6 +53,7 @@ fn unknown() {
                 width: 1,
                 value: 0,
                 description: Some("16-bit instruction trapped".to_string()),
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
@@ -50,6 +63,7 @@ fn unknown() {
                 width: 25,
                 value: 0,
                 description: None,
+                diagnostic: None,
                 subfields: vec![FieldInfo {
                     name: "RES0",
                     long_name: Some("Reserved"),
@@ -57,6 +71,7 @@ fn unknown() {
                     width: 25,
                     value: 0,
                     description: Some("ISS is RES0".to_string()),
+                    diagnostic: None,
                     subfields: vec![],
                 }],
             },
@@ -72,20 +87,125 @@ fn data_abort() {
             FieldInfo {
                 name: "RES0",
                 long_name: Some("Reserved"),
-                start: 37,
-                width: 27,
+                start: 56,
+                width: 8,
                 value: 0,
                 description: None,
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
                 name: "ISS2",
                 long_name: None,
                 start: 32,
-                width: 5,
+                width: 24,
                 value: 0,
                 description: None,
-                subfields: vec![],
+                diagnostic: None,
+                subfields: vec![
+                    FieldInfo {
+                        name: "RES0",
+                        long_name: Some("Reserved"),
+                        start: 13,
+                        width: 11,
+                        value: 0,
+                        description: None,
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "SASV",
+                        long_name: Some("SAS Valid"),
+                        start: 12,
+                        width: 1,
+                        value: 0,
+                        description: Some("SAS is only valid if ISV is true".to_string()),
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "RES0",
+                        long_name: Some("Reserved"),
+                        start: 11,
+                        width: 1,
+                        value: 0,
+                        description: None,
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "TnD",
+                        long_name: Some("Tag not Data"),
+                        start: 10,
+                        width: 1,
+                        value: 0,
+                        description: Some("Fault not on an Allocation Tag access".to_string()),
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "RES0",
+                        long_name: Some("Reserved for this DFSC value"),
+                        start: 9,
+                        width: 1,
+                        value: 0,
+                        description: None,
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "GCS",
+                        long_name: Some("Guarded Control Stack data access"),
+                        start: 8,
+                        width: 1,
+                        value: 0,
+                        description: Some(
+                            "Fault not caused by a Guarded Control Stack data access".to_string()
+                        ),
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "RES0",
+                        long_name: Some("Reserved for this DFSC value"),
+                        start: 7,
+                        width: 1,
+                        value: 0,
+                        description: None,
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "RES0",
+                        long_name: Some("Reserved for this DFSC value"),
+                        start: 6,
+                        width: 1,
+                        value: 0,
+                        description: None,
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "RES0",
+                        long_name: Some("Reserved for this DFSC value"),
+                        start: 5,
+                        width: 1,
+                        value: 0,
+                        description: None,
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "Xs",
+                        long_name: Some("Status register for ST64BV or ST64BV0"),
+                        start: 0,
+                        width: 5,
+                        value: 0,
+                        description: None,
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                ],
             },
             FieldInfo {
                 name: "EC",
@@ -96,6 +216,7 @@ fn data_abort() {
                 description: Some(
                     "Data Abort taken without a change in Exception level".to_string()
                 ),
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
@@ -105,6 +226,7 @@ fn data_abort() {
                 width: 1,
                 value: 1,
                 description: Some("32-bit instruction trapped".to_string()),
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
@@ -114,6 +236,7 @@ fn data_abort() {
                 width: 25,
                 value: 80,
                 description: None,
+                diagnostic: None,
                 subfields: vec![
                     FieldInfo {
                         name: "ISV",
@@ -122,24 +245,59 @@ fn data_abort() {
                         width: 1,
                         value: 0,
                         description: Some("No valid instruction syndrome".to_string()),
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
                         name: "RES0",
                         long_name: Some("Reserved"),
-                        start: 14,
-                        width: 10,
+                        start: 18,
+                        width: 6,
+                        value: 0,
+                        description: None,
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "WU",
+                        long_name: Some("Write Update"),
+                        start: 16,
+                        width: 2,
                         value: 0,
                         description: None,
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "FnP",
+                        long_name: Some("FAR not Precise"),
+                        start: 15,
+                        width: 1,
+                        value: 0,
+                        description: Some(
+                            "FAR holds the faulting virtual address, if FnV is false".to_string()
+                        ),
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "PFV",
+                        long_name: Some("PFAR Valid"),
+                        start: 14,
+                        width: 1,
+                        value: 0,
+                        description: Some("PFAR is not valid".to_string()),
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
                         name: "VNCR",
-                        long_name: None,
+                        long_name: Some("VNCR_EL2 access"),
                         start: 13,
                         width: 1,
                         value: 0,
-                        description: None,
+                        description: Some("Fault not generated by the use of VNCR_EL2".to_string()),
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -149,6 +307,7 @@ fn data_abort() {
                         width: 2,
                         value: 0,
                         description: Some("Recoverable state (UER)".to_string()),
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -158,6 +317,7 @@ fn data_abort() {
                         width: 1,
                         value: 0,
                         description: Some("FAR is valid".to_string()),
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -167,6 +327,7 @@ fn data_abort() {
                         width: 1,
                         value: 0,
                         description: None,
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -176,6 +337,7 @@ fn data_abort() {
                         width: 1,
                         value: 0,
                         description: None,
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -185,6 +347,7 @@ fn data_abort() {
                         width: 1,
                         value: 0,
                         description: None,
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -194,6 +357,7 @@ fn data_abort() {
                         width: 1,
                         value: 1,
                         description: Some("Abort caused by writing to memory".to_string()),
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -207,6 +371,7 @@ fn data_abort() {
                                          walk or hardware update of translation table."
                                 .to_string()
                         ),
+                        diagnostic: None,
                         subfields: vec![],
                     }
                 ]
@@ -223,20 +388,125 @@ fn data_abort_isv() {
             FieldInfo {
                 name: "RES0",
                 long_name: Some("Reserved"),
-                start: 37,
-                width: 27,
+                start: 56,
+                width: 8,
                 value: 0,
                 description: None,
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
                 name: "ISS2",
                 long_name: None,
                 start: 32,
-                width: 5,
+                width: 24,
                 value: 0,
                 description: None,
-                subfields: vec![],
+                diagnostic: None,
+                subfields: vec![
+                    FieldInfo {
+                        name: "RES0",
+                        long_name: Some("Reserved"),
+                        start: 13,
+                        width: 11,
+                        value: 0,
+                        description: None,
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "SASV",
+                        long_name: Some("SAS Valid"),
+                        start: 12,
+                        width: 1,
+                        value: 0,
+                        description: Some("SAS is only valid if ISV is true".to_string()),
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "RES0",
+                        long_name: Some("Reserved"),
+                        start: 11,
+                        width: 1,
+                        value: 0,
+                        description: None,
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "TnD",
+                        long_name: Some("Tag not Data"),
+                        start: 10,
+                        width: 1,
+                        value: 0,
+                        description: Some("Fault not on an Allocation Tag access".to_string()),
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "RES0",
+                        long_name: Some("Reserved for this DFSC value"),
+                        start: 9,
+                        width: 1,
+                        value: 0,
+                        description: None,
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "GCS",
+                        long_name: Some("Guarded Control Stack data access"),
+                        start: 8,
+                        width: 1,
+                        value: 0,
+                        description: Some(
+                            "Fault not caused by a Guarded Control Stack data access".to_string()
+                        ),
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "RES0",
+                        long_name: Some("Reserved for this DFSC value"),
+                        start: 7,
+                        width: 1,
+                        value: 0,
+                        description: None,
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "RES0",
+                        long_name: Some("Reserved for this DFSC value"),
+                        start: 6,
+                        width: 1,
+                        value: 0,
+                        description: None,
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "RES0",
+                        long_name: Some("Reserved for this DFSC value"),
+                        start: 5,
+                        width: 1,
+                        value: 0,
+                        description: None,
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                    FieldInfo {
+                        name: "Xs",
+                        long_name: Some("Status register for ST64BV or ST64BV0"),
+                        start: 0,
+                        width: 5,
+                        value: 0,
+                        description: None,
+                        diagnostic: None,
+                        subfields: vec![],
+                    },
+                ],
             },
             FieldInfo {
                 name: "EC",
@@ -247,6 +517,7 @@ fn data_abort_isv() {
                 description: Some(
                     "Data Abort taken without a change in Exception level".to_string()
                 ),
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
@@ -256,6 +527,7 @@ fn data_abort_isv() {
                 width: 1,
                 value: 1,
                 description: Some("32-bit instruction trapped".to_string()),
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
@@ -265,6 +537,7 @@ fn data_abort_isv() {
                 width: 25,
                 value: 22163536,
                 description: None,
+                diagnostic: None,
                 subfields: vec![
                     FieldInfo {
                         name: "ISV",
@@ -273,6 +546,7 @@ fn data_abort_isv() {
                         width: 1,
                         value: 1,
                         description: Some("Valid instruction syndrome".to_string()),
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -282,6 +556,7 @@ fn data_abort_isv() {
                         width: 2,
                         value: 1,
                         description: Some("halfword".to_string()),
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -291,6 +566,7 @@ fn data_abort_isv() {
                         width: 1,
                         value: 0,
                         description: None,
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -300,6 +576,7 @@ fn data_abort_isv() {
                         width: 5,
                         value: 18,
                         description: None,
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -309,6 +586,7 @@ fn data_abort_isv() {
                         width: 1,
                         value: 0,
                         description: Some("32-bit wide register".to_string()),
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -318,15 +596,21 @@ fn data_abort_isv() {
                         width: 1,
                         value: 0,
                         description: Some("No acquire/release semantics".to_string()),
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
                         name: "VNCR",
-                        long_name: None,
+                        long_name: Some("VNCR_EL2 access"),
                         start: 13,
                         width: 1,
                         value: 1,
-                        description: None,
+                        description: Some(
+                            "Fault came from an access to the VNCR_EL2 page rather than the guest's \
+                             own memory"
+                                .to_string()
+                        ),
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -336,6 +620,7 @@ fn data_abort_isv() {
                         width: 2,
                         value: 2,
                         description: Some("Uncontainable (UC)".to_string()),
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -345,6 +630,7 @@ fn data_abort_isv() {
                         width: 1,
                         value: 0,
                         description: Some("FAR is valid".to_string()),
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -354,6 +640,7 @@ fn data_abort_isv() {
                         width: 1,
                         value: 0,
                         description: None,
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -363,6 +650,7 @@ fn data_abort_isv() {
                         width: 1,
                         value: 0,
                         description: None,
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -372,6 +660,7 @@ fn data_abort_isv() {
                         width: 1,
                         value: 0,
                         description: None,
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -381,6 +670,7 @@ fn data_abort_isv() {
                         width: 1,
                         value: 1,
                         description: Some("Abort caused by writing to memory".to_string()),
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -394,6 +684,7 @@ fn data_abort_isv() {
                                          walk or hardware update of translation table."
                                 .to_string()
                         ),
+                        diagnostic: None,
                         subfields: vec![],
                     }
                 ]
@@ -410,19 +701,21 @@ fn instruction_abort() {
             FieldInfo {
                 name: "RES0",
                 long_name: Some("Reserved"),
-                start: 37,
-                width: 27,
+                start: 56,
+                width: 8,
                 value: 0,
                 description: None,
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
                 name: "ISS2",
                 long_name: None,
                 start: 32,
-                width: 5,
+                width: 24,
                 value: 0,
                 description: None,
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
@@ -432,6 +725,7 @@ fn instruction_abort() {
                 width: 6,
                 value: 32,
                 description: Some("Instruction Abort from a lower Exception level".to_string()),
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
@@ -441,6 +735,7 @@ fn instruction_abort() {
                 width: 1,
                 value: 1,
                 description: Some("32-bit instruction trapped".to_string()),
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
@@ -450,6 +745,7 @@ fn instruction_abort() {
                 width: 25,
                 value: 7696,
                 description: None,
+                diagnostic: None,
                 subfields: vec![
                     FieldInfo {
                         name: "RES0",
@@ -458,6 +754,7 @@ fn instruction_abort() {
                         width: 12,
                         value: 0,
                         description: None,
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -467,6 +764,7 @@ fn instruction_abort() {
                         width: 2,
                         value: 3,
                         description: Some("Restartable state (UEO)".to_string()),
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -478,6 +776,7 @@ fn instruction_abort() {
                         description: Some(
                             "FAR is not valid, it holds an unknown value".to_string()
                         ),
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -487,6 +786,7 @@ fn instruction_abort() {
                         width: 1,
                         value: 1,
                         description: None,
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -496,6 +796,7 @@ fn instruction_abort() {
                         width: 1,
                         value: 0,
                         description: None,
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -505,6 +806,7 @@ fn instruction_abort() {
                         width: 1,
                         value: 0,
                         description: None,
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -514,6 +816,7 @@ fn instruction_abort() {
                         width: 1,
                         value: 0,
                         description: None,
+                        diagnostic: None,
                         subfields: vec![],
                     },
                     FieldInfo {
@@ -527,6 +830,7 @@ fn instruction_abort() {
                                          walk or hardware update of translation table."
                                 .to_string()
                         ),
+                        diagnostic: None,
                         subfields: vec![],
                     }
                 ]
@@ -543,19 +847,21 @@ fn sve() {
             FieldInfo {
                 name: "RES0",
                 long_name: Some("Reserved"),
-                start: 37,
-                width: 27,
+                start: 56,
+                width: 8,
                 value: 0,
                 description: None,
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
                 name: "ISS2",
                 long_name: None,
                 start: 32,
-                width: 5,
+                width: 24,
                 value: 0,
                 description: None,
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
@@ -567,6 +873,7 @@ fn sve() {
                 description: Some(
                     "Trapped access to SVE, Advanced SIMD or floating point".to_string()
                 ),
+                diagnostic: None,
                 subfields: vec![]
             },
             FieldInfo {
@@ -576,6 +883,7 @@ fn sve() {
                 width: 1,
                 value: 1,
                 description: Some("32-bit instruction trapped".to_string()),
+                diagnostic: None,
                 subfields: vec![]
             },
             FieldInfo {
@@ -585,6 +893,7 @@ fn sve() {
                 width: 25,
                 value: 19922944,
                 description: None,
+                diagnostic: None,
                 subfields: vec![
                     FieldInfo {
                         name: "CV",
@@ -593,6 +902,7 @@ fn sve() {
                         width: 1,
                         value: 1,
                         description: Some("COND is valid".to_string()),
+                        diagnostic: None,
                         subfields: vec![]
                     },
                     FieldInfo {
@@ -601,7 +911,8 @@ fn sve() {
                         start: 20,
                         width: 4,
                         value: 3,
-                        description: None,
+                        description: Some("CC/LO (carry clear, unsigned lower)".to_string()),
+                        diagnostic: None,
                         subfields: vec![]
                     },
                     FieldInfo {
@@ -611,6 +922,7 @@ fn sve() {
                         width: 20,
                         value: 0,
                         description: None,
+                        diagnostic: None,
                         subfields: vec![]
                     }
                 ]
@@ -627,19 +939,21 @@ fn ld64b() {
             FieldInfo {
                 name: "RES0",
                 long_name: Some("Reserved"),
-                start: 37,
-                width: 27,
+                start: 56,
+                width: 8,
                 value: 0,
                 description: None,
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
                 name: "ISS2",
                 long_name: None,
                 start: 32,
-                width: 5,
+                width: 24,
                 value: 0,
                 description: None,
+                diagnostic: None,
                 subfields: vec![],
             },
             FieldInfo {
@@ -652,6 +966,7 @@ fn ld64b() {
                     "Trapped execution of an LD64B, ST64B, ST64BV, or ST64BV0 instruction"
                         .to_string()
                 ),
+                diagnostic: None,
                 subfields: vec![]
             },
             FieldInfo {
@@ -661,6 +976,7 @@ fn ld64b() {
                 width: 1,
                 value: 1,
                 description: Some("32-bit instruction trapped".to_string()),
+                diagnostic: None,
                 subfields: vec![]
             },
             FieldInfo {
@@ -670,6 +986,7 @@ fn ld64b() {
                 width: 25,
                 value: 2,
                 description: None,
+                diagnostic: None,
                 subfields: vec![FieldInfo {
                     name: "ISS",
                     long_name: None,
@@ -677,9 +994,1422 @@ fn ld64b() {
                     width: 25,
                     value: 2,
                     description: Some("LD64B or ST64B trapped".to_string()),
+                    diagnostic: None,
                     subfields: vec![]
                 }]
             }
         ]
     );
 }
+
+#[test]
+fn sme() {
+    assert_eq!(
+        decode(0x76000003).unwrap(),
+        vec![
+            FieldInfo {
+                name: "RES0",
+                long_name: Some("Reserved"),
+                start: 56,
+                width: 8,
+                value: 0,
+                description: None,
+                diagnostic: None,
+                subfields: vec![],
+            },
+            FieldInfo {
+                name: "ISS2",
+                long_name: None,
+                start: 32,
+                width: 24,
+                value: 0,
+                description: None,
+                diagnostic: None,
+                subfields: vec![],
+            },
+            FieldInfo {
+                name: "EC",
+                long_name: Some("Exception Class"),
+                start: 26,
+                width: 6,
+                value: 29,
+                description: Some(
+                    "Access to SME functionality trapped as a result of CPACR_EL1.SMEN, \
+                     CPTR_EL2.SMEN, CPTR_EL2.TSM, CPTR_EL3.ESM, or an instruction that is \
+                     illegal because of the value of PSTATE.SM or PSTATE.ZA"
+                        .to_string()
+                ),
+                diagnostic: None,
+                subfields: vec![]
+            },
+            FieldInfo {
+                name: "IL",
+                long_name: Some("Instruction Length"),
+                start: 25,
+                width: 1,
+                value: 1,
+                description: Some("32-bit instruction trapped".to_string()),
+                diagnostic: None,
+                subfields: vec![]
+            },
+            FieldInfo {
+                name: "ISS",
+                long_name: Some("Instruction Specific Syndrome"),
+                start: 0,
+                width: 25,
+                value: 3,
+                description: None,
+                diagnostic: None,
+                subfields: vec![
+                    FieldInfo {
+                        name: "RES0",
+                        long_name: Some("Reserved"),
+                        start: 3,
+                        width: 22,
+                        value: 0,
+                        description: None,
+                        diagnostic: None,
+                        subfields: vec![]
+                    },
+                    FieldInfo {
+                        name: "SMTC",
+                        long_name: Some("SME Trap Code"),
+                        start: 0,
+                        width: 3,
+                        value: 3,
+                        description: Some(
+                            "SME instruction trapped because PSTATE.ZA is 0 (ZA storage disabled)"
+                                .to_string()
+                        ),
+                        diagnostic: None,
+                        subfields: vec![]
+                    }
+                ]
+            },
+        ]
+    );
+}
+
+#[test]
+fn granule_protection_check() {
+    let decoded = decode(0x7a014068).unwrap();
+    assert_eq!(
+        decoded[2].description,
+        Some("Granule Protection Check exception".to_string())
+    );
+    assert_eq!(
+        decoded[4].subfields,
+        vec![
+            FieldInfo {
+                name: "RES0",
+                long_name: Some("Reserved"),
+                start: 22,
+                width: 3,
+                value: 0,
+                description: None,
+                diagnostic: None,
+                subfields: vec![]
+            },
+            FieldInfo {
+                name: "S2PTW",
+                long_name: Some("Stage 2 translation table walk"),
+                start: 21,
+                width: 1,
+                value: 0,
+                description: Some("Fault not on a stage 2 translation table walk".to_string()),
+                diagnostic: None,
+                subfields: vec![]
+            },
+            FieldInfo {
+                name: "InD",
+                long_name: Some("Instruction not Data"),
+                start: 20,
+                width: 1,
+                value: 0,
+                description: Some("Data access".to_string()),
+                diagnostic: None,
+                subfields: vec![]
+            },
+            FieldInfo {
+                name: "GPCSC",
+                long_name: Some("Granule Protection Check Status Code"),
+                start: 14,
+                width: 6,
+                value: 5,
+                description: Some(
+                    "GPT walk fault, level 1 of the Granule Protection Table".to_string()
+                ),
+                diagnostic: None,
+                subfields: vec![]
+            },
+            FieldInfo {
+                name: "VNCR",
+                long_name: Some("VNCR_EL2 access"),
+                start: 13,
+                width: 1,
+                value: 0,
+                description: Some("Fault not generated by the use of VNCR_EL2".to_string()),
+                diagnostic: None,
+                subfields: vec![]
+            },
+            FieldInfo {
+                name: "RES0",
+                long_name: Some("Reserved"),
+                start: 9,
+                width: 4,
+                value: 0,
+                description: None,
+                diagnostic: None,
+                subfields: vec![]
+            },
+            FieldInfo {
+                name: "CM",
+                long_name: Some("Cache Maintenance"),
+                start: 8,
+                width: 1,
+                value: 0,
+                description: None,
+                diagnostic: None,
+                subfields: vec![]
+            },
+            FieldInfo {
+                name: "S1PTW",
+                long_name: Some("Stage-1 translation table walk"),
+                start: 7,
+                width: 1,
+                value: 0,
+                description: None,
+                diagnostic: None,
+                subfields: vec![]
+            },
+            FieldInfo {
+                name: "WnR",
+                long_name: Some("Write not Read"),
+                start: 6,
+                width: 1,
+                value: 1,
+                description: Some("Abort caused by writing to memory".to_string()),
+                diagnostic: None,
+                subfields: vec![]
+            },
+            FieldInfo {
+                name: "xFSC",
+                long_name: Some("Instruction or Data Fault Status Code"),
+                start: 0,
+                width: 6,
+                value: 0x28,
+                description: Some(
+                    "Granule Protection Fault, not on translation table walk or hardware update \
+                     of translation table."
+                        .to_string()
+                ),
+                diagnostic: None,
+                subfields: vec![]
+            },
+        ]
+    );
+}
+
+#[test]
+fn mops() {
+    let decoded = decode(0x9e560022).unwrap();
+    assert_eq!(
+        decoded[2].description,
+        Some("Memory Copy or Memory Set exception".to_string())
+    );
+    assert_eq!(
+        decoded[4].description,
+        Some("CPY[F]ERTRN [x0]!, [x1]!, x2!".to_string())
+    );
+    assert_eq!(
+        decoded[4].subfields[4],
+        FieldInfo {
+            name: "wrong_option",
+            long_name: None,
+            start: 17,
+            width: 1,
+            value: 1,
+            description: Some(
+                "The instruction was executed with the wrong algorithm option for this PE"
+                    .to_string()
+            ),
+            diagnostic: None,
+            subfields: vec![]
+        }
+    );
+
+    let decoded = decode(0x9f880c85).unwrap();
+    assert_eq!(
+        decoded[4].description,
+        Some("SETGMT [x3]!, x5!, x4".to_string())
+    );
+}
+
+#[test]
+fn gcs() {
+    let decoded = decode(0xb6000021).unwrap();
+    assert_eq!(
+        decoded[2].description,
+        Some("Guarded Control Stack exception".to_string())
+    );
+    assert_eq!(
+        decoded[4].description,
+        Some("Return address mismatch on GCS data check for GCSPOPM x1".to_string())
+    );
+
+    let decoded = decode(0x10096000050).unwrap();
+    assert_eq!(decoded[1].subfields[5].name, "GCS");
+    assert_eq!(decoded[1].subfields[5].value, 1);
+}
+
+#[test]
+fn gcs_unknown_ex_type() {
+    let esr = 0xb6300abc;
+    assert!(matches!(
+        decode(esr),
+        Err(FieldError {
+            error: DecodeError::InvalidExType { ex_type: 0b0011 },
+            ..
+        })
+    ));
+    assert!(decode_static(esr).is_err());
+
+    let options = DecodeOptions {
+        lenient: true,
+        ..Default::default()
+    };
+    let decoded = decode_static_with_options(esr, &options).unwrap();
+    assert_eq!(
+        decoded.iss_subfields[1].diagnostic,
+        Some(DecodeError::InvalidExType { ex_type: 0b0011 })
+    );
+    let iss = decoded.iss_subfields.last().unwrap();
+    assert_eq!(
+        (iss.name, iss.start, iss.width, iss.value),
+        ("ISS", 0, 15, 0xabc)
+    );
+    assert!(decoded.to_string().contains("! Invalid GCS ExType 0x3\n"));
+
+    let decoded = decode_with_options(esr, &options).unwrap();
+    assert_eq!(decoded[4].subfields.len(), 4);
+    assert_eq!(decoded[4].description, None);
+}
+
+#[test]
+fn data_abort_overlay_permission_fault() {
+    let decoded = decode(0x409600004f).unwrap();
+    assert_eq!(
+        decoded[1].description,
+        Some("Permission fault due to Overlay permissions".to_string())
+    );
+    assert_eq!(
+        decoded[1].subfields[7],
+        FieldInfo {
+            name: "Overlay",
+            long_name: Some("Overlay permission fault"),
+            start: 6,
+            width: 1,
+            value: 1,
+            description: Some("Permission fault caused by Overlay permissions".to_string()),
+            diagnostic: None,
+            subfields: vec![],
+        }
+    );
+
+    // The permission fault fields are RES0 for other DFSC values.
+    assert!(decode(0x4096000050).is_err());
+}
+
+#[test]
+fn data_abort_ld64b_unsupported() {
+    let decoded = decode(0x96001835).unwrap();
+    assert_eq!(
+        decoded[4].subfields[6],
+        FieldInfo {
+            name: "LST",
+            long_name: Some("Load/Store Type"),
+            start: 11,
+            width: 2,
+            value: 3,
+            description: Some("An ST64BV0 instruction generated the Data Abort".to_string()),
+            diagnostic: None,
+            subfields: vec![],
+        }
+    );
+}
+
+#[test]
+fn data_abort_write_update() {
+    let decoded = decode(0x96010050).unwrap();
+    let wu = get_field(&decoded, "ISS.WU").unwrap();
+    assert_eq!((wu.start, wu.width, wu.value), (16, 2, 0b01));
+    assert_eq!(Esr(0x96010050).data_abort().unwrap().wu(), Some(0b01));
+
+    // WU is part of SRT if ISV is set.
+    assert_eq!(Esr(0x97010050).data_abort().unwrap().wu(), None);
+    assert_eq!(get_field(&decode(0x97010050).unwrap(), "ISS.WU"), None);
+}
+
+#[test]
+fn data_abort_sas_valid_without_isv() {
+    // SAS is RES0 if neither ISV nor ISS2.SASV is set.
+    assert!(matches!(
+        decode(0x96c00050),
+        Err(FieldError {
+            error: DecodeError::InvalidRes0 { res0: 0b110000 },
+            ..
+        })
+    ));
+
+    let esr = 0x1000_96c0_0050;
+    let decoded = decode(esr).unwrap();
+    assert_eq!(get_field(&decoded, "ISS2.SASV").unwrap().value, 1);
+    let sas = get_field(&decoded, "ISS.SAS").unwrap();
+    assert_eq!((sas.start, sas.width, sas.value), (22, 2, 0b11));
+    assert_eq!(sas.description, Some("doubleword".to_string()));
+    let data_abort = Esr(esr).data_abort().unwrap();
+    assert!(!data_abort.isv());
+    assert!(data_abort.sasv());
+    assert_eq!(data_abort.sas(), Some(SyndromeAccessSize::Doubleword));
+    assert_eq!(data_abort.srt(), None);
+}
+
+#[test]
+fn eret() {
+    let decoded = decode(0x6a000003).unwrap();
+    assert_eq!(
+        decoded[2].description,
+        Some("Trapped ERET, ERETAA or ERETAB instruction execution".to_string())
+    );
+    assert_eq!(decoded[4].description, Some("ERETAB".to_string()));
+}
+
+#[test]
+fn msrr() {
+    let decoded = decode(0x52300841).unwrap();
+    assert_eq!(
+        decoded[2].description,
+        Some("Trapped MSRR, MRRS or SYSP instruction execution in AArch64 state".to_string())
+    );
+    assert_eq!(
+        decoded[4].description,
+        Some("MRRS x2, x3, TTBR0_EL1".to_string())
+    );
+
+    for (esr, description) in [
+        (0x52301c08, "MSRR PAR_EL1, x0, x1"),
+        (0x52122086, "TLBIP VAE1IS, x4, x5"),
+        (0x52122544, "TLBIP RVAE1ISNXS, x10, x11"),
+        (0x521ffc1e, "SYSP #7, C15, C15, #7, x0, x1"),
+    ] {
+        let decoded = decode(esr).unwrap();
+        assert_eq!(
+            get_field(&decoded, "ISS").unwrap().description.as_deref(),
+            Some(description),
+            "{esr:#x}"
+        );
+    }
+    assert_eq!(
+        get_field(&decode(0x52122086).unwrap(), "ISS.Direction")
+            .unwrap()
+            .description
+            .as_deref(),
+        Some("System instruction with a register pair (SYSP)")
+    );
+}
+
+#[test]
+fn smc_aarch32() {
+    let decoded = decode(0x4e080000).unwrap();
+    assert_eq!(
+        decoded[2].description,
+        Some("SMC instruction execution in AArch32 state".to_string())
+    );
+    assert_eq!(
+        decoded[4].subfields[2],
+        FieldInfo {
+            name: "CCKNOWNPASS",
+            long_name: Some("Condition code check known to have passed"),
+            start: 19,
+            width: 1,
+            value: 1,
+            description: Some(
+                "The instruction was unconditional, or was conditional and passed its condition \
+                 code check"
+                    .to_string()
+            ),
+            diagnostic: None,
+            subfields: vec![],
+        }
+    );
+}
+
+#[test]
+fn tstart() {
+    let decoded = decode(0x6e0000e0).unwrap();
+    assert_eq!(decoded[4].description, Some("TSTART x7".to_string()));
+}
+
+#[test]
+fn pac_trap() {
+    let decoded = decode(0x26000000).unwrap();
+    assert_eq!(
+        decoded[2].description,
+        Some(
+            "Trapped use of a Pointer authentication instruction because of HCR_EL2.API or \
+             SCR_EL3.API"
+                .to_string()
+        )
+    );
+    assert_eq!(decoded[4].subfields.len(), 1);
+    assert_eq!(
+        decoded[4].subfields[0].description,
+        Some("ISS is RES0".to_string())
+    );
+
+    let error = decode(0x26000100).unwrap_err();
+    assert_eq!(error.ec, Some(ExceptionClass::PacTrap));
+    assert_eq!(error.field.to_string(), "ISS.RES0[0..25]");
+    assert_eq!(error.error, DecodeError::InvalidRes0 { res0: 0x100 });
+}
+
+#[test]
+fn pmu() {
+    let decoded = decode(0xf6000001).unwrap();
+    assert_eq!(decoded[2].description, Some("PMU exception".to_string()));
+    assert_eq!(
+        decoded[4].subfields[1],
+        FieldInfo {
+            name: "SYNC",
+            long_name: Some("Synchronous PMU exception"),
+            start: 0,
+            width: 1,
+            value: 1,
+            description: Some("The PMU exception was taken synchronously".to_string()),
+            diagnostic: None,
+            subfields: vec![],
+        }
+    );
+    assert_eq!(
+        decode(0xf6000000).unwrap()[4].subfields[1].description,
+        Some("The PMU exception was taken asynchronously".to_string())
+    );
+
+    let error = decode(0xf6000003).unwrap_err();
+    assert_eq!(error.ec, Some(ExceptionClass::Pmu));
+    assert_eq!(error.field.to_string(), "ISS.RES0[1..25]");
+    assert_eq!(error.error, DecodeError::InvalidRes0 { res0: 1 });
+}
+
+#[test]
+fn impdef_el3() {
+    let decoded = decode(0x7e123456).unwrap();
+    assert_eq!(
+        decoded[2].description,
+        Some("IMPLEMENTATION DEFINED exception to EL3".to_string())
+    );
+    assert_eq!(
+        decoded[4].subfields,
+        vec![FieldInfo {
+            name: "IMPDEF",
+            long_name: Some("Implementation defined"),
+            start: 0,
+            width: 25,
+            value: 0x123456,
+            description: Some("ISS is IMPLEMENTATION DEFINED".to_string()),
+            diagnostic: None,
+            subfields: vec![],
+        }]
+    );
+}
+
+#[test]
+fn watchpoint_number() {
+    let decoded = decode(0xd60e0062).unwrap();
+    assert_eq!(
+        decoded[4].description,
+        Some("Watchpoint 3 triggered (DBGWVR3_EL1 and DBGWCR3_EL1)".to_string())
+    );
+    // Watchpoints above 15 are accessed through MDSELR_EL1.
+    assert_eq!(
+        decode(0xd68e0062).unwrap()[4].description,
+        Some(
+            "Watchpoint 35 triggered (DBGWVR3_EL1 and DBGWCR3_EL1 with MDSELR_EL1.BANK 2)"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        decode(0xd6420062).unwrap()[4].description,
+        Some(
+            "Watchpoint 16 triggered (DBGWVR0_EL1 and DBGWCR0_EL1 with MDSELR_EL1.BANK 1)"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        decoded[4].subfields[1],
+        FieldInfo {
+            name: "WPT",
+            long_name: Some("Watchpoint number"),
+            start: 18,
+            width: 6,
+            value: 3,
+            description: None,
+            diagnostic: None,
+            subfields: vec![],
+        }
+    );
+}
+
+#[test]
+fn typed_data_abort() {
+    let esr = Esr(0x97523050);
+    assert_eq!(
+        esr.exception_class().unwrap(),
+        ExceptionClass::DataAbortSameEl
+    );
+    assert!(esr.il());
+    assert_eq!(esr.sys_reg_trap(), None);
+    let data_abort = esr.data_abort().unwrap();
+    assert_eq!(data_abort, DataAbortIss::new(0x1523050, 0));
+    assert!(data_abort.isv());
+    assert_eq!(data_abort.sas(), Some(SyndromeAccessSize::Halfword));
+    assert_eq!(data_abort.srt(), Some(18));
+    assert_eq!(data_abort.sf(), Some(false));
+    assert!(!data_abort.fnp());
+    assert!(data_abort.vncr());
+    assert_eq!(data_abort.set(), Some(0b10));
+    assert!(data_abort.wnr());
+    assert_eq!(data_abort.dfsc().unwrap(), FaultStatusCode::SyncExternal);
+}
+
+#[test]
+fn typed_instruction_abort() {
+    let esr = Esr(0x82001e10);
+    assert_eq!(esr.data_abort(), None);
+    assert_eq!(esr.watchpoint(), None);
+    let instruction_abort = esr.instruction_abort().unwrap();
+    assert_eq!(instruction_abort, InstructionAbortIss::new(0x1e10));
+    assert_eq!(instruction_abort.set(), Some(0b11));
+    assert!(instruction_abort.fnv());
+    assert!(instruction_abort.ea());
+    assert!(!instruction_abort.s1ptw());
+    assert_eq!(
+        instruction_abort.ifsc().unwrap(),
+        FaultStatusCode::SyncExternal
+    );
+    assert_eq!(InstructionAbortIss::new(0x1e05).set(), None);
+}
+
+#[test]
+fn typed_watchpoint() {
+    let esr = Esr(0xd60e0062);
+    assert_eq!(esr.instruction_abort(), None);
+    let watchpoint = esr.watchpoint().unwrap();
+    assert_eq!(watchpoint, WatchpointIss::new(0x0e0062));
+    assert!(watchpoint.wptv());
+    assert_eq!(watchpoint.wpt(), 3);
+    assert_eq!(watchpoint.watchpoint_number(), Some(3));
+    assert!(!watchpoint.wpf());
+    assert!(!watchpoint.fnp());
+    assert!(!watchpoint.cm());
+    assert!(watchpoint.wnr());
+    assert_eq!(watchpoint.dfsc(), 0b100010);
+    assert_eq!(WatchpointIss::new(0x0c0062).watchpoint_number(), None);
+}
+
+#[test]
+fn typed_sys_reg_trap() {
+    let sys_reg_trap = Esr(0x62341c41).sys_reg_trap().unwrap();
+    assert_eq!(sys_reg_trap.op0(), 3);
+    assert_eq!(sys_reg_trap.op1(), 0);
+    assert_eq!(sys_reg_trap.crn(), 7);
+    assert_eq!(sys_reg_trap.crm(), 0);
+    assert_eq!(sys_reg_trap.op2(), 2);
+    assert_eq!(sys_reg_trap.rt(), 2);
+    assert!(sys_reg_trap.is_read());
+}
+
+/// Returns the ISS of a trapped MSR or MRS access to the register with the given encoding.
+fn sys_reg_trap_iss(op0: u64, op1: u64, crn: u64, crm: u64, op2: u64, read: bool) -> u64 {
+    op0 << 20 | op2 << 17 | op1 << 14 | crn << 10 | crm << 1 | u64::from(read)
+}
+
+#[test]
+fn sys_reg_trap_names() {
+    let sctlr = SysRegTrapIss::new(sys_reg_trap_iss(3, 0, 1, 0, 0, true));
+    assert_eq!(sctlr.name(), "SCTLR_EL1");
+    // Registers which can only be written.
+    let eoir1 = SysRegTrapIss::new(sys_reg_trap_iss(3, 0, 12, 12, 1, false));
+    assert_eq!(eoir1.name(), "ICC_EOIR1_EL1");
+    let oslar = SysRegTrapIss::new(sys_reg_trap_iss(2, 0, 1, 0, 4, false));
+    assert_eq!(oslar.name(), "OSLAR_EL1");
+    // The same encoding names different registers for reads and writes.
+    let dbgdtrrx = SysRegTrapIss::new(sys_reg_trap_iss(2, 3, 0, 5, 0, true));
+    assert_eq!(dbgdtrrx.name(), "DBGDTRRX_EL0");
+    let dbgdtrtx = SysRegTrapIss::new(sys_reg_trap_iss(2, 3, 0, 5, 0, false));
+    assert_eq!(dbgdtrtx.name(), "DBGDTRTX_EL0");
+    let unknown = SysRegTrapIss::new(sys_reg_trap_iss(3, 7, 15, 15, 7, true));
+    assert_eq!(unknown.name(), "unknown");
+}
+
+#[test]
+fn sys_instructions() {
+    for (esr, description) in [
+        (0x62122066, "TLBI VAE1IS, x3"),
+        (0x6212dc1c, "DC CIVAC, x0"),
+        (0x621023e6, "TLBI VMALLE1IS"),
+        (0x621027e6, "TLBI VMALLE1ISNXS"),
+        (0x62101fe2, "IC IALLUIS"),
+        (0x621b1e10, "AT S12E1W, x16"),
+        (0x621ffffe, "SYS #7, C15, C15, #7"),
+        (0x621fffff, "SYSL x31, #7, C15, C15, #7"),
+    ] {
+        let decoded = decode(esr).unwrap();
+        assert_eq!(
+            get_field(&decoded, "ISS").unwrap().description.as_deref(),
+            Some(description),
+            "{esr:#x}"
+        );
+    }
+    let tlbi = Esr(0x62122066).sys_reg_trap().unwrap();
+    assert!(tlbi.is_sys_instruction());
+    assert_eq!(tlbi.sys_instruction_name(), Some("TLBI VAE1IS"));
+    assert_eq!(
+        Esr(0x621ffffe)
+            .sys_reg_trap()
+            .unwrap()
+            .sys_instruction_name(),
+        None
+    );
+}
+
+#[test]
+fn coproc_register_names() {
+    for (esr, description) in [
+        (0x0fe00401, "MRC p15, 0, r0, c1, c0, 0 ; SCTLR"),
+        (0x0fe00800, "MCR p15, 0, r0, c2, c0, 0 ; TTBR0"),
+        (0x0fe00c20, "MCR p15, 0, r1, c3, c0, 0 ; DACR"),
+        (0x0fe005e1, "MRC p15, 0, SP_hyp, c1, c0, 0 ; SCTLR"),
+        (0x0fe00661, "MRC p15, 0, SP_svc, c1, c0, 0 ; SCTLR"),
+        (0x0fe03855, "MRC p15, 0, r2, c14, c10, 0 ; PMEVCNTR16"),
+        (0x0fffffff, "MRC p15, 7, 31, c15, c15, 7"),
+        (0x17e80046, "MCR p14, 0, r2, c0, c3, 4 ; DBGBVR3"),
+        (0x17e000a1, "MRC p14, 0, r5, c0, c0, 0 ; DBGDIDR"),
+        (0x17e000ab, "MRC p14, 0, r5, c0, c5, 0 ; DBGDTRRXint"),
+        (0x17e000aa, "MCR p14, 0, r5, c0, c5, 0 ; DBGDTRTXint"),
+        (0x13e00405, "MRRC p15, 0, r0, r1, c2 ; TTBR0"),
+        (0x13e40c44, "MCRR p15, 4, r2, r3, c2 ; HTTBR"),
+        (0x13e07ffe, "MCRR p15, 0, 31, 31, c15"),
+        (0x13e0374e, "MCRR p15, 0, R10_fiq, sp, c7 ; PAR"),
+        (0x33e00403, "MRRC p14, 0, r0, r1, c1 ; DBGDRAR"),
+    ] {
+        let decoded = decode(esr).unwrap();
+        assert_eq!(
+            get_field(&decoded, "ISS").unwrap().description.as_deref(),
+            Some(description),
+            "{esr:#x}"
+        );
+    }
+}
+
+#[test]
+fn typed_invalid_ec() {
+    assert!(matches!(
+        Esr(0xfc000000).exception_class(),
+        Err(DecodeError::InvalidEc { ec: 0x3f })
+    ));
+}
+
+#[test]
+fn exception_class_descriptions_match_tree() {
+    for ec in 0..64 {
+        if let Ok(class) = ExceptionClass::try_from(ec) {
+            assert_eq!(class as u64, ec);
+            if let Ok(decoded) = decode(ec << 26) {
+                assert_eq!(decoded[2].description.as_deref(), Some(class.description()));
+            }
+        }
+    }
+}
+
+#[test]
+fn encode_data_abort() {
+    let esr = EsrBuilder::new(ExceptionClass::DataAbortSameEl)
+        .field("ISV", 1)
+        .unwrap()
+        .field("SAS", 0b01)
+        .unwrap()
+        .field("SRT", 18)
+        .unwrap()
+        .field("VNCR", 1)
+        .unwrap()
+        .field("SET", 0b10)
+        .unwrap()
+        .field("WnR", 1)
+        .unwrap()
+        .field("DFSC", 0b010000)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(esr, 0x97523050);
+}
+
+#[test]
+fn encode_invalid() {
+    assert!(matches!(
+        EsrBuilder::new(ExceptionClass::Brk64).field("DFSC", 0),
+        Err(EncodeError::UnknownField { .. })
+    ));
+    assert!(matches!(
+        EsrBuilder::new(ExceptionClass::Brk64).field("Comment", 0x10000),
+        Err(EncodeError::ValueTooWide { .. })
+    ));
+    // SET is only valid for a synchronous External abort.
+    assert!(matches!(
+        EsrBuilder::new(ExceptionClass::DataAbortSameEl)
+            .field("SET", 0b10)
+            .unwrap()
+            .field("DFSC", 0b000100)
+            .unwrap()
+            .build(),
+        Err(EncodeError::FieldNotValid { name: "SET" })
+    ));
+    // Watchpoints must have a DFSC of 0b100010.
+    assert!(matches!(
+        EsrBuilder::new(ExceptionClass::WatchpointSameEl).build(),
+        Err(EncodeError::Decode(FieldError {
+            error: DecodeError::InvalidFsc { fsc: 0 },
+            ..
+        }))
+    ));
+}
+
+/// An example valid ESR value for every exception class, with as many fields non-zero as possible.
+const EXAMPLE_ESRS: [u64; 49] = [
+    0x02000000,
+    0x07e00065,
+    0x0feaed3f,
+    0x13ea2d3f,
+    0x17eaed3f,
+    0x1bea5137,
+    0x1f300000,
+    0x23e1dc41,
+    0x26000000,
+    0x2a000002,
+    0x33ea2d3f,
+    0x36000003,
+    0x3a000000,
+    0x4600abcd,
+    0x4a00abcd,
+    0x4f180000,
+    0x52300841,
+    0x5600abcd,
+    0x5a00abcd,
+    0x5e00abcd,
+    0x62341c41,
+    0x66000000,
+    0x6a000003,
+    0x6e0000e0,
+    0x72000003,
+    0x76000003,
+    0x7a3161e8,
+    0x7e123456,
+    0x82001e10,
+    0x86000687,
+    0x8a000000,
+    0x7ff93ffe7cf,
+    0x9600d875,
+    0x9a000000,
+    0x9f9f7fff,
+    0xa280079f,
+    0xb280079f,
+    0xb6200c80,
+    0xbe002e11,
+    0xc2000022,
+    0xc6000022,
+    0xcb000062,
+    0xce000022,
+    0xd2ffa162,
+    0xd6000062,
+    0xe000abcd,
+    0xea000022,
+    0xf200abcd,
+    0xf6000001,
+];
+
+#[test]
+fn encode_round_trip() {
+    let mut classes = vec![];
+    for esr in EXAMPLE_ESRS {
+        let class = Esr(esr).exception_class().unwrap();
+        classes.push(class);
+        let decoded = decode(esr).unwrap();
+        let mut builder = EsrBuilder::new(class).il(Esr(esr).il());
+        for field in decoded[1].subfields.iter().chain(&decoded[4].subfields) {
+            if field.name != "RES0" {
+                builder = builder.field(field.name, field.value).unwrap();
+            }
+        }
+        assert_eq!(builder.build().unwrap(), esr, "{esr:#x}");
+    }
+
+    // Check that every exception class is covered.
+    for ec in 0..64 {
+        if let Ok(class) = ExceptionClass::try_from(ec) {
+            assert!(classes.contains(&class), "{class:?}");
+        }
+    }
+}
+
+/// Returns the names and bit ranges within the ESR of the non-reserved ISS and ISS2 fields which
+/// the decoder gives for the given ESR value, or `None` if any of them are invalid.
+fn decoded_iss_fields(esr: u64) -> Option<Vec<(&'static str, Range<usize>)>> {
+    let options = DecodeOptions {
+        lenient: true,
+        ..Default::default()
+    };
+    let decoded = decode_static_with_options(esr, &options).unwrap();
+    let fields = decoded
+        .iss_subfields
+        .iter()
+        .map(|field| (field, 0))
+        .chain(decoded.iss2_subfields.iter().map(|field| (field, 32)));
+    let mut result = vec![];
+    for (field, offset) in fields {
+        if field.diagnostic.is_some() {
+            return None;
+        }
+        if field.name != "RES0" {
+            let start = offset + field.start;
+            result.push((field.name, start..start + field.width));
+        }
+    }
+    Some(result)
+}
+
+#[test]
+fn iss_fields_match_decoder() {
+    for ec in 0..64 {
+        let Ok(class) = ExceptionClass::try_from(ec) else {
+            continue;
+        };
+        let iss_fields = class.iss_fields();
+        // Decode example values, and an empty ISS, with each field of up to 6 bits set to each of
+        // its values in turn.
+        // This finds the fields which depend on the value of some other field, like SAS on ISV.
+        let examples = EXAMPLE_ESRS
+            .iter()
+            .copied()
+            .filter(|&esr| Esr(esr).exception_class() == Ok(class))
+            .chain([ec << 26 | 1 << 25]);
+        let mut esrs = vec![];
+        for example in examples {
+            esrs.push(example);
+            for (_, range) in iss_fields.iter().filter(|(_, range)| range.len() <= 6) {
+                for value in 0..1 << range.len() {
+                    esrs.push(*example.clone().set_bits(range.clone(), value));
+                }
+            }
+        }
+        let mut found = vec![];
+        for field in esrs.into_iter().filter_map(decoded_iss_fields).flatten() {
+            assert!(
+                iss_fields.contains(&field),
+                "{field:?} missing from {class:?}"
+            );
+            if !found.contains(&field) {
+                found.push(field);
+            }
+        }
+        for field in iss_fields {
+            assert!(found.contains(field), "{field:?} not found for {class:?}");
+        }
+    }
+}
+
+#[test]
+fn decode_static_matches_decode() {
+    for esr in EXAMPLE_ESRS {
+        let decoded = decode(esr).unwrap();
+        let decoded_static = decode_static(esr).unwrap();
+        for (index, (field, static_field)) in decoded.iter().zip(&decoded_static.fields).enumerate()
+        {
+            assert_eq!(field.name, static_field.name, "{esr:#x}");
+            assert_eq!(field.value, static_field.value, "{esr:#x}");
+            let subfields = decoded_static
+                .subfields(index)
+                .iter()
+                .copied()
+                .map(FieldInfo::from)
+                .collect::<Vec<_>>();
+            assert_eq!(field.subfields, subfields, "{esr:#x}");
+        }
+        assert_eq!(
+            decoded[4].description,
+            decoded_static
+                .iss_description
+                .map(|description| description.to_string()),
+            "{esr:#x}"
+        );
+    }
+}
+
+#[test]
+fn decode_static_display() {
+    let decoded = decode_static(0x6a000003).unwrap();
+    assert_eq!(
+        decoded.to_string(),
+        "\
+56..63 RES0: 0x00 0b00000000
+32..55 ISS2: 0x000000 0b000000000000000000000000
+26..31 EC: 0x1a 0b011010
+  # Trapped ERET, ERETAA or ERETAB instruction execution
+25     IL: true
+  # 32-bit instruction trapped
+00..24 ISS: 0x0000003 0b0000000000000000000000011
+  # ERETAB
+  02..24 RES0: 0x000000 0b00000000000000000000000
+  01     ERET: true
+    # ERETAA or ERETAB instruction trapped
+  00     ERETA: true
+    # B Key
+"
+    );
+    assert!(
+        format!("{decoded:#}")
+            .starts_with("56..63 RES0: 0x00 0b00000000 (Reserved)\n32..55 ISS2: ")
+    );
+}
+
+#[test]
+fn options_impossible_ec() {
+    // HVC64
+    let esr = 0x5a00abcd;
+    assert!(matches!(
+        decode_with_options(esr, &DecodeOptions::for_register(EsrRegister::EsrEl1)),
+        Err(FieldError {
+            error: DecodeError::ImpossibleEc {
+                ec: 0b010110,
+                el: ExceptionLevel::El1
+            },
+            ..
+        })
+    ));
+    assert!(decode_with_options(esr, &DecodeOptions::for_register(EsrRegister::EsrEl2)).is_ok());
+
+    // Granule Protection Check
+    let esr = 0x7a000028;
+    assert!(matches!(
+        decode_with_options(esr, &DecodeOptions::for_register(EsrRegister::EsrEl2)),
+        Err(FieldError {
+            error: DecodeError::ImpossibleEc {
+                ec: 0b011110,
+                el: ExceptionLevel::El2
+            },
+            ..
+        })
+    ));
+    assert!(decode_with_options(esr, &DecodeOptions::for_register(EsrRegister::EsrEl3)).is_ok());
+
+    let options = DecodeOptions {
+        tge: Some(true),
+        ..DecodeOptions::for_register(EsrRegister::EsrEl1)
+    };
+    assert!(matches!(
+        decode_with_options(0x96000050, &options),
+        Err(FieldError {
+            error: DecodeError::El1WithTge,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn options_describe_route() {
+    // Data Abort from a lower Exception level.
+    let esr = 0x92000050;
+    let description = |options| {
+        decode_with_options(esr, &options).unwrap()[2]
+            .description
+            .clone()
+            .unwrap()
+    };
+    assert_eq!(
+        description(DecodeOptions::default()),
+        "Data Abort from a lower Exception level"
+    );
+    assert_eq!(
+        description(DecodeOptions::for_register(EsrRegister::EsrEl1)),
+        "Data Abort from EL0, taken to EL1"
+    );
+    assert_eq!(
+        description(DecodeOptions::for_register(EsrRegister::EsrEl2)),
+        "Data Abort from EL0 or EL1, taken to EL2"
+    );
+    assert_eq!(
+        description(DecodeOptions {
+            tge: Some(true),
+            e2h: Some(true),
+            ..DecodeOptions::for_register(EsrRegister::EsrEl2)
+        }),
+        "Data Abort from EL0 in the EL2&0 regime, taken to EL2"
+    );
+    assert_eq!(
+        description(DecodeOptions::for_register(EsrRegister::EsrEl3)),
+        "Data Abort from EL0, EL1 or EL2, taken to EL3"
+    );
+
+    // Data Abort without a change in Exception level.
+    let decoded = decode_with_options(
+        0x96000050,
+        &DecodeOptions::for_register(EsrRegister::EsrEl2),
+    )
+    .unwrap();
+    assert_eq!(
+        decoded[2].description.as_deref(),
+        Some("Data Abort from EL2, taken to EL2")
+    );
+}
+
+#[test]
+fn features_from_id_registers() {
+    // Cortex-A53
+    let id_registers = IdRegisters {
+        id_aa64pfr0_el1: 0x2222,
+        id_aa64isar1_el1: 0,
+        id_aa64mmfr0_el1: 0x1122,
+        id_aa64mmfr2_el1: 0,
+    };
+    assert_eq!(Features::from(id_registers), Features::NONE);
+
+    let id_registers = IdRegisters {
+        id_aa64pfr0_el1: 0x1000_0000,
+        id_aa64isar1_el1: 0x1000_0000_0000_0000,
+        id_aa64mmfr0_el1: 0x1000_0000,
+        id_aa64mmfr2_el1: 0x0200_0000,
+    };
+    assert_eq!(Features::from(id_registers), Features::ALL);
+}
+
+#[test]
+fn features_absent() {
+    let options = DecodeOptions {
+        features: Features::NONE,
+        ..Default::default()
+    };
+
+    // Data Abort with VNCR set.
+    let esr = 0x96002010;
+    let decoded = decode(esr).unwrap();
+    assert!(
+        decoded[4]
+            .subfields
+            .iter()
+            .any(|field| field.name == "VNCR")
+    );
+    assert!(matches!(
+        decode_with_options(esr, &options),
+        Err(FieldError {
+            error: DecodeError::InvalidRes0 { res0: 1 },
+            ..
+        })
+    ));
+
+    // Synchronous External abort with SET of 0.
+    let decoded = decode_with_options(0x96000010, &options).unwrap();
+    assert!(decoded[4].subfields.iter().all(|field| field.name != "SET"));
+    assert!(
+        decoded[4]
+            .subfields
+            .iter()
+            .any(|field| field.long_name == Some("Reserved because FEAT_RAS is not implemented"))
+    );
+    assert!(matches!(
+        decode_with_options(0x96001010, &options),
+        Err(FieldError {
+            error: DecodeError::InvalidRes0 { res0: 0b10 },
+            ..
+        })
+    ));
+
+    // Translation fault, level -1.
+    let esr = 0x9600002b;
+    assert!(decode(esr).is_ok());
+    assert!(matches!(
+        decode_with_options(esr, &options),
+        Err(FieldError {
+            error: DecodeError::InvalidFsc { fsc: 0b101011 },
+            ..
+        })
+    ));
+    let decoded = decode_with_options(
+        esr,
+        &DecodeOptions {
+            features: Features {
+                lpa2: true,
+                ..Features::NONE
+            },
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(decoded[4].subfields.last().unwrap().value, 0b101011);
+}
+
+#[test]
+fn lenient_diagnostics() {
+    let options = DecodeOptions {
+        lenient: true,
+        ..Default::default()
+    };
+
+    // Data Abort with non-zero RES0 bits and an invalid SET.
+    let esr = 0x96fc0810;
+    assert!(matches!(
+        decode(esr),
+        Err(FieldError {
+            error: DecodeError::InvalidRes0 { res0: 0x3f },
+            ..
+        })
+    ));
+    let decoded = decode_static_with_options(esr, &options).unwrap();
+    let diagnostics = decoded
+        .diagnostics()
+        .map(|error| (error.field.name, error.field.start, error.error))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        diagnostics,
+        vec![
+            ("RES0", 18, DecodeError::InvalidRes0 { res0: 0x3f }),
+            ("SET", 11, DecodeError::InvalidSet { set: 0b01 }),
+        ]
+    );
+    // The other fields are still decoded.
+    assert_eq!(
+        decoded.iss_subfields.last().unwrap().description,
+        Some(
+            "Synchronous External abort, not on translation table walk or hardware update of \
+             translation table."
+        )
+    );
+    assert!(
+        decoded
+            .to_string()
+            .contains("  11..12 SET: 0x1 0b01\n    ! Invalid SET 0x1\n")
+    );
+
+    let decoded = decode_with_options(esr, &options).unwrap();
+    assert_eq!(
+        decoded[4].subfields[1].diagnostic,
+        Some(DecodeError::InvalidRes0 { res0: 0x3f })
+    );
+
+    // Invalid EC.
+    let decoded = decode_static_with_options(0xfc000000, &options).unwrap();
+    assert_eq!(
+        decoded.fields[2].diagnostic,
+        Some(DecodeError::InvalidEc { ec: 0x3f })
+    );
+    assert!(decoded.iss_subfields.is_empty());
+
+    // EC which can't be taken to the given Exception level.
+    let decoded = decode_static_with_options(
+        0x5a00abcd,
+        &DecodeOptions {
+            lenient: true,
+            ..DecodeOptions::for_register(EsrRegister::EsrEl1)
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        decoded.fields[2].diagnostic,
+        Some(DecodeError::ImpossibleEc {
+            ec: 0b010110,
+            el: ExceptionLevel::El1
+        })
+    );
+    assert_eq!(decoded.iss_subfields[1].value, 0xabcd);
+}
+
+#[test]
+fn field_error_context() {
+    // Watchpoint with bit 14 set, which is RES0.
+    let error = decode(0xd6004022).unwrap_err();
+    assert_eq!(
+        error,
+        FieldError {
+            field: FieldPath {
+                parent: Some("ISS"),
+                name: "RES0",
+                start: 14,
+                width: 1,
+            },
+            ec: Some(ExceptionClass::WatchpointSameEl),
+            error: DecodeError::InvalidRes0 { res0: 1 },
+        }
+    );
+    assert_eq!(error.field.bits(), 14..15);
+    assert_eq!(
+        error.to_string(),
+        "Invalid ESR, res0 is 0x1 in ISS.RES0[14..15] for EC 0x35"
+    );
+
+    // Top-level RES0.
+    let error = decode(0x0100_0000_0000_0000).unwrap_err();
+    assert_eq!(error.field.to_string(), "RES0[56..64]");
+    assert_eq!(error.ec, Some(ExceptionClass::Unknown));
+
+    // Invalid EC.
+    let error = decode(0xfc000000).unwrap_err();
+    assert_eq!(error.to_string(), "Invalid EC 0x3f in EC[26..32]");
+    assert_eq!(error.ec, None);
+    assert_eq!(
+        DecodeError::from(error),
+        DecodeError::InvalidEc { ec: 0x3f }
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialize_json() {
+    let decoded = decode_with_options(
+        0x96000810,
+        &DecodeOptions {
+            lenient: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let json = serde_json::to_value(&decoded).unwrap();
+    assert_eq!(
+        json[2],
+        serde_json::json!({
+            "name": "EC",
+            "long_name": "Exception Class",
+            "start": 26,
+            "width": 6,
+            "value": 0x25,
+            "description": "Data Abort taken without a change in Exception level",
+            "diagnostic": null,
+            "subfields": [],
+        })
+    );
+    let set = &json[4]["subfields"][6];
+    assert_eq!(set["name"], "SET");
+    assert_eq!(
+        set["diagnostic"],
+        serde_json::json!({"InvalidSet": {"set": 1}})
+    );
+}
+
+#[test]
+fn field_tree_matches_decode_static() {
+    for esr in [0x6a000003, 0x96000050, 0x5a00abcd] {
+        let decoded = decode(esr).unwrap();
+        let decoded_static = decode_static(esr).unwrap();
+        let tree = FieldTree::new(&decoded, FormatOptions::default());
+        assert_eq!(tree.to_string(), decoded_static.to_string());
+        assert_eq!(format!("{tree:#}"), format!("{decoded_static:#}"));
+    }
+}
+
+#[test]
+fn field_tree_options() {
+    let decoded = decode(0x6a000003).unwrap();
+    let options = FormatOptions {
+        long_names: true,
+        indentation: 4,
+        binary: false,
+        bit_ranges: false,
+    };
+    let expected = "\
+RES0: 0x00 (Reserved)
+ISS2: 0x000000
+EC: 0x1a (Exception Class)
+    # Trapped ERET, ERETAA or ERETAB instruction execution
+IL: true (Instruction Length)
+    # 32-bit instruction trapped
+ISS: 0x0000003 (Instruction Specific Syndrome)
+    # ERETAB
+    RES0: 0x000000 (Reserved)
+    ERET: true (ERET or ERETA*)
+        # ERETAA or ERETAB instruction trapped
+    ERETA: true (A key or B key)
+        # B Key
+";
+    assert_eq!(FieldTree::new(&decoded, options).to_string(), expected);
+    assert_eq!(
+        decode_static(0x6a000003)
+            .unwrap()
+            .display_with(options)
+            .to_string(),
+        expected
+    );
+}
+
+#[test]
+fn get_field_by_path() {
+    let decoded = decode(0x96000050).unwrap();
+    assert_eq!(get_field(&decoded, "EC").unwrap().value, 0x25);
+    assert_eq!(
+        get_field(&decoded, "ISS.WnR").unwrap().value_string(),
+        "true"
+    );
+    assert_eq!(get_field(&decoded, "ISS.DFSC").unwrap().value, 0x10);
+    assert_eq!(
+        get_field(&decoded, "ISS")
+            .unwrap()
+            .subfield("DFSC")
+            .unwrap()
+            .value,
+        0x10
+    );
+    assert_eq!(get_field(&decoded, "ISS2.RES0").unwrap().start, 13);
+    assert_eq!(get_field(&decoded, "ISS2.RES0[7..8]").unwrap().start, 7);
+    assert_eq!(get_field(&decoded, "ISS2.RES0[7..9]"), None);
+    assert_eq!(get_field(&decoded, "ISS.Nope"), None);
+    assert_eq!(get_field(&decoded, "ISS.DFSC.Nope"), None);
+    assert_eq!(get_field(&decoded, ""), None);
+}
+
+#[test]
+fn get_field_matches_field_path() {
+    let decoded = decode_with_options(
+        0x96040050,
+        &DecodeOptions {
+            lenient: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let error = decode(0x96040050).unwrap_err();
+    let path = error.field.to_string();
+    assert_eq!(
+        get_field(&decoded, &path).unwrap().diagnostic,
+        Some(error.error)
+    );
+}
+
+#[test]
+fn decoded_esr_get() {
+    for esr in [0x96000050, 0x6a000003, 0x5a00abcd] {
+        let decoded = decode(esr).unwrap();
+        let decoded_static = decode_static(esr).unwrap();
+        for path in [
+            "EC",
+            "ISS",
+            "ISS.WnR",
+            "ISS.DFSC",
+            "ISS.ERETA",
+            "ISS2.RES0[7..8]",
+            "ISS.Nope",
+            "ISS.DFSC.Nope",
+        ] {
+            assert_eq!(
+                decoded_static.get(path).map(|field| field.value),
+                get_field(&decoded, path).map(|field| field.value),
+                "{path}"
+            );
+        }
+    }
+}
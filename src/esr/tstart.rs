@@ -0,0 +1,41 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::IssField;
+use crate::{FieldList, StaticFieldInfo};
+use bit_field::BitField;
+use core::fmt::{self, Formatter};
+
+/// The non-reserved ISS fields which may be present for a trapped TSTART instruction.
+pub const TSTART_FIELDS: &[IssField] = &[("Rd", 5..10)];
+
+/// Decodes the ISS value for a trapped TSTART instruction.
+pub fn decode_iss_tstart(iss: u64) -> FieldList {
+    let res0a = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 10, 25).check_res0();
+    let rd = StaticFieldInfo::get(
+        iss,
+        "Rd",
+        Some("General-purpose register number of the trapped instruction"),
+        5,
+        10,
+    );
+    let res0b = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 0, 5).check_res0();
+
+    FieldList::from([res0a, rd, res0b])
+}
+
+/// Writes the trapped instruction for the given ISS value.
+pub fn fmt_iss_tstart(f: &mut Formatter, iss: u64) -> fmt::Result {
+    write!(f, "TSTART x{}", iss.get_bits(5..10))
+}
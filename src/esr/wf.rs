@@ -12,27 +12,40 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::common::describe_cv;
-use crate::{DecodeError, FieldInfo};
+use super::IssField;
+use super::common::{describe_cond, describe_cv};
+use crate::{DecodeError, FieldList, StaticFieldInfo};
+
+/// The non-reserved ISS fields which may be present for a trapped WF* instruction.
+pub const WF_FIELDS: &[IssField] = &[
+    ("CV", 24..25),
+    ("COND", 20..24),
+    ("RN", 5..10),
+    ("RV", 2..3),
+    ("TI", 0..2),
+];
 
 /// Decodes the ISS value for a trapped WF* instruction.
-pub fn decode_iss_wf(iss: u64) -> Result<Vec<FieldInfo>, DecodeError> {
-    let cv =
-        FieldInfo::get_bit(iss, "CV", Some("Condition code valid"), 24).describe_bit(describe_cv);
-    let cond = FieldInfo::get(
+pub fn decode_iss_wf(iss: u64) -> FieldList {
+    let cv = StaticFieldInfo::get_bit(iss, "CV", Some("Condition code valid"), 24)
+        .describe_bit(describe_cv);
+    let cond = StaticFieldInfo::get(
         iss,
         "COND",
         Some("Condition code of the trapped instruction"),
         20,
         24,
-    );
-    let res0a = FieldInfo::get(iss, "RES0", Some("Reserved"), 10, 20).check_res0()?;
-    let rn = FieldInfo::get(iss, "RN", Some("Register Number"), 5, 10);
-    let res0b = FieldInfo::get(iss, "RES0", Some("Reserved"), 3, 5).check_res0()?;
-    let rv = FieldInfo::get_bit(iss, "RV", Some("Register Valid"), 2).describe_bit(describe_rv);
-    let ti = FieldInfo::get(iss, "TI", Some("Trapped Instruction"), 0, 2).describe(describe_ti)?;
+    )
+    .describe(describe_cond);
+    let res0a = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 10, 20).check_res0();
+    let rn = StaticFieldInfo::get(iss, "RN", Some("Register Number"), 5, 10);
+    let res0b = StaticFieldInfo::get(iss, "RES0", Some("Reserved"), 3, 5).check_res0();
+    let rv =
+        StaticFieldInfo::get_bit(iss, "RV", Some("Register Valid"), 2).describe_bit(describe_rv);
+    let ti =
+        StaticFieldInfo::get(iss, "TI", Some("Trapped Instruction"), 0, 2).describe(describe_ti);
 
-    Ok(vec![cv, cond, res0a, rn, res0b, rv, ti])
+    FieldList::from([cv, cond, res0a, rn, res0b, rv, ti])
 }
 
 fn describe_rv(rv: bool) -> &'static str {
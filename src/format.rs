@@ -0,0 +1,184 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Formatting of decoded fields as an indented tree, as printed by the command-line tool.
+
+#[cfg(feature = "alloc")]
+use crate::FieldInfo;
+use crate::{DecodeError, StaticFieldInfo};
+use core::fmt::{self, Display, Formatter};
+
+/// Options for how to format a tree of fields.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct FormatOptions {
+    /// Whether to include the long name of each field after its value.
+    pub long_names: bool,
+    /// The number of spaces to indent each level of subfields by.
+    pub indentation: usize,
+    /// Whether to include multi-bit values in binary as well as hexadecimal.
+    pub binary: bool,
+    /// Whether to start each line with the range of bits which the field covers.
+    pub bit_ranges: bool,
+}
+
+impl Default for FormatOptions {
+    /// Returns the options used by the command-line tool without `-v`.
+    fn default() -> Self {
+        Self {
+            long_names: false,
+            indentation: 2,
+            binary: true,
+            bit_ranges: true,
+        }
+    }
+}
+
+/// A tree of fields, which can be formatted with `Display` in the same format as the command-line
+/// tool.
+///
+/// The alternate form (`{:#}`) includes the long names of fields, whatever the options say.
+#[cfg(feature = "alloc")]
+#[derive(Copy, Clone, Debug)]
+pub struct FieldTree<'a> {
+    fields: &'a [FieldInfo],
+    options: FormatOptions,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> FieldTree<'a> {
+    /// Returns a tree of the given fields and their subfields, to be formatted with the given
+    /// options.
+    pub fn new(fields: &'a [FieldInfo], options: FormatOptions) -> Self {
+        Self { fields, options }
+    }
+}
+
+/// Writes the given fields and their subfields, indented to the given level.
+#[cfg(feature = "alloc")]
+fn fmt_fields(
+    f: &mut Formatter,
+    fields: &[FieldInfo],
+    options: &FormatOptions,
+    level: usize,
+) -> fmt::Result {
+    for field in fields {
+        FieldLine::from(field).fmt(f, options, level)?;
+        fmt_fields(f, &field.subfields, options, level + 1)?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+impl Display for FieldTree<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let options = FormatOptions {
+            long_names: self.options.long_names || f.alternate(),
+            ..self.options
+        };
+        fmt_fields(f, self.fields, &options, 0)
+    }
+}
+
+/// The parts of a field needed to format it as a line of the tree.
+pub(crate) struct FieldLine<'a> {
+    pub name: &'a str,
+    pub long_name: Option<&'a str>,
+    pub start: usize,
+    pub width: usize,
+    pub value: u64,
+    pub description: Option<&'a dyn Display>,
+    pub diagnostic: Option<DecodeError>,
+}
+
+impl FieldLine<'_> {
+    /// Writes a line for the field indented to the given level, followed by lines with its
+    /// description and diagnostic if it has them.
+    pub fn fmt(&self, f: &mut Formatter, options: &FormatOptions, level: usize) -> fmt::Result {
+        let indentation = level * options.indentation;
+        let detail_indentation = indentation + options.indentation;
+        write!(f, "{:indentation$}", "")?;
+        if options.bit_ranges {
+            if self.width == 1 {
+                write!(f, "{:02}     ", self.start)?;
+            } else {
+                write!(f, "{:02}..{:02} ", self.start, self.start + self.width - 1)?;
+            }
+        }
+        write!(f, "{}: ", self.name)?;
+        if self.width == 1 {
+            f.write_str(if self.value == 1 { "true" } else { "false" })?;
+        } else {
+            write!(
+                f,
+                "{:#0hex_width$x}",
+                self.value,
+                hex_width = self.width.div_ceil(4) + 2
+            )?;
+            if options.binary {
+                write!(
+                    f,
+                    " {:#0binary_width$b}",
+                    self.value,
+                    binary_width = self.width + 2
+                )?;
+            }
+        }
+        match self.long_name {
+            Some(long_name) if options.long_names => writeln!(f, " ({long_name})")?,
+            _ => writeln!(f)?,
+        }
+        if let Some(description) = self.description {
+            writeln!(f, "{:detail_indentation$}# {}", "", description)?;
+        }
+        if let Some(diagnostic) = self.diagnostic {
+            writeln!(f, "{:detail_indentation$}! {}", "", diagnostic)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> From<&'a StaticFieldInfo> for FieldLine<'a> {
+    fn from(field: &'a StaticFieldInfo) -> Self {
+        Self {
+            name: field.name,
+            long_name: field.long_name,
+            start: field.start,
+            width: field.width,
+            value: field.value,
+            description: field
+                .description
+                .as_ref()
+                .map(|description| description as &dyn Display),
+            diagnostic: field.diagnostic,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<&'a FieldInfo> for FieldLine<'a> {
+    fn from(field: &'a FieldInfo) -> Self {
+        Self {
+            name: field.name,
+            long_name: field.long_name,
+            start: field.start,
+            width: field.width,
+            value: field.value,
+            description: field
+                .description
+                .as_ref()
+                .map(|description| description as &dyn Display),
+            diagnostic: field.diagnostic,
+        }
+    }
+}
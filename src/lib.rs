@@ -13,22 +13,65 @@
 // limitations under the License.
 
 //! Library for decoding aarch64 Exception Syndrome Register and Main ID Register values.
+//!
+//! With the default `alloc` feature the full tree of fields is available from [`decode`],
+//! [`decode_midr`] and [`decode_smccc`]. Without it the crate is `no_std` and doesn't need a heap,
+//! and ESR values can be decoded with [`decode_static`], e.g. from an exception handler.
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 mod esr;
+mod format;
+#[cfg(feature = "alloc")]
 mod midr;
+#[cfg(feature = "alloc")]
 mod smccc;
+#[cfg(feature = "sysregs")]
+mod sysreg;
 
+#[cfg(feature = "alloc")]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 use bit_field::BitField;
-pub use esr::decode;
+use core::array;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::iter::Take;
+use core::num::ParseIntError;
+use core::ops::{Deref, Range};
+pub use esr::{
+    DataAbortIss, DecodeOptions, DecodedEsr, Esr, EsrRegister, ExceptionClass, ExceptionLevel,
+    FaultStatusCode, Features, IdRegisters, InstructionAbortIss, IssDescription,
+    SYSREG_NAMES_RELEASE, SyndromeAccessSize, SysRegTrapIss, WatchpointIss, decode_static,
+    decode_static_with_options,
+};
+#[cfg(feature = "alloc")]
+pub use esr::{EsrBuilder, decode, decode_with_options};
+#[cfg(feature = "alloc")]
+pub use format::FieldTree;
+pub use format::FormatOptions;
+#[cfg(feature = "alloc")]
 pub use midr::decode_midr;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+#[cfg(feature = "alloc")]
 pub use smccc::decode_smccc;
-use std::fmt::{self, Debug, Display, Formatter};
-use std::num::ParseIntError;
+#[cfg(feature = "sysregs")]
+pub use sysreg::decode_sysreg;
 use thiserror::Error;
 
-/// Information about a particular field.
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct FieldInfo {
+/// Information about a particular field, which doesn't need a heap.
+///
+/// This is the same as [`FieldInfo`], except that the description must be a static string and
+/// there are no subfields.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct StaticFieldInfo {
     /// The short name of the field, e.g. "ISS".
     pub name: &'static str,
     /// The long name of the field, e.g. "Instruction Specific Syndrome".
@@ -40,12 +83,22 @@ pub struct FieldInfo {
     /// The value of the field.
     pub value: u64,
     /// A description explaining the field value, if available.
-    pub description: Option<String>,
-    /// Any sub-fields.
-    pub subfields: Vec<FieldInfo>,
+    pub description: Option<&'static str>,
+    /// A problem with the field value, if any, such as a RES0 field being non-zero.
+    pub diagnostic: Option<DecodeError>,
 }
 
-impl FieldInfo {
+impl StaticFieldInfo {
+    const EMPTY: Self = Self {
+        name: "",
+        long_name: None,
+        start: 0,
+        width: 0,
+        value: 0,
+        description: None,
+        diagnostic: None,
+    };
+
     fn get(
         register: u64,
         name: &'static str,
@@ -61,7 +114,7 @@ impl FieldInfo {
             width: end - start,
             value,
             description: None,
-            subfields: vec![],
+            diagnostic: None,
         }
     }
 
@@ -74,7 +127,37 @@ impl FieldInfo {
         Self::get(register, name, long_name, bit, bit + 1)
     }
 
-    fn with_description(self, description: String) -> Self {
+    /// Returns a field in the given range of bits with the given value, as returned by an accessor
+    /// of one of the typed syndrome types such as [`DataAbortIss`].
+    fn new(
+        name: &'static str,
+        long_name: Option<&'static str>,
+        bits: Range<usize>,
+        value: u64,
+    ) -> Self {
+        Self {
+            name,
+            long_name,
+            start: bits.start,
+            width: bits.len(),
+            value,
+            description: None,
+            diagnostic: None,
+        }
+    }
+
+    /// Returns a single-bit field at the given bit with the given value, as returned by an accessor
+    /// of one of the typed syndrome types.
+    fn new_bit(
+        name: &'static str,
+        long_name: Option<&'static str>,
+        bit: usize,
+        value: bool,
+    ) -> Self {
+        Self::new(name, long_name, bit..bit + 1, value.into())
+    }
+
+    fn with_description(self, description: &'static str) -> Self {
         Self {
             description: Some(description),
             ..self
@@ -94,8 +177,185 @@ impl FieldInfo {
         F: FnOnce(bool) -> &'static str,
     {
         let bit = self.as_bit();
-        let description = describer(bit).to_string();
-        self.with_description(description)
+        self.with_description(describer(bit))
+    }
+
+    fn with_diagnostic(self, diagnostic: DecodeError) -> Self {
+        Self {
+            diagnostic: Some(diagnostic),
+            ..self
+        }
+    }
+
+    /// Describes the field with the given function, or records its error as a diagnostic if the
+    /// value isn't valid.
+    fn describe<F>(self, describer: F) -> Self
+    where
+        F: FnOnce(u64) -> Result<&'static str, DecodeError>,
+    {
+        match describer(self.value) {
+            Ok(description) => self.with_description(description),
+            Err(diagnostic) => self.with_diagnostic(diagnostic),
+        }
+    }
+
+    /// Records a diagnostic if the field isn't 0.
+    fn check_res0(self) -> Self {
+        if self.value != 0 {
+            self.with_diagnostic(DecodeError::InvalidRes0 { res0: self.value })
+        } else {
+            self
+        }
+    }
+}
+
+impl Display for StaticFieldInfo {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_field_value(f, self.name, self.width, self.value)
+    }
+}
+
+/// A list of fields with a fixed maximum capacity, which doesn't need a heap.
+#[derive(Copy, Clone)]
+pub struct FieldList {
+    fields: [StaticFieldInfo; Self::CAPACITY],
+    len: usize,
+}
+
+impl FieldList {
+    /// The maximum number of fields which a list may hold.
+    pub const CAPACITY: usize = 16;
+
+    /// Returns a new empty list.
+    pub const fn new() -> Self {
+        Self {
+            fields: [StaticFieldInfo::EMPTY; Self::CAPACITY],
+            len: 0,
+        }
+    }
+
+    /// Adds the given field to the end of the list.
+    ///
+    /// Panics if the list is already full.
+    fn push(&mut self, field: StaticFieldInfo) {
+        self.fields[self.len] = field;
+        self.len += 1;
+    }
+}
+
+impl Default for FieldList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for FieldList {
+    type Target = [StaticFieldInfo];
+
+    fn deref(&self) -> &[StaticFieldInfo] {
+        &self.fields[..self.len]
+    }
+}
+
+impl Debug for FieldList {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl PartialEq for FieldList {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for FieldList {}
+
+impl Extend<StaticFieldInfo> for FieldList {
+    fn extend<T: IntoIterator<Item = StaticFieldInfo>>(&mut self, fields: T) {
+        for field in fields {
+            self.push(field);
+        }
+    }
+}
+
+impl<const N: usize> From<[StaticFieldInfo; N]> for FieldList {
+    fn from(fields: [StaticFieldInfo; N]) -> Self {
+        let mut list = Self::new();
+        list.extend(fields);
+        list
+    }
+}
+
+impl IntoIterator for FieldList {
+    type Item = StaticFieldInfo;
+    type IntoIter = Take<array::IntoIter<StaticFieldInfo, { FieldList::CAPACITY }>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.fields.into_iter().take(self.len)
+    }
+}
+
+impl<'a> IntoIterator for &'a FieldList {
+    type Item = &'a StaticFieldInfo;
+    type IntoIter = core::slice::Iter<'a, StaticFieldInfo>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Writes the name and value of a field, as used by the `Display` implementations of
+/// [`FieldInfo`] and [`StaticFieldInfo`].
+fn fmt_field_value(f: &mut Formatter, name: &str, width: usize, value: u64) -> fmt::Result {
+    if width == 1 {
+        write!(f, "{}: {}", name, if value == 1 { "true" } else { "false" })
+    } else {
+        write!(
+            f,
+            "{}: {:#0hex_width$x} {:#0binary_width$b}",
+            name,
+            value,
+            value,
+            hex_width = width.div_ceil(4) + 2,
+            binary_width = width + 2,
+        )
+    }
+}
+
+/// Information about a particular field.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct FieldInfo {
+    /// The short name of the field, e.g. "ISS".
+    pub name: &'static str,
+    /// The long name of the field, e.g. "Instruction Specific Syndrome".
+    pub long_name: Option<&'static str>,
+    /// The index of the lowest bit of the field.
+    pub start: usize,
+    /// The number of bits in the field.
+    pub width: usize,
+    /// The value of the field.
+    pub value: u64,
+    /// A description explaining the field value, if available.
+    pub description: Option<String>,
+    /// A problem with the field value, if any, such as a RES0 field being non-zero.
+    pub diagnostic: Option<DecodeError>,
+    /// Any sub-fields.
+    pub subfields: Vec<FieldInfo>,
+}
+
+#[cfg(feature = "alloc")]
+impl FieldInfo {
+    fn get(
+        register: u64,
+        name: &'static str,
+        long_name: Option<&'static str>,
+        start: usize,
+        end: usize,
+    ) -> Self {
+        StaticFieldInfo::get(register, name, long_name, start, end).into()
     }
 
     fn describe<F>(self, describer: F) -> Result<Self, DecodeError>
@@ -103,7 +363,10 @@ impl FieldInfo {
         F: FnOnce(u64) -> Result<&'static str, DecodeError>,
     {
         let description = describer(self.value)?.to_string();
-        Ok(self.with_description(description))
+        Ok(Self {
+            description: Some(description),
+            ..self
+        })
     }
 
     fn check_res0(self) -> Result<Self, DecodeError> {
@@ -114,6 +377,15 @@ impl FieldInfo {
         }
     }
 
+    #[cfg(feature = "sysregs")]
+    fn check_res1(self) -> Result<Self, DecodeError> {
+        if self.value != (1 << self.width) - 1 {
+            Err(DecodeError::InvalidRes1 { res1: self.value })
+        } else {
+            Ok(self)
+        }
+    }
+
     /// Returns the value as a hexadecimal string, or "true" or "false" if it is a single bit.
     pub fn value_string(&self) -> String {
         if self.width == 1 {
@@ -127,35 +399,96 @@ impl FieldInfo {
     pub fn value_binary_string(&self) -> String {
         format!("{:#01$b}", self.value, self.width + 2)
     }
+
+    /// Returns the subfield with the given path relative to this field, such as `"DFSC"`.
+    ///
+    /// See [`get_field`] for the format of paths.
+    pub fn subfield(&self, path: &str) -> Option<&FieldInfo> {
+        get_field(&self.subfields, path)
+    }
 }
 
+/// Returns the field in the given tree with the given path, such as `"ISS.DFSC"`, if there is one.
+///
+/// A path is the short names of the field and each of its parents, separated by `.`. Where several
+/// sibling fields have the same name, such as `RES0`, a name may be followed by the range of bits
+/// to choose between them, in the same format as [`FieldPath`], e.g. `"ISS.RES0[14..24]"`.
+/// Otherwise the first matching field is returned.
+#[cfg(feature = "alloc")]
+pub fn get_field<'a>(fields: &'a [FieldInfo], path: &str) -> Option<&'a FieldInfo> {
+    let (segment, rest) = split_path(path);
+    let field = fields
+        .iter()
+        .find(|field| path_segment_matches(segment, field.name, field.start, field.width))?;
+    match rest {
+        Some(rest) => get_field(&field.subfields, rest),
+        None => Some(field),
+    }
+}
+
+/// Splits the first segment of a field path from the rest, if there is more.
+fn split_path(path: &str) -> (&str, Option<&str>) {
+    // Bit ranges contain dots, so skip over them.
+    let mut in_range = false;
+    for (index, c) in path.char_indices() {
+        match c {
+            '[' => in_range = true,
+            ']' => in_range = false,
+            '.' if !in_range => return (&path[..index], Some(&path[index + 1..])),
+            _ => {}
+        }
+    }
+    (path, None)
+}
+
+/// Returns whether the given segment of a field path, such as `DFSC` or `RES0[14..24]`, refers to
+/// a field with the given name and bits.
+fn path_segment_matches(segment: &str, name: &str, start: usize, width: usize) -> bool {
+    let Some((segment_name, range)) = segment.split_once('[') else {
+        return segment == name;
+    };
+    let bits = range
+        .strip_suffix(']')
+        .and_then(|range| range.split_once(".."))
+        .and_then(|(range_start, range_end)| {
+            Some((range_start.parse().ok()?, range_end.parse().ok()?))
+        });
+    segment_name == name && bits == Some((start, start + width))
+}
+
+#[cfg(feature = "alloc")]
 impl Display for FieldInfo {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        if self.width == 1 {
-            write!(
-                f,
-                "{}: {}",
-                self.name,
-                if self.value == 1 { "true" } else { "false" }
-            )
-        } else {
-            write!(
-                f,
-                "{}: {} {}",
-                self.name,
-                self.value_string(),
-                self.value_binary_string(),
-            )
+        fmt_field_value(f, self.name, self.width, self.value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<StaticFieldInfo> for FieldInfo {
+    fn from(field: StaticFieldInfo) -> Self {
+        Self {
+            name: field.name,
+            long_name: field.long_name,
+            start: field.start,
+            width: field.width,
+            value: field.value,
+            description: field.description.map(ToString::to_string),
+            diagnostic: field.diagnostic,
+            subfields: Vec::new(),
         }
     }
 }
 
 /// An error decoding a register value.
-#[derive(Debug, Error)]
+#[derive(Copy, Clone, Debug, Eq, Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum DecodeError {
     /// A RES0 field was not 0.
     #[error("Invalid ESR, res0 is {res0:#x}")]
     InvalidRes0 { res0: u64 },
+    /// A RES1 field was not all ones.
+    #[error("Invalid value, res1 is {res1:#x}")]
+    InvalidRes1 { res1: u64 },
     /// The EC field had an invalid value.
     #[error("Invalid EC {ec:#x}")]
     InvalidEc { ec: u64 },
@@ -174,6 +507,108 @@ pub enum DecodeError {
     /// The ISS field has an invalid value for a trapped LD64B or ST64B* exception.
     #[error("Invalid ISS {iss:#x} for trapped LD64B or ST64B*")]
     InvalidLd64bIss { iss: u64 },
+    /// The SMTC field had an invalid value.
+    #[error("Invalid SMTC {smtc:#x}")]
+    InvalidSmtc { smtc: u64 },
+    /// The GPCSC field had an invalid value.
+    #[error("Invalid GPCSC {gpcsc:#x}")]
+    InvalidGpcsc { gpcsc: u64 },
+    /// The ExType field had an invalid value for a GCS exception.
+    #[error("Invalid GCS ExType {ex_type:#x}")]
+    InvalidExType { ex_type: u64 },
+    /// The IT field had an invalid value for a GCS exception.
+    #[error("Invalid GCS IT {it:#x}")]
+    InvalidIt { it: u64 },
+    /// The EC can't be reported in the register given in the `DecodeOptions`.
+    #[error("EC {ec:#x} can't be taken to {el}")]
+    ImpossibleEc { ec: u64, el: ExceptionLevel },
+    /// The `DecodeOptions` are for ESR_EL1 with HCR_EL2.TGE set, but exceptions can't be taken to
+    /// EL1 then.
+    #[error("Exceptions can't be taken to EL1 when HCR_EL2.TGE is set")]
+    El1WithTge,
+    /// The register to decode wasn't found in the register database.
+    #[error("Unknown register")]
+    UnknownRegister,
+}
+
+/// The location of a field within a register value, such as `ISS.RES0[14..24]`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct FieldPath {
+    /// The short name of the top-level field containing the field, if it is a subfield.
+    pub parent: Option<&'static str>,
+    /// The short name of the field.
+    pub name: &'static str,
+    /// The index of the lowest bit of the field.
+    pub start: usize,
+    /// The number of bits in the field.
+    pub width: usize,
+}
+
+impl FieldPath {
+    /// Returns the range of bits of the register which the field covers.
+    pub fn bits(&self) -> Range<usize> {
+        self.start..self.start + self.width
+    }
+}
+
+impl Display for FieldPath {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if let Some(parent) = self.parent {
+            write!(f, "{parent}.")?;
+        }
+        write!(f, "{}[{:?}]", self.name, self.bits())
+    }
+}
+
+/// An error decoding an ESR value, along with the field which caused it.
+#[derive(Copy, Clone, Debug, Eq, Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct FieldError {
+    /// The field with the invalid value.
+    pub field: FieldPath,
+    /// The exception class which was being decoded, if the EC field was valid.
+    pub ec: Option<ExceptionClass>,
+    /// The problem with the field value.
+    pub error: DecodeError,
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} in {}", self.error, self.field)?;
+        if let Some(ec) = self.ec {
+            write!(f, " for EC {:#x}", ec as u64)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<FieldError> for DecodeError {
+    fn from(error: FieldError) -> Self {
+        error.error
+    }
+}
+
+/// An error encoding a register value.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Error)]
+pub enum EncodeError {
+    /// There is no field with the given name for the exception class.
+    #[error("No field {name} for EC {ec:#x}")]
+    UnknownField { name: String, ec: u64 },
+    /// The value doesn't fit in the field.
+    #[error("Value {value:#x} too wide for {width}-bit field {name}")]
+    ValueTooWide {
+        name: &'static str,
+        value: u64,
+        width: usize,
+    },
+    /// The field is not valid given the values of the other fields.
+    #[error("Field {name} is not valid given the values of the other fields")]
+    FieldNotValid { name: &'static str },
+    /// The encoded value could not be decoded.
+    #[error("Encoded value is not valid: {0}")]
+    Decode(#[from] FieldError),
 }
 
 /// Parses a decimal or hexadecimal number from a string.
@@ -12,8 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use aarch64_esr_decoder::{FieldInfo, decode, decode_midr, decode_smccc, parse_number};
+use aarch64_esr_decoder::{
+    DecodeOptions, FieldInfo, FieldTree, FormatOptions, decode_midr, decode_smccc, decode_sysreg,
+    decode_with_options, get_field, parse_number,
+};
+use arm_sysregs_json::RegisterEntry;
+use serde::Serialize;
 use std::env;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::BufReader;
 use std::ops::Deref;
 use std::process::exit;
 
@@ -23,110 +31,289 @@ fn main() {
         Err(error_code) => exit(error_code),
     };
 
-    let value = parse_number(&args.value).unwrap();
-    let decoded = match args.mode {
+    let value = parse_number(&args.value).unwrap_or_else(|error| {
+        let register = args.mode.register();
+        exit_with_error(
+            &format!("Invalid {register} value {}", args.value),
+            register,
+            &args.value,
+            error.to_string(),
+            args.format,
+        )
+    });
+    let (register, header, decoded) = match &args.mode {
         Mode::Esr => {
-            println!("ESR {value:#034x}:");
-            decode(value).unwrap()
+            let options = DecodeOptions {
+                lenient: args.lenient,
+                ..Default::default()
+            };
+            (
+                "ESR",
+                format!("ESR {value:#034x}:"),
+                unwrap_or_exit(
+                    decode_with_options(value, &options),
+                    "ESR",
+                    value,
+                    args.format,
+                ),
+            )
         }
-        Mode::Midr => {
-            println!("MIDR {value:#034x}:");
-            decode_midr(value).unwrap()
-        }
-        Mode::Smccc => {
-            println!("SMC ID {value:#018x}:");
-            decode_smccc(value).unwrap()
+        Mode::Midr => (
+            "MIDR",
+            format!("MIDR {value:#034x}:"),
+            unwrap_or_exit(decode_midr(value), "MIDR", value, args.format),
+        ),
+        Mode::Smccc => (
+            "SMCCC",
+            format!("SMC ID {value:#018x}:"),
+            unwrap_or_exit(decode_smccc(value), "SMCCC", value, args.format),
+        ),
+        Mode::Sysreg(name) => {
+            let Some(registers_path) = &args.registers else {
+                eprintln!("Decoding {name} needs a register database, passed with --registers.");
+                exit(1);
+            };
+            let registers = load_registers(registers_path);
+            (
+                name.as_str(),
+                format!("{name} {value:#018x}:"),
+                unwrap_or_exit(
+                    decode_sysreg(registers, name, value),
+                    name,
+                    value,
+                    args.format,
+                ),
+            )
         }
     };
-    print_decoded(&decoded, args.verbose, 0);
-}
-
-fn print_decoded(fields: &[FieldInfo], verbose: bool, level: usize) {
-    let indentation = " ".repeat(level * 2);
-    for field in fields {
-        let verbose_name = match field.long_name {
-            Some(long_name) if verbose => format!(" ({long_name})"),
-            _ => "".to_string(),
+    if let Some(path) = &args.field {
+        let Some(field) = get_field(&decoded, path) else {
+            eprintln!("No field {path} in {register} {value:#x}");
+            exit(1);
         };
-        if field.width == 1 {
-            println!(
-                "{}{:02}     {}{}",
-                indentation, field.start, field, verbose_name
-            );
-        } else {
-            println!(
-                "{}{:02}..{:02} {}{}",
-                indentation,
-                field.start,
-                field.start + field.width - 1,
-                field,
-                verbose_name,
-            );
+        match args.format {
+            Format::Text => println!("{}", field.value_string()),
+            Format::Json => println!("{}", serde_json::to_string_pretty(field).unwrap()),
         }
-        if let Some(description) = &field.description {
-            println!("{indentation}  # {description}");
+        return;
+    }
+    match args.format {
+        Format::Text => {
+            let options = FormatOptions {
+                long_names: args.verbose,
+                ..Default::default()
+            };
+            println!("{header}");
+            print!("{}", FieldTree::new(&decoded, options));
+        }
+        Format::Json => {
+            let output = JsonOutput {
+                register,
+                value,
+                fields: &decoded,
+            };
+            println!("{}", serde_json::to_string_pretty(&output).unwrap());
         }
+    }
+}
 
-        print_decoded(&field.subfields, verbose, level + 1);
+/// Returns the decoded fields, or prints the error in the given format and exits if the value
+/// couldn't be decoded.
+fn unwrap_or_exit<E: Display + Serialize>(
+    result: Result<Vec<FieldInfo>, E>,
+    register: &str,
+    value: u64,
+    format: Format,
+) -> Vec<FieldInfo> {
+    result.unwrap_or_else(|error| {
+        exit_with_error(
+            &format!("Failed to decode {register} {value:#x}"),
+            register,
+            value,
+            error,
+            format,
+        )
+    })
+}
+
+/// Prints the given error in the given format and exits.
+///
+/// For text the message is printed to stderr followed by the error, for JSON a `JsonError` is
+/// printed to stdout.
+fn exit_with_error<V: Serialize, E: Display + Serialize>(
+    message: &str,
+    register: &str,
+    value: V,
+    error: E,
+    format: Format,
+) -> ! {
+    match format {
+        Format::Text => eprintln!("{message}: {error}"),
+        Format::Json => {
+            let output = JsonError {
+                register,
+                value,
+                error,
+            };
+            println!("{}", serde_json::to_string_pretty(&output).unwrap());
+        }
     }
+    exit(1);
+}
+
+/// Loads the register database from the given `Registers.json` file.
+///
+/// The database is leaked, as the decoded fields borrow from it until the program exits.
+fn load_registers(path: &str) -> &'static [RegisterEntry] {
+    let file = File::open(path).unwrap_or_else(|e| {
+        eprintln!("Failed to open {path}: {e}");
+        exit(1);
+    });
+    let registers: Vec<RegisterEntry> = serde_json::from_reader(BufReader::new(file))
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to parse {path}: {e}");
+            exit(1);
+        });
+    registers.leak()
+}
+
+/// The top-level object printed for `--format json`.
+#[derive(Serialize)]
+struct JsonOutput<'a> {
+    register: &'a str,
+    value: u64,
+    fields: &'a [FieldInfo],
+}
+
+/// The top-level object printed for `--format json` if the value couldn't be parsed or decoded.
+///
+/// The value is a number if it was parsed, or the string given on the command line if not.
+#[derive(Serialize)]
+struct JsonError<'a, V, E> {
+    register: &'a str,
+    value: V,
+    error: E,
 }
 
 /// Parse and return command-line arguments, or an error code to return.
 fn parse_args() -> Result<Args, i32> {
     let args: Vec<String> = env::args().collect();
     let args: Vec<&str> = args.iter().map(Deref::deref).collect();
-    match args.as_slice() {
-        [_, esr] => Ok(Args {
-            verbose: false,
-            mode: Mode::Esr,
-            value: esr.to_string(),
-        }),
-        [_, "-v", esr] => Ok(Args {
-            verbose: true,
-            mode: Mode::Esr,
-            value: esr.to_string(),
-        }),
-        [_, "midr", midr] => Ok(Args {
-            verbose: false,
-            mode: Mode::Midr,
-            value: midr.to_string(),
-        }),
-        [_, "-v", "midr", midr] => Ok(Args {
-            verbose: true,
-            mode: Mode::Midr,
-            value: midr.to_string(),
-        }),
-        [_, "smccc", smccc] => Ok(Args {
-            verbose: false,
-            mode: Mode::Smccc,
-            value: smccc.to_string(),
-        }),
-        [_, "-v", "smccc", smccc] => Ok(Args {
-            verbose: true,
-            mode: Mode::Smccc,
-            value: smccc.to_string(),
-        }),
+    let mut verbose = false;
+    let mut lenient = false;
+    let mut format = Format::Text;
+    let mut field = None;
+    let mut registers = None;
+    let mut rest = &args[1..];
+    loop {
+        rest = match rest {
+            ["-v", tail @ ..] => {
+                verbose = true;
+                tail
+            }
+            ["-l", tail @ ..] => {
+                lenient = true;
+                tail
+            }
+            ["--format", "text", tail @ ..] => {
+                format = Format::Text;
+                tail
+            }
+            ["--format", "json", tail @ ..] => {
+                format = Format::Json;
+                tail
+            }
+            ["--field", path, tail @ ..] => {
+                field = Some(path.to_string());
+                tail
+            }
+            ["--registers", path, tail @ ..] => {
+                registers = Some(path.to_string());
+                tail
+            }
+            _ => break,
+        };
+    }
+    let (mode, value) = match rest {
+        [esr] => (Mode::Esr, esr),
+        ["midr", midr] => (Mode::Midr, midr),
+        ["smccc", smccc] => (Mode::Smccc, smccc),
+        ["sysreg", name, value] => (Mode::Sysreg(name.to_string()), value),
         _ => {
             eprintln!("Usage:");
-            eprintln!("  {} [-v] <ESR value>", args[0]);
-            eprintln!("  {} [-v] midr <MIDR value>", args[0]);
-            eprintln!("  {} [-v] smccc <SMCCC function ID>", args[0]);
-            Err(1)
+            eprintln!(
+                "  {} [-v] [-l] [--format text|json] [--field PATH] <ESR value>",
+                args[0]
+            );
+            eprintln!(
+                "  {} [-v] [--format text|json] [--field PATH] midr <MIDR value>",
+                args[0]
+            );
+            eprintln!(
+                "  {} [-v] [--format text|json] [--field PATH] smccc <SMCCC function ID>",
+                args[0]
+            );
+            eprintln!(
+                "  {} [-v] [--format text|json] [--field PATH] --registers FILE sysreg <name> <value>",
+                args[0]
+            );
+            eprintln!();
+            eprintln!("  -v  Include the long names of fields.");
+            eprintln!(
+                "  -l  Decode as much as possible of an invalid ESR, marking problems with '!'."
+            );
+            eprintln!("  --format json  Print the decoded fields as JSON.");
+            eprintln!("  --field PATH   Print only the value of the given field, e.g. ISS.WnR.");
+            eprintln!("  --registers FILE  Arm's Registers.json, to decode any system register.");
+            return Err(1);
         }
-    }
+    };
+    Ok(Args {
+        verbose,
+        lenient,
+        format,
+        field,
+        registers,
+        mode,
+        value: value.to_string(),
+    })
 }
 
 /// Command-line arguments.
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct Args {
     verbose: bool,
+    lenient: bool,
+    format: Format,
+    field: Option<String>,
+    registers: Option<String>,
     mode: Mode,
     value: String,
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 enum Mode {
     Esr,
     Midr,
     Smccc,
+    /// A system register with the given name, decoded using the register database.
+    Sysreg(String),
+}
+
+impl Mode {
+    /// Returns the name of the register being decoded.
+    fn register(&self) -> &str {
+        match self {
+            Self::Esr => "ESR",
+            Self::Midr => "MIDR",
+            Self::Smccc => "SMCCC",
+            Self::Sysreg(name) => name,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Format {
+    Text,
+    Json,
 }
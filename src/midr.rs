@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::{DecodeError, FieldInfo};
+use alloc::{vec, vec::Vec};
 
 /// Decodes the given Main ID Register value, or returns an error if it is not valid.
 pub fn decode_midr(midr: u64) -> Result<Vec<FieldInfo>, DecodeError> {
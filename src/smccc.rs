@@ -31,6 +31,7 @@ use secure::decode_secure_service;
 use tapp::decode_tapp_service;
 
 use super::{DecodeError, FieldInfo};
+use alloc::{vec, vec::Vec};
 
 /// Decodes the function ID of an SMCCC (ARM DEN 0028E v1.4) call, or returns an error if it is not valid.
 pub fn decode_smccc(smccc: u64) -> Result<Vec<FieldInfo>, DecodeError> {
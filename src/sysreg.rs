@@ -0,0 +1,774 @@
+// Copyright 2026 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Decoding of arbitrary system register values using Arm's machine-readable register database.
+
+use super::{DecodeError, FieldInfo, parse_number};
+use alloc::{format, string::String, vec::Vec};
+use arm_sysregs_json::{
+    ExecutionState, Expression, FieldEntry, Fieldset, Range, RegisterEntry, ValueEntry, Values,
+};
+use bit_field::BitField;
+use core::cmp::Reverse;
+
+/// Decodes the given value of the register with the given name, using the field layout from the
+/// given entries of Arm's `Registers.json`, as parsed by the `arm-sysregs-json` crate.
+///
+/// Names are matched case-insensitively, and may include an index for register arrays such as
+/// `PMEVCNTR3_EL0`. Where several registers have the same name, the AArch64 one is preferred.
+///
+/// The returned fields borrow their names from the register database, so it must live for the rest
+/// of the program. Load it once, and then e.g. leak it with `Box::leak` or keep it in a `static`.
+///
+/// Returns an error if there is no such register, or if a RES0 or RES1 field has the wrong value.
+pub fn decode_sysreg(
+    registers: &'static [RegisterEntry],
+    name: &str,
+    value: u64,
+) -> Result<Vec<FieldInfo>, DecodeError> {
+    let mut candidates = Vec::new();
+    find_registers(registers, name, &mut candidates);
+    let fieldsets = candidates
+        .into_iter()
+        .min_by_key(|(_, state)| *state != Some(ExecutionState::AArch64))
+        .ok_or(DecodeError::UnknownRegister)?
+        .0;
+    let fieldset = choose_fieldset(fieldsets, value).ok_or(DecodeError::UnknownRegister)?;
+    decode_fieldset(fieldset, value)
+}
+
+/// Adds the fieldsets of all registers in the given entries with the given name to `candidates`,
+/// along with the execution state of each.
+fn find_registers(
+    entries: &'static [RegisterEntry],
+    name: &str,
+    candidates: &mut Vec<(&'static [Fieldset], Option<ExecutionState>)>,
+) {
+    for entry in entries {
+        match entry {
+            RegisterEntry::Register(register) => {
+                if register.name.eq_ignore_ascii_case(name) {
+                    candidates.push((&register.fieldsets, register.state));
+                }
+            }
+            RegisterEntry::RegisterArray(array) => {
+                if array_name_matches(&array.name, &array.index_variable, &array.indexes, name) {
+                    candidates.push((&array.fieldsets, array.state));
+                }
+            }
+            RegisterEntry::RegisterBlock(block) => find_registers(&block.blocks, name, candidates),
+        }
+    }
+}
+
+/// Returns whether the given name, such as `PMEVCNTR3_EL0`, is an instance of the register array
+/// with the given name pattern, such as `PMEVCNTR<n>_EL0`.
+fn array_name_matches(pattern: &str, index_variable: &str, indexes: &[Range], name: &str) -> bool {
+    let Some((prefix, suffix)) = pattern.split_once(&format!("<{index_variable}>")) else {
+        return pattern.eq_ignore_ascii_case(name);
+    };
+    if name.len() < prefix.len() + suffix.len()
+        || !name.is_char_boundary(prefix.len())
+        || !name.is_char_boundary(name.len() - suffix.len())
+        || !name[..prefix.len()].eq_ignore_ascii_case(prefix)
+        || !name[name.len() - suffix.len()..].eq_ignore_ascii_case(suffix)
+    {
+        return false;
+    }
+    let index = &name[prefix.len()..name.len() - suffix.len()];
+    index.parse::<u32>().is_ok_and(|index| {
+        indexes
+            .iter()
+            .any(|range| (range.start..range.start + range.width).contains(&index))
+    })
+}
+
+/// Returns the first of the given fieldsets whose condition holds for the given register value,
+/// or if none are known to then the first one whose condition can't be evaluated.
+fn choose_fieldset(fieldsets: &[Fieldset], value: u64) -> Option<&Fieldset> {
+    fieldsets
+        .iter()
+        .find(|fieldset| evaluate(&fieldset.condition, &fieldset.values, value) == Some(true))
+        .or_else(|| {
+            fieldsets
+                .iter()
+                .find(|fieldset| evaluate(&fieldset.condition, &fieldset.values, value).is_none())
+        })
+}
+
+/// Decodes all the fields of the given fieldset, most significant first.
+fn decode_fieldset(fieldset: &'static Fieldset, value: u64) -> Result<Vec<FieldInfo>, DecodeError> {
+    let mut fields = Vec::new();
+    for entry in &fieldset.values {
+        decode_entry(entry, None, &fieldset.values, value, &mut fields)?;
+    }
+    fields.sort_by_key(|field| Reverse(field.start));
+    Ok(fields)
+}
+
+/// Decodes the given field entry, adding a field for each range of bits it covers to `fields`.
+///
+/// `siblings` are the other entries in the same fieldset, which conditions may refer to.
+fn decode_entry(
+    entry: &'static FieldEntry,
+    outer_name: Option<&'static str>,
+    siblings: &[FieldEntry],
+    value: u64,
+    fields: &mut Vec<FieldInfo>,
+) -> Result<(), DecodeError> {
+    match entry {
+        FieldEntry::Field(field) => {
+            let name = field_name(field.name.as_deref().or(outer_name));
+            for range in &field.rangeset {
+                if let Some(mut field_info) = get(value, name, None, range) {
+                    if let Some(values) = &field.values {
+                        field_info.description =
+                            describe_value(values, field_info.value, siblings, value);
+                    }
+                    fields.push(field_info);
+                }
+            }
+        }
+        FieldEntry::Reserved(reserved) => {
+            for range in &reserved.rangeset {
+                let Some(field_info) = get(value, reserved_name(&reserved.value), None, range)
+                else {
+                    continue;
+                };
+                let field_info = match reserved.value.as_str() {
+                    "RES0" => field_info.check_res0()?,
+                    "RES1" => field_info.check_res1()?,
+                    _ => field_info,
+                };
+                fields.push(field_info);
+            }
+        }
+        FieldEntry::ImplementationDefined(field) => {
+            let name = field_name(field.name.as_deref().or(outer_name));
+            for range in &field.rangeset {
+                fields.extend(get(value, name, Some("IMPLEMENTATION DEFINED"), range));
+            }
+        }
+        FieldEntry::Array(array) => {
+            let name = field_name(array.name.as_deref().or(outer_name));
+            for range in &array.rangeset {
+                fields.extend(get_array(
+                    value,
+                    name,
+                    range,
+                    &array.indexes,
+                    array.values.as_ref(),
+                    siblings,
+                ));
+            }
+        }
+        FieldEntry::Vector(vector) => {
+            let name = field_name(vector.name.as_deref().or(outer_name));
+            for range in &vector.rangeset {
+                fields.extend(get_array(
+                    value,
+                    name,
+                    range,
+                    &vector.indexes,
+                    vector.values.as_ref(),
+                    siblings,
+                ));
+            }
+        }
+        FieldEntry::ConditionalField(conditional) => {
+            let name = conditional.name.as_deref().or(outer_name);
+            let chosen = conditional
+                .fields
+                .iter()
+                .find(|field| evaluate(&field.condition, siblings, value) == Some(true))
+                .or_else(|| {
+                    conditional
+                        .fields
+                        .iter()
+                        .find(|field| evaluate(&field.condition, siblings, value).is_none())
+                });
+            if let Some(chosen) = chosen {
+                decode_entry(&chosen.field, name, siblings, value, fields)?;
+            } else {
+                for range in &conditional.rangeset {
+                    fields.extend(get(value, field_name(name), None, range));
+                }
+            }
+        }
+        FieldEntry::ConstantField(constant) => {
+            let name = field_name(constant.name.as_deref().or(outer_name));
+            for range in &constant.rangeset {
+                if let Some(mut field_info) = get(value, name, None, range) {
+                    field_info.description =
+                        describe_entry(&constant.value, field_info.value, siblings, value)
+                            .flatten();
+                    fields.push(field_info);
+                }
+            }
+        }
+        FieldEntry::Dynamic(dynamic) => {
+            let name = field_name(dynamic.name.as_deref().or(outer_name));
+            for range in &dynamic.rangeset {
+                let Some(mut field_info) = get(value, name, None, range) else {
+                    continue;
+                };
+                if let Some(fieldset) = choose_fieldset(&dynamic.instances, value) {
+                    // The fields of each instance are relative to the whole register, so only
+                    // keep those within this field.
+                    field_info.subfields = decode_fieldset(fieldset, value)?
+                        .into_iter()
+                        .filter(|subfield| {
+                            subfield.start >= field_info.start
+                                && subfield.start + subfield.width
+                                    <= field_info.start + field_info.width
+                        })
+                        .collect();
+                }
+                fields.push(field_info);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the given field name, or a placeholder if it is unnamed.
+fn field_name(name: Option<&'static str>) -> &'static str {
+    name.unwrap_or("UNNAMED")
+}
+
+/// Returns the name to use for a reserved field with the given kind, such as `RES0`.
+fn reserved_name(kind: &str) -> &'static str {
+    match kind {
+        "RES0" => "RES0",
+        "RES1" => "RES1",
+        "RAZ" => "RAZ",
+        "RAO" => "RAO",
+        "RAZ/WI" => "RAZ/WI",
+        "RAO/WI" => "RAO/WI",
+        "UNKNOWN" => "UNKNOWN",
+        _ => "Reserved",
+    }
+}
+
+/// Returns a field for the given range of bits of the register value, or `None` if the range is
+/// beyond the 64 bits of the value.
+fn get(
+    register: u64,
+    name: &'static str,
+    long_name: Option<&'static str>,
+    range: &Range,
+) -> Option<FieldInfo> {
+    let start = range.start as usize;
+    let end = (start + range.width as usize).min(64);
+    (start < end).then(|| FieldInfo::get(register, name, long_name, start, end))
+}
+
+/// Returns a field for the given range of bits of the register value, split into a subfield for
+/// each element of an array field with the given indexes.
+///
+/// `siblings` are the other entries in the same fieldset, which conditions on values may refer to.
+fn get_array(
+    register: u64,
+    name: &'static str,
+    range: &Range,
+    indexes: &[Range],
+    values: Option<&Values>,
+    siblings: &[FieldEntry],
+) -> Option<FieldInfo> {
+    let mut field = get(register, name, None, range)?;
+    let count: u32 = indexes.iter().map(|index| index.width).sum();
+    if count > 0 && range.width.is_multiple_of(count) {
+        let element_width = range.width / count;
+        field.subfields = (0..count)
+            .rev()
+            .filter_map(|index| {
+                let element_range = Range {
+                    start: range.start + index * element_width,
+                    width: element_width,
+                };
+                let mut element = get(register, name, None, &element_range)?;
+                element.description = values
+                    .and_then(|values| describe_value(values, element.value, siblings, register));
+                Some(element)
+            })
+            .collect();
+    }
+    Some(field)
+}
+
+/// Returns the meaning of the given field value from the given list of possible values, if it is
+/// there.
+///
+/// `fields` and `register` are the fields and value of the whole register, which conditions on
+/// values may refer to.
+fn describe_value(
+    values: &Values,
+    value: u64,
+    fields: &[FieldEntry],
+    register: u64,
+) -> Option<String> {
+    find_value(values, value, fields, register).flatten()
+}
+
+/// Returns `Some` with the meaning of the first entry in the given list of possible values which
+/// the given field value matches, or `None` if it doesn't match any of them.
+fn find_value(
+    values: &Values,
+    value: u64,
+    fields: &[FieldEntry],
+    register: u64,
+) -> Option<Option<String>> {
+    values
+        .values
+        .iter()
+        .find_map(|entry| describe_entry(entry, value, fields, register))
+}
+
+/// Returns `Some` with the meaning of the given field value if it matches the given entry, or `None`
+/// if it doesn't match.
+fn describe_entry(
+    entry: &ValueEntry,
+    value: u64,
+    fields: &[FieldEntry],
+    register: u64,
+) -> Option<Option<String>> {
+    match entry {
+        ValueEntry::Value(entry) => {
+            value_matches(&entry.value, value).then(|| entry.meaning.as_deref().map(clean_text))
+        }
+        ValueEntry::NamedValue(entry) => value_matches(&entry.value, value).then(|| {
+            Some(match &entry.meaning {
+                Some(meaning) => format!("{}: {}", entry.name, clean_text(meaning)),
+                None => entry.name.clone(),
+            })
+        }),
+        ValueEntry::Link(entry) => {
+            value_matches(&entry.value, value).then(|| entry.meaning.as_deref().map(clean_text))
+        }
+        ValueEntry::ValueRange(entry) => {
+            let start = parse_value(&entry.start.value)?;
+            let end = parse_value(&entry.end.value)?;
+            (start..=end)
+                .contains(&value)
+                .then(|| entry.meaning.as_deref().map(clean_text))
+        }
+        ValueEntry::ConditionalValue(entry) => {
+            // Conditions which can't be evaluated are assumed to hold, as for fieldsets.
+            if evaluate(&entry.condition, fields, register) == Some(false) {
+                return None;
+            }
+            let meaning = find_value(&entry.values, value, fields, register)?;
+            Some(meaning.or_else(|| entry.meaning.as_deref().map(clean_text)))
+        }
+        ValueEntry::Group(entry) => value_matches(&entry.value, value).then(|| {
+            describe_value(&entry.values, value, fields, register)
+                .or_else(|| entry.meaning.as_deref().map(clean_text))
+        }),
+        ValueEntry::EquationValue(_) | ValueEntry::ImplementationDefined(_) => None,
+    }
+}
+
+/// Collapses runs of whitespace in the given text from the register database to single spaces.
+fn clean_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Returns whether the given value matches the given value pattern from the register database.
+///
+/// Patterns are usually quoted binary strings such as `'01'` or `'0b01'`, in which an `x` matches
+/// either bit value, but may also be plain decimal or hexadecimal numbers.
+fn value_matches(pattern: &str, value: u64) -> bool {
+    let Some(bits) = binary_pattern(pattern) else {
+        return parse_number(pattern.trim()) == Ok(value);
+    };
+    if bits.len() > 64 || (bits.len() < 64 && value >> bits.len() != 0) {
+        return false;
+    }
+    bits.chars()
+        .rev()
+        .enumerate()
+        .all(|(index, bit)| match bit {
+            '0' => !value.get_bit(index),
+            '1' => value.get_bit(index),
+            _ => true,
+        })
+}
+
+/// Parses the given value from the register database as a number, if it has no `x` bits.
+fn parse_value(pattern: &str) -> Option<u64> {
+    match binary_pattern(pattern) {
+        Some(bits) => u64::from_str_radix(bits, 2).ok(),
+        None => parse_number(pattern.trim()).ok(),
+    }
+}
+
+/// Returns the bits of the given quoted binary value pattern, or `None` if it isn't one.
+fn binary_pattern(pattern: &str) -> Option<&str> {
+    let bits = pattern.trim().strip_prefix('\'')?.strip_suffix('\'')?;
+    let bits = bits.strip_prefix("0b").unwrap_or(bits);
+    (!bits.is_empty() && bits.chars().all(|bit| matches!(bit, '0' | '1' | 'x'))).then_some(bits)
+}
+
+/// Evaluates a condition from the register database for the given register value, where
+/// `fields` are the fields of the register which it may refer to.
+///
+/// Returns `None` if the condition depends on anything other than the register value, such as
+/// which architecture features are implemented.
+fn evaluate(condition: &Expression, fields: &[FieldEntry], value: u64) -> Option<bool> {
+    match condition {
+        Expression::Bool(bool) => Some(bool.value),
+        Expression::BinaryOp(op) => match op.op.as_str() {
+            "&&" => match (
+                evaluate(&op.left, fields, value),
+                evaluate(&op.right, fields, value),
+            ) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            },
+            "||" => match (
+                evaluate(&op.left, fields, value),
+                evaluate(&op.right, fields, value),
+            ) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            },
+            "==" | "!=" => {
+                let field_value = field_value(&op.left, fields, value)?;
+                let matches = match op.right.as_ref() {
+                    Expression::Value(pattern) => value_matches(&pattern.value, field_value),
+                    Expression::Integer(integer) => u64::try_from(integer.value) == Ok(field_value),
+                    _ => return None,
+                };
+                Some(matches == (op.op == "=="))
+            }
+            _ => None,
+        },
+        Expression::UnaryOp(op) if op.op == "!" => evaluate(&op.expr, fields, value).map(|b| !b),
+        _ => None,
+    }
+}
+
+/// Returns the value of the field of the register which the given expression refers to, such as
+/// `PAR_EL1.F`, if it is one of the given fields.
+fn field_value(expression: &Expression, fields: &[FieldEntry], value: u64) -> Option<u64> {
+    let name = match expression {
+        Expression::Field(field) => &field.value.field,
+        Expression::DotAtom(atom) => match atom.values.last()? {
+            Expression::Identifier(identifier) => &identifier.value,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let range = fields.iter().find_map(|entry| match entry {
+        FieldEntry::Field(field) if field.name.as_ref() == Some(name) => {
+            match field.rangeset.as_slice() {
+                [range] => Some(range),
+                _ => None,
+            }
+        }
+        _ => None,
+    })?;
+    let start = range.start as usize;
+    let end = start + range.width as usize;
+    (end <= 64).then(|| value.get_bits(start..end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec};
+    use serde_json::json;
+
+    /// Returns a leaked register database containing the given registers in JSON form.
+    fn registers(registers: serde_json::Value) -> &'static [RegisterEntry] {
+        serde_json::from_value::<Vec<RegisterEntry>>(registers)
+            .unwrap()
+            .leak()
+    }
+
+    fn register(name: &str, state: &str, fieldsets: serde_json::Value) -> serde_json::Value {
+        json!({
+            "_type": "Register",
+            "_meta": null,
+            "access_text": null,
+            "accessors": [],
+            "condition": {"_type": "AST.Bool", "value": true},
+            "configuration": null,
+            "fieldsets": fieldsets,
+            "groups": null,
+            "instances": true,
+            "mapset": [],
+            "name": name,
+            "purpose": null,
+            "state": state,
+            "title": null,
+        })
+    }
+
+    fn fieldset(condition: serde_json::Value, fields: serde_json::Value) -> serde_json::Value {
+        json!({
+            "condition": condition,
+            "description": {"after": null, "before": null},
+            "display": null,
+            "name": null,
+            "values": fields,
+            "width": 64,
+        })
+    }
+
+    fn field(name: &str, start: u32, width: u32, values: serde_json::Value) -> serde_json::Value {
+        json!({
+            "_type": "Fields.Field",
+            "description": {"after": null, "before": null},
+            "name": name,
+            "rangeset": [{"start": start, "width": width}],
+            "resets": null,
+            "values": {"values": values},
+            "volatile": null,
+        })
+    }
+
+    fn reserved(kind: &str, start: u32, width: u32) -> serde_json::Value {
+        json!({
+            "_type": "Fields.Reserved",
+            "description": {"after": null, "before": null},
+            "rangeset": [{"start": start, "width": width}],
+            "value": kind,
+        })
+    }
+
+    fn value(value: &str, meaning: &str) -> serde_json::Value {
+        json!({"_type": "Values.Value", "value": value, "meaning": meaning})
+    }
+
+    fn always() -> serde_json::Value {
+        json!({"_type": "AST.Bool", "value": true})
+    }
+
+    /// Returns a condition that the given field of the given register has the given value.
+    fn field_is(register: &str, field: &str, value: &str) -> serde_json::Value {
+        json!({
+            "_type": "AST.BinaryOp",
+            "op": "==",
+            "left": {
+                "_type": "AST.DotAtom",
+                "values": [
+                    {"_type": "AST.Identifier", "value": register},
+                    {"_type": "AST.Identifier", "value": field},
+                ],
+            },
+            "right": {"_type": "Values.Value", "value": value, "meaning": null},
+        })
+    }
+
+    fn sctlr() -> &'static [RegisterEntry] {
+        registers(json!([
+            register(
+                "SCTLR",
+                "AArch32",
+                json!([fieldset(always(), json!([reserved("RES0", 0, 32)]))]),
+            ),
+            register(
+                "SCTLR_EL1",
+                "AArch64",
+                json!([fieldset(
+                    always(),
+                    json!([
+                        reserved("RES0", 32, 32),
+                        reserved("RES1", 28, 4),
+                        field(
+                            "EE",
+                            25,
+                            1,
+                            json!([value("'0'", "Little-endian."), value("'1'", "Big-endian."),]),
+                        ),
+                        reserved("RES0", 1, 24),
+                        field(
+                            "M",
+                            0,
+                            1,
+                            json!([
+                                value("'0'", "MMU disabled."),
+                                value("'1'", "MMU\n  enabled."),
+                            ]),
+                        ),
+                    ]),
+                )]),
+            ),
+        ]))
+    }
+
+    #[test]
+    fn decode_fields() {
+        let decoded = decode_sysreg(sctlr(), "sctlr_el1", 0xf200_0001).unwrap();
+        assert_eq!(
+            decoded,
+            vec![
+                FieldInfo::get(0xf200_0001, "RES0", None, 32, 64),
+                FieldInfo::get(0xf200_0001, "RES1", None, 28, 32),
+                FieldInfo {
+                    description: Some("Big-endian.".to_string()),
+                    ..FieldInfo::get(0xf200_0001, "EE", None, 25, 26)
+                },
+                FieldInfo::get(0xf200_0001, "RES0", None, 1, 25),
+                FieldInfo {
+                    description: Some("MMU enabled.".to_string()),
+                    ..FieldInfo::get(0xf200_0001, "M", None, 0, 1)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reserved_fields() {
+        assert_eq!(
+            decode_sysreg(sctlr(), "SCTLR_EL1", 0xf000_0002),
+            Err(DecodeError::InvalidRes0 { res0: 1 })
+        );
+        assert_eq!(
+            decode_sysreg(sctlr(), "SCTLR_EL1", 0x1000_0000),
+            Err(DecodeError::InvalidRes1 { res1: 1 })
+        );
+    }
+
+    #[test]
+    fn unknown_register() {
+        assert_eq!(
+            decode_sysreg(sctlr(), "SCTLR_EL3", 0),
+            Err(DecodeError::UnknownRegister)
+        );
+    }
+
+    #[test]
+    fn fieldset_condition() {
+        let registers = registers(json!([register(
+            "PAR_EL1",
+            "AArch64",
+            json!([
+                fieldset(
+                    field_is("PAR_EL1", "F", "'0'"),
+                    json!([field("PA", 12, 40, json!([])), field("F", 0, 1, json!([]))]),
+                ),
+                fieldset(
+                    field_is("PAR_EL1", "F", "'1'"),
+                    json!([
+                        field(
+                            "FST",
+                            1,
+                            6,
+                            json!([value("'0001xx'", "Translation fault.")]),
+                        ),
+                        field("F", 0, 1, json!([])),
+                    ]),
+                ),
+            ]),
+        )]));
+
+        let decoded = decode_sysreg(registers, "PAR_EL1", 0x1234_5000).unwrap();
+        assert_eq!(decoded[0].name, "PA");
+        assert_eq!(decoded[0].value, 0x12345);
+
+        let decoded = decode_sysreg(registers, "PAR_EL1", 0b1011).unwrap();
+        assert_eq!(decoded[0].name, "FST");
+        assert_eq!(
+            decoded[0].description.as_deref(),
+            Some("Translation fault.")
+        );
+    }
+
+    #[test]
+    fn conditional_value() {
+        let registers = registers(json!([register(
+            "TEST_EL1",
+            "AArch64",
+            json!([fieldset(
+                always(),
+                json!([
+                    field("MODE", 4, 1, json!([])),
+                    field(
+                        "VAL",
+                        0,
+                        4,
+                        json!([
+                            {
+                                "_type": "Values.ConditionalValue",
+                                "condition": field_is("TEST_EL1", "MODE", "'1'"),
+                                "meaning": "Only when MODE is 1.",
+                                "values": {"values": [value("'0001'", "Special one.")]},
+                            },
+                            value("'0001'", "Plain one."),
+                            value("'0010'", "Plain two."),
+                        ]),
+                    ),
+                ]),
+            )]),
+        )]));
+        let describe = |value| {
+            decode_sysreg(registers, "TEST_EL1", value).unwrap()[1]
+                .description
+                .clone()
+        };
+
+        assert_eq!(describe(0b1_0001).as_deref(), Some("Special one."));
+        // The nested values don't match, so later entries are used.
+        assert_eq!(describe(0b1_0010).as_deref(), Some("Plain two."));
+        // The condition doesn't hold.
+        assert_eq!(describe(0b0_0001).as_deref(), Some("Plain one."));
+        assert_eq!(describe(0b1_0011), None);
+    }
+
+    #[test]
+    fn register_array() {
+        let registers = registers(json!([{
+            "_type": "RegisterArray",
+            "_meta": null,
+            "access_text": null,
+            "accessors": [],
+            "condition": always(),
+            "configuration": null,
+            "fieldsets": [fieldset(always(), json!([field("EVTCOUNT", 0, 64, json!([]))]))],
+            "groups": null,
+            "index_variable": "n",
+            "indexes": [{"start": 0, "width": 31}],
+            "instances": true,
+            "mapset": [],
+            "name": "PMEVCNTR<n>_EL0",
+            "purpose": null,
+            "state": "AArch64",
+            "title": null,
+        }]));
+        assert_eq!(
+            decode_sysreg(registers, "PMEVCNTR30_EL0", 42).unwrap()[0].value,
+            42
+        );
+        assert_eq!(
+            decode_sysreg(registers, "PMEVCNTR31_EL0", 42),
+            Err(DecodeError::UnknownRegister)
+        );
+    }
+
+    #[test]
+    fn value_patterns() {
+        assert!(value_matches("'0b01'", 1));
+        assert!(value_matches("'1x0'", 0b110));
+        assert!(value_matches("'1x0'", 0b100));
+        assert!(!value_matches("'1x0'", 0b101));
+        assert!(!value_matches("'10'", 0b110));
+        assert!(value_matches("0x10", 16));
+        assert_eq!(parse_value("'0101'"), Some(5));
+        assert_eq!(parse_value("'01x1'"), None);
+    }
+}